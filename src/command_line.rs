@@ -1,19 +1,154 @@
 //! The command-line arguments for `openrpc-gen`.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// The environment variables overriding the options of the configuration files, printed at the
+/// end of `--help`.
+///
+/// This must be kept in sync with `config::ENV_OVERRIDES` and the README.
+const ENV_HELP: &str = "\
+Environment variables:
+  The following variables override the corresponding option of the configuration files. They
+  must be set to either `true` or `false`.
+
+  OPENRPC_GEN_USE_CORE                 generation.use-core
+  OPENRPC_GEN_NO_STD                   generation.no-std
+  OPENRPC_GEN_METHOD_NAME_CONSTANTS    generation.method-name-constants
+  OPENRPC_GEN_RESULT_TYPES             generation.result-types
+  OPENRPC_GEN_PARAM_TYPES              generation.param-types
+  OPENRPC_GEN_ERROR_TYPES              generation.error-types
+  OPENRPC_GEN_CLIENT_TRAIT             generation.client-trait
+  OPENRPC_GEN_TRACING                  generation.tracing";
 
 /// A CLI tool to parse OpenRPC documents and generate Rust types from them.
 #[derive(Debug, Clone, clap::Parser)]
+#[clap(after_help = ENV_HELP)]
 pub struct CommandLineArgs {
     /// The path to the configuration file to use.
-    #[clap(short, long)]
+    #[clap(short, long, required_unless_present = "batch")]
+    pub config: Option<PathBuf>,
+    /// The OpenRPC document to be parsed.
+    #[clap(short, long, required_unless_present = "batch")]
+    pub document: Option<PathBuf>,
+    /// A JSON pointer (such as `/api/spec`) to the OpenRPC document within the provided file,
+    /// when it is embedded in a larger JSON document.
+    #[clap(long)]
+    pub document_pointer: Option<String>,
+    /// The path to the output file.
+    #[clap(short, long, required_unless_present_any = ["dry_run", "batch"])]
+    pub output: Option<PathBuf>,
+    /// An optional path to a TypeScript declaration file mirroring the generated types.
+    #[clap(long)]
+    pub typescript: Option<PathBuf>,
+    /// Print `cargo:rerun-if-changed` lines for the input files to the standard output,
+    /// including the files targeted by the external references of the documents.
+    ///
+    /// This is meant to be used from build scripts.
+    #[clap(long)]
+    pub cargo_emit: bool,
+    /// Print parsing errors to the standard output as a JSON array, in addition to the
+    /// regular error messages.
+    #[clap(long)]
+    pub error_json: bool,
+    /// Only generate the methods whose name matches one of the provided glob patterns and the
+    /// types whose path does (such as `#/components/schemas/Block*`), along with the types
+    /// they reference.
+    ///
+    /// This may be repeated.
+    #[clap(long)]
+    pub only: Vec<String>,
+    /// Parse the document and generate the code without writing anything, printing the number
+    /// of generated types, aliases, methods and warnings instead.
+    #[clap(long)]
+    pub dry_run: bool,
+    /// The path to a TOML manifest listing several generation jobs to run in a single
+    /// invocation, as `[[job]]` tables with the `config`, `document`, `document-pointer`,
+    /// `output`, `typescript` and `only` keys of the corresponding arguments.
+    ///
+    /// Relative paths are resolved from the directory of the manifest. Documents used by
+    /// several jobs are only parsed once.
+    #[clap(
+        long,
+        conflicts_with_all = ["config", "document", "document_pointer", "output", "typescript", "only"]
+    )]
+    pub batch: Option<PathBuf>,
+}
+
+/// A single generation job, turning a document into a Rust file according to a configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Job {
+    /// The path to the configuration file to use.
     pub config: PathBuf,
     /// The OpenRPC document to be parsed.
-    #[clap(short, long)]
     pub document: PathBuf,
+    /// A JSON pointer to the OpenRPC document within the provided file.
+    #[serde(default)]
+    pub document_pointer: Option<String>,
     /// The path to the output file.
-    #[clap(short, long)]
-    pub output: PathBuf,
+    #[serde(default)]
+    pub output: Option<PathBuf>,
+    /// An optional path to a TypeScript declaration file mirroring the generated types.
+    #[serde(default)]
+    pub typescript: Option<PathBuf>,
+    /// The glob patterns of the methods and types to generate.
+    #[serde(default)]
+    pub only: Vec<String>,
+}
+
+/// The manifest provided to `--batch`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Batch {
+    /// The jobs to run, in order.
+    #[serde(default)]
+    job: Vec<Job>,
+}
+
+impl CommandLineArgs {
+    /// Returns the jobs requested by the command line, either listed by the `--batch` manifest
+    /// or described by the arguments themselves.
+    ///
+    /// The output of each job is only required when not doing a dry run.
+    pub fn jobs(&self) -> Result<Vec<Job>, String> {
+        let Some(path) = &self.batch else {
+            // `clap` ensures that those are provided when no batch manifest is.
+            return Ok(vec![Job {
+                config: self.config.clone().unwrap(),
+                document: self.document.clone().unwrap(),
+                document_pointer: self.document_pointer.clone(),
+                output: self.output.clone(),
+                typescript: self.typescript.clone(),
+                only: self.only.clone(),
+            }]);
+        };
+
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| format!("`{}`: {e}", path.display()))?;
+        let batch: Batch =
+            toml::from_str(&contents).map_err(|e| format!("`{}`: {e}", path.display()))?;
+        let base = path.parent().unwrap_or(Path::new(""));
+        let mut jobs = batch.job;
+        for (i, job) in jobs.iter_mut().enumerate() {
+            if job.output.is_none() && !self.dry_run {
+                return Err(format!(
+                    "`{}`: job {} has no `output`",
+                    path.display(),
+                    i + 1
+                ));
+            }
+            for p in [&mut job.config, &mut job.document]
+                .into_iter()
+                .chain(job.output.as_mut())
+                .chain(job.typescript.as_mut())
+            {
+                *p = base.join(&*p);
+            }
+        }
+        Ok(jobs)
+    }
 }
 
 /// Loads an instance of [`CommandLineArgs`] from the environment.
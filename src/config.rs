@@ -28,6 +28,9 @@ pub struct Primitives {
     pub array: String,
     /// The name of the type that should be used to represent strings.
     ///
+    /// When this is `Arc<str>` or `Rc<str>`, a `shared_str` module is generated to (de)serialize
+    /// values of that type, including within arrays, without the `rc` feature of `serde`.
+    ///
     /// **Default:** `String`
     #[serde(default = "defaults::string")]
     pub string: String,
@@ -48,6 +51,21 @@ pub struct Primitives {
     pub optional: String,
 }
 
+impl Primitives {
+    /// Whether [`Primitives::string`] is a reference-counted string type, such as `Arc<str>` or
+    /// `Rc<str>`.
+    ///
+    /// Those types can only be (de)serialized when the `rc` feature of `serde` is enabled, so a
+    /// helper module is generated for them.
+    pub fn is_shared_string(&self) -> bool {
+        let ty: String = self.string.split_whitespace().collect();
+        ["Arc<str>", "Rc<str>"].iter().any(|shared| {
+            ty.strip_suffix(shared)
+                .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with("::"))
+        })
+    }
+}
+
 impl Default for Primitives {
     fn default() -> Self {
         Self {
@@ -161,6 +179,43 @@ pub struct Fixes {
     /// **Default:** `[]`
     #[serde(default)]
     pub preserve: BTreeSet<String>,
+    /// Whether method parameters referencing a struct with `readOnly` fields should use a
+    /// copy of that struct without those fields.
+    ///
+    /// The copy is named after the original struct with a `Request` suffix. The original
+    /// struct is still used everywhere else (e.g. in method results).
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub strip_read_only_params: bool,
+    /// How types defined by inline schemas without a `title` are named.
+    ///
+    /// **Default:** `title`
+    #[serde(default)]
+    pub anon_naming: AnonNaming,
+    /// Whether struct fields should be named after the snake case form of their name in JSON
+    /// (such as `block_hash` for `blockHash`) instead of its lowercase form.
+    ///
+    /// This changes the names of the public fields of the generated structs. Combined with
+    /// `generation.infer-rename-all`, most fields then no longer need a `rename` attribute.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub snake_case_fields: bool,
+}
+
+/// How types defined by inline schemas without a `title` are named.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AnonNaming {
+    /// The types are all named `Anonymous`, and must be renamed with `fixes.rename` when
+    /// more than one of them is generated.
+    #[default]
+    Title,
+    /// The types are named after a short hash of their structure, such as `AnonAb12cd`.
+    ///
+    /// Those names do not change when the schemas are moved around the document.
+    ContentHash,
 }
 
 impl Default for Fixes {
@@ -177,6 +232,9 @@ impl Default for Fixes {
             auto_flatten_one_ref: true,
             set_tags: BTreeMap::new(),
             preserve: BTreeSet::new(),
+            strip_read_only_params: false,
+            anon_naming: AnonNaming::default(),
+            snake_case_fields: false,
         }
     }
 }
@@ -190,6 +248,30 @@ pub struct Generation {
     /// **Default:** `false`
     #[serde(default)]
     pub use_core: bool,
+    /// Whether the generated code must compile in `#![no_std]` crates that use the `alloc`
+    /// crate.
+    ///
+    /// The allocated types used by the generated code, such as `String` and `Vec`, are then
+    /// imported from `alloc`. Other primitives, such as the `array` primitive, must be configured
+    /// to use types available in `alloc`. This requires `use-core`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub no_std: bool,
+    /// The visibility of the generated items, such as `pub(crate)` or
+    /// `pub(in crate::generated)`.
+    ///
+    /// **Default:** `pub`
+    #[serde(default = "defaults::visibility")]
+    pub visibility: String,
+    /// The path of the module the generated items are reachable from, such as
+    /// `crate::generated`.
+    ///
+    /// When set, references to generated types are qualified with this path.
+    ///
+    /// **Default:** `None`
+    #[serde(default)]
+    pub module_path: Option<String>,
     /// A collection of additional `use` statements.
     ///
     /// **Default:** `[]`
@@ -206,6 +288,131 @@ pub struct Generation {
     /// **Default:** `false`
     #[serde(default)]
     pub method_name_constants: bool,
+    /// A map from method names to the additional names under which they are available.
+    ///
+    /// Aliases get their own method name constant and are accepted by `Method::from_name`.
+    ///
+    /// **Default:** `{}`
+    #[serde(default)]
+    pub method_aliases: BTreeMap<String, Vec<String>>,
+    /// Whether to generate a `Method` enum with one variant per method.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub method_enum: bool,
+    /// Whether to generate a unit type per method implementing the sealed `RpcMethod` trait,
+    /// which ties the method to its parameter and result types.
+    ///
+    /// This requires `method-enum` and `param-types`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub method_markers: bool,
+    /// Whether to generate a `Response` enum with one variant per method, holding the result of
+    /// that method.
+    ///
+    /// This requires `method-enum`. Generation fails if a type of the document is also named
+    /// `Response`, in which case it must be renamed with `fixes.rename`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub response_enum: bool,
+    /// Whether strings containing JSON documents described by a `contentSchema` should be
+    /// decoded into the type of that schema, rather than kept as strings.
+    ///
+    /// The generated code then requires the `serde_json` crate.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub decode_json_strings: bool,
+    /// Whether booleans should also be deserialized from the strings `"true"` and `"false"`,
+    /// and from the integers `0` and `1`.
+    ///
+    /// Booleans are still serialized as JSON booleans.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub lenient_bool: bool,
+    /// The maximum number of items of arrays, and of bytes of strings, accepted when
+    /// deserializing the fields of structs and the payloads of enum variants.
+    ///
+    /// Longer values fail to deserialize, regardless of the bounds set by the schema. This
+    /// hardens servers against allocations driven by untrusted input. Fields already using a
+    /// custom (de)serialization module, such as shared strings or `BoundedVec`s, are not
+    /// capped.
+    ///
+    /// **Default:** `None`
+    #[serde(default)]
+    pub max_collection_size: Option<usize>,
+    /// Whether leading and trailing whitespace should be trimmed from string fields when they
+    /// are deserialized.
+    ///
+    /// Either `true` to trim all string fields, or a list of the paths of the fields to trim.
+    /// Serialization is unaffected. Fields already using a custom (de)serialization module,
+    /// such as shared strings or capped strings, are skipped by `true` and rejected when
+    /// listed.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub trim_strings: TrimStrings,
+    /// Whether the generated structs should capture the vendor extensions (`x-` properties) of
+    /// their objects into an `extensions` field, preserving them when serialized back.
+    ///
+    /// Structs that are transparent, that have flattened fields, or that already have an
+    /// `extensions` field are left untouched. The generated code then requires the
+    /// `serde_json` crate. The extensions are held in a `HashMap`, or in a `BTreeMap` with
+    /// `no-std`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub capture_extensions: bool,
+    /// Whether arrays with a `maxItems` keyword should be represented by a generated
+    /// `BoundedVec<T, N>` type, which fails to deserialize when holding more than `N` items.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub bounded_vec: bool,
+    /// Whether generated types should derive `arbitrary::Arbitrary` when the `arbitrary`
+    /// feature of the crate including them is enabled, which helps fuzzing the code handling
+    /// them.
+    ///
+    /// The `BoundedVec` type generated by `bounded-vec` never holds more than `N` items. Other
+    /// schema constraints are not enforced. All the types referenced by the generated types,
+    /// including the configured primitives, must implement `Arbitrary` themselves.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub arbitrary: bool,
+    /// Whether to derive `Default` for string enums whose schema specifies a `default` value.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub enum_defaults: bool,
+    /// Whether to implement `TryFrom<serde_json::Value>` for the generated structs and enums,
+    /// as well as `TryFrom<T>` for `serde_json::Value`.
+    ///
+    /// The generated code then requires the `serde_json` crate.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub value_tryfrom: bool,
+    /// Whether to generate a `*Outcome` enum for each method returning a result, combining its
+    /// result type with the errors it may return, and deserializing from a whole JSON-RPC
+    /// response.
+    ///
+    /// This requires `result-types`, `error-types` and `open-errors` to be enabled, so that
+    /// errors that are not declared for a method end up in its `Other` variant. The generated
+    /// code then requires the `serde_json` crate.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub outcome_types: bool,
+    /// Whether to generate a `method_specs` function describing every method, allowing
+    /// servers to route and validate requests.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub method_specs: bool,
     /// Whether to generate type aliases for method result types.
     ///
     /// **Default:** `false`
@@ -226,19 +433,607 @@ pub struct Generation {
     /// **Default:** `{}`
     #[serde(default)]
     pub derives: BTreeMap<String, Vec<String>>,
+    /// A map from enum paths to the representation of the enum, emitted as a `#[repr(...)]`
+    /// attribute.
+    ///
+    /// The representation is either `C` or a primitive integer type such as `u8` or `i32`.
+    /// Variants take the implicit discriminants `0`, `1`, `2`..., which must fit the chosen
+    /// integer type.
+    ///
+    /// **Default:** `{}`
+    #[serde(default)]
+    pub enum_repr: BTreeMap<String, String>,
+    /// Whether to generate constructor functions (such as `new_foo`) for each variant of
+    /// tagged enums.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub variant_constructors: bool,
+    /// Whether to generate an `into_values` method on parameter types that can be passed by
+    /// position, returning the parameters as a list of `serde_json::Value`s.
+    ///
+    /// This requires `param-types` to be enabled.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub params_into_values: bool,
+    /// Whether to implement `From<(T1, T2, ...)>` for parameter types that can be passed by
+    /// position, mapping the elements of the tuple to the parameters in order.
+    ///
+    /// This is only done for methods with at most 12 parameters. This requires `param-types` to
+    /// be enabled.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub params_from_tuple: bool,
+    /// Whether to generate a `from_rpc_params` function on parameter types, converting the
+    /// `params` of a request received as a `serde_json::Value` into them.
+    ///
+    /// Arrays are read as positional parameters and objects as named parameters, whatever
+    /// `either-params` is set to. This requires `param-types` to be enabled.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub params_from_value: bool,
+    /// How the parameters of methods accepting both positional and named parameters are
+    /// deserialized.
+    ///
+    /// With `any`, the format decides whether an array or an object is read, which requires
+    /// it to be self-describing. `by-position` and `by-name` instead always ask for an array or
+    /// an object, which makes those methods usable with formats that don't support
+    /// `deserialize_any`, but self-describing formats such as JSON then reject the other
+    /// structure. Use `params-from-value` to accept both from a `serde_json::Value`.
+    ///
+    /// **Default:** `any`
+    #[serde(default)]
+    pub either_params: EitherParams,
+    /// Whether to generate a macro (such as `get_block_params!`) for each method, building its
+    /// parameter type from `key: value` pairs and defaulting the optional parameters that are
+    /// omitted.
+    ///
+    /// This requires `param-types` to be enabled.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub param_macros: bool,
+    /// Whether to generate, for each OpenRPC `link` of a method, a function building the
+    /// parameters of the linked method from the result of the method.
+    ///
+    /// Link parameters may be constants or references to a field of the result, such as
+    /// `$response.body#/hash`. Links using other runtime expressions, or leaving a required
+    /// parameter unset, are skipped. This requires `param-types` to be enabled.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub link_helpers: bool,
+    /// Whether to generate an `Error` enum with one variant per error that methods may return.
+    ///
+    /// Generation fails if a type of the document is also named `Error`, in which case it must
+    /// be renamed with `fixes.rename`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub error_types: bool,
+    /// Whether the `Error` enum should include an `Unknown` variant for the error codes that are
+    /// not declared in the document, rather than failing to deserialize them.
+    ///
+    /// The `Error` enum is then generated even when the document declares no error.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub open_errors: bool,
+    /// Whether to generate an `error_codes` module with a constant for each standard JSON-RPC
+    /// error code, and for each error code declared in the document.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub error_code_constants: bool,
+    /// Whether to generate a `*Error` enum for each method, with one variant per error the
+    /// method declares, along with conversions from and to the `Error` enum.
+    ///
+    /// This requires `error-types`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub method_errors: bool,
+    /// Whether to generate an `Id` type representing the identifier of a JSON-RPC request.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub id_type: bool,
+    /// Whether to generate a `Request` type representing a JSON-RPC request or notification.
+    ///
+    /// Notifications are serialized without an `id` member.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub request_type: bool,
+    /// Whether to generate a zero-sized `JsonRpcVersion` type, which always serializes as
+    /// `"2.0"` and fails to deserialize from any other value.
+    ///
+    /// Struct fields named `jsonrpc` whose schema only allows the `"2.0"` value (through `const`
+    /// or a single-value `enum`) use this type instead of a string.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub jsonrpc_version: bool,
+    /// A map from struct field paths to the path of a predicate deciding whether the field
+    /// should be skipped during serialization (such as `Vec::is_empty`).
+    ///
+    /// **Default:** `{}`
+    #[serde(default)]
+    pub skip_if: BTreeMap<String, String>,
+    /// Whether to infer a `rename_all` attribute for structs whose fields mostly follow a
+    /// single naming convention in JSON, rather than renaming each field individually.
+    ///
+    /// Fields that don't follow the convention keep their own `rename` attribute. Since fields
+    /// are named after the lowercase form of their name in JSON, which no `rename_all` rule
+    /// produces, this is mostly useful along with `fixes.snake-case-fields`.
+    ///
+    /// The rule is inferred for each struct separately, including the structs generated for the
+    /// object variants of enums, so variants following different conventions each get their own
+    /// `rename_all` attribute.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub infer_rename_all: bool,
+    /// Whether untagged enums with a single variant should be generated as transparent
+    /// newtypes instead.
+    ///
+    /// Tagged enums are never collapsed: their tag is part of their JSON representation, which
+    /// a transparent newtype would drop.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub collapse_single_variant: bool,
+    /// Whether structs with a single required field should be serialized as that field, using
+    /// `#[serde(transparent)]`.
+    ///
+    /// This changes the JSON representation of those structs.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub auto_transparent: bool,
+    /// Whether structs with a single field should implement `Deref` and `DerefMut` to that
+    /// field.
+    ///
+    /// Unlike `auto-transparent`, this only affects the Rust API of those structs.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub wrapper_deref: bool,
+    /// Whether newtypes over a number, such as structs with a single required numeric field,
+    /// should implement `From` their inner type, and their inner type `From` them.
+    ///
+    /// The conversions are unchecked, any constraint of the schema is ignored.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub numeric_newtype_from: bool,
+    /// Whether newtypes over a `Copy` type, such as structs with a single required numeric field,
+    /// should get a `const fn new` constructor, making them usable in `const` and `static` items.
+    ///
+    /// Variants of unit enums are already usable in those contexts and get no constructor.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub const_constructors: bool,
+    /// Whether to generate a `validate` method on structs whose fields declare bounds, such as
+    /// `minimum`, `maxLength` or `minItems`, returning all the bounds violated by the value.
+    ///
+    /// Only the bounds declared by the schemas of the fields themselves are checked, and values
+    /// are never rejected during deserialization because of them. Numeric bounds are only
+    /// checked for fields represented by primitive Rust numbers.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub validate_method: bool,
+    /// Whether to generate named accessor methods on tuple structs, such as `fn block(&self)`.
+    ///
+    /// The names of the accessors are derived from the `title` of the items or from the type
+    /// they reference, falling back to `item{i}`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub tuple_accessors: bool,
+    /// Whether to generate a `*_or_default` accessor for each optional field of structs,
+    /// returning the default value of the schema when the field is absent.
+    ///
+    /// Fields without a default value in the schema fall back to `Default::default()`. Fields
+    /// whose type has no known default value are skipped.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub option_default_accessors: bool,
+    /// Whether the documentation of the generated types should end with the JSON pointer of
+    /// the schema they were generated from.
+    ///
+    /// Unlike `debug-path`, this is part of the documentation of the types.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub source_links: bool,
+    /// Whether the documentation of the generated type aliases should end with the name of the
+    /// aliased type, such as "Alias for `i64`.".
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub alias_underlying_note: bool,
+    /// Whether to emit a `#[cfg(test)]` block naming every type defined by the document, so
+    /// that `cargo test --no-run` checks that they are all generated and resolve.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub emit_existence_checks: bool,
+    /// A list of struct paths for which `PartialEq`, `Eq` and `Hash` should be implemented
+    /// manually, comparing and hashing floating point numbers by their bit patterns.
+    ///
+    /// The structs referenced by the fields of those structs must be listed as well, unless
+    /// they derive `Hash`.
+    ///
+    /// **Default:** `[]`
+    #[serde(default)]
+    pub float_hash: BTreeSet<String>,
+    /// A list of enum paths for which `PartialEq` and `Eq` should be implemented manually,
+    /// considering two values equal when they are the same variant, regardless of their
+    /// payloads.
+    ///
+    /// This is useful for `oneOf` enums whose payloads can't be compared.
+    ///
+    /// **Default:** `[]`
+    #[serde(default)]
+    pub variant_eq: BTreeSet<String>,
+    /// A map from struct paths to the names of fields (as they appear in JSON) that their
+    /// `PartialEq` implementation should ignore.
+    ///
+    /// `PartialEq` is implemented manually for those structs, comparing all the other fields.
+    ///
+    /// **Default:** `{}`
+    #[serde(default)]
+    pub eq_ignore_fields: BTreeMap<String, BTreeSet<String>>,
+    /// A map from type paths to the path of a module used to (de)serialize fields of that type,
+    /// using `#[serde(with = "...")]`.
+    ///
+    /// Fields that are not required use the `option` submodule of that module instead.
+    ///
+    /// **Default:** `{}`
+    #[serde(default)]
+    pub type_serde_with: BTreeMap<String, String>,
+    /// A map from type paths to the path of the `serde` crate their `Serialize` and
+    /// `Deserialize` implementations should use, such as `vendored::serde`.
+    ///
+    /// **Default:** `{}`
+    #[serde(default)]
+    pub serde_path_overrides: BTreeMap<String, String>,
+    /// A map from struct paths to a template from which their `Display` implementation is
+    /// generated, such as `"{id}@{height}"`.
+    ///
+    /// Placeholders name fields by their name in JSON and may include a format spec, such as
+    /// `{hash:?}`. Literal braces are escaped by doubling them.
+    ///
+    /// **Default:** `{}`
+    #[serde(default)]
+    pub display_templates: BTreeMap<String, String>,
+    /// A map from the paths of external types (such as those introduced by `fixes.replace`) to
+    /// their fields and the Rust types of those fields.
+    ///
+    /// A `*Def` shim mirroring each external type is generated using `#[serde(remote = "...")]`,
+    /// and fields of that type are (de)serialized through it. The fields of the external type
+    /// must be public.
+    ///
+    /// **Default:** `{}`
+    #[serde(default)]
+    pub remote_types: BTreeMap<String, BTreeMap<String, String>>,
+    /// Whether types mixing `readOnly` and `writeOnly` fields should be split into a `*Request`
+    /// type (without the `readOnly` fields) used by method parameters, and a `*Response` type
+    /// (without the `writeOnly` fields) used by method results.
+    ///
+    /// Types referencing such types are split as well, and the original types are removed once
+    /// nothing references them. Structs with only `readOnly` fields are left to the
+    /// `strip-read-only-params` fix.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub split_access: bool,
+    /// Whether to generate a `Client` trait with one method per OpenRPC method.
+    ///
+    /// Implementors only need to provide the `call` method, which performs the actual request.
+    /// This requires `param-types` to be enabled.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub client_trait: bool,
+    /// Describes the shape of paginated methods, for which a `*Stream` type is generated to
+    /// fetch the pages of their results one after the other.
+    ///
+    /// This requires `client-trait` to be enabled.
+    ///
+    /// **Default:** `None`
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    /// Whether to add a `#[tracing::instrument]` attribute to the methods of the `Client` trait.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub tracing: bool,
+    /// The path of the `tracing` crate, used when `tracing` is enabled.
+    ///
+    /// **Default:** `tracing`
+    #[serde(default = "defaults::tracing_crate")]
+    pub tracing_crate: String,
+    /// How the `async` methods of the `Client` trait are written.
+    ///
+    /// **Default:** `native`
+    #[serde(default)]
+    pub async_style: AsyncStyle,
+    /// The path of the `async-trait` crate, used when `async-style` is `async-trait`.
+    ///
+    /// **Default:** `async_trait`
+    #[serde(default = "defaults::async_trait_crate")]
+    pub async_trait_crate: String,
+    /// Whether to derive `Copy` for structs whose fields are all `Copy`.
+    ///
+    /// Fields that are optional, arrays or strings are never considered `Copy`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub infer_copy: bool,
+    /// Whether to box the payload of enum variants that are much larger than the other
+    /// variants, which would otherwise trigger the `clippy::large_enum_variant` lint.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub box_large_variants: bool,
+    /// Whether the schemas referenced by the members of `allOf` and `anyOf` schemas should be
+    /// kept as distinct types, flattened into the resulting struct, rather than having their
+    /// fields merged into it.
+    ///
+    /// An `allOf` such as `[{ "$ref": "#/components/schemas/Base" }, { "properties": ... }]`
+    /// then becomes a struct with a `#[serde(flatten)] base: Base` field followed by the inline
+    /// properties. Only the types declared in `components/schemas` are preserved, inline members
+    /// are still merged according to `fixes.auto-flatten-one-fields` and
+    /// `fixes.auto-flatten-one-ref`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub allof_flatten_refs: bool,
+    /// The paths of struct types, such as a common metadata block, whose fields are flattened
+    /// into every struct referencing them rather than nested under the referencing field.
+    ///
+    /// The referencing fields are marked `#[serde(flatten)]`, keeping the type and the
+    /// referencing field in the generated code while making the wire format flat. Only the fields
+    /// referencing the type directly (or through aliases) are flattened, arrays and maps of it
+    /// stay nested.
+    ///
+    /// **Default:** `[]`
+    #[serde(default)]
+    pub common_flatten: BTreeSet<String>,
+    /// Whether to implement `PartialEq<str>` and `PartialEq<&str>` for enums represented as
+    /// strings and for newtypes over strings, comparing their JSON representation.
+    ///
+    /// Such enums also get an `as_str` method returning the name of the variant in JSON.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub str_partial_eq: bool,
+    /// Whether to wrap the generated items in a `#[rustfmt::skip]` module, preventing `rustfmt`
+    /// from reformatting them when it runs over the whole crate.
+    ///
+    /// The items are re-exported from the parent module, but paths starting with `super::` in
+    /// `additional-imports` must account for the extra module. This also prevents `run-rustfmt`
+    /// from having any effect.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub rustfmt_skip: bool,
+    /// Whether to append a comment to the generated file recording the version of openrpc-gen
+    /// and a hash of the input document that produced it.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub footer: bool,
+    /// Whether the footer should also record the time at which the file was generated.
+    ///
+    /// This makes the output differ between runs over the same inputs. This requires `footer`.
+    ///
+    /// **Default:** `false`
+    #[serde(default)]
+    pub footer_timestamp: bool,
+    /// How the generated code should be indented.
+    ///
+    /// **Default:** `{ kind = "spaces", width = 4 }`
+    #[serde(default)]
+    pub indent: Indent,
 }
 
 impl Default for Generation {
     fn default() -> Self {
         Self {
             use_core: false,
+            no_std: false,
+            visibility: defaults::visibility(),
+            module_path: None,
             additional_imports: Vec::new(),
             method_name_prefix: None,
             method_name_constants: false,
+            method_aliases: BTreeMap::new(),
+            method_enum: false,
+            method_markers: false,
+            response_enum: false,
+            method_specs: false,
+            outcome_types: false,
+            value_tryfrom: false,
+            enum_defaults: false,
+            decode_json_strings: false,
+            lenient_bool: false,
+            max_collection_size: None,
+            trim_strings: TrimStrings::default(),
+            capture_extensions: false,
+            bounded_vec: false,
+            arbitrary: false,
             result_types: false,
             param_types: false,
             global_derives: defaults::global_derives(),
             derives: BTreeMap::new(),
+            enum_repr: BTreeMap::new(),
+            variant_constructors: false,
+            params_into_values: false,
+            params_from_tuple: false,
+            params_from_value: false,
+            either_params: EitherParams::Any,
+            param_macros: false,
+            link_helpers: false,
+            error_types: false,
+            open_errors: false,
+            error_code_constants: false,
+            method_errors: false,
+            id_type: false,
+            request_type: false,
+            jsonrpc_version: false,
+            skip_if: BTreeMap::new(),
+            infer_rename_all: false,
+            collapse_single_variant: false,
+            auto_transparent: false,
+            wrapper_deref: false,
+            numeric_newtype_from: false,
+            const_constructors: false,
+            validate_method: false,
+            tuple_accessors: false,
+            option_default_accessors: false,
+            source_links: false,
+            alias_underlying_note: false,
+            emit_existence_checks: false,
+            float_hash: BTreeSet::new(),
+            variant_eq: BTreeSet::new(),
+            eq_ignore_fields: BTreeMap::new(),
+            type_serde_with: BTreeMap::new(),
+            serde_path_overrides: BTreeMap::new(),
+            display_templates: BTreeMap::new(),
+            remote_types: BTreeMap::new(),
+            split_access: false,
+            client_trait: false,
+            pagination: None,
+            tracing: false,
+            tracing_crate: defaults::tracing_crate(),
+            async_style: AsyncStyle::Native,
+            async_trait_crate: defaults::async_trait_crate(),
+            infer_copy: false,
+            box_large_variants: false,
+            allof_flatten_refs: false,
+            common_flatten: BTreeSet::new(),
+            str_partial_eq: false,
+            rustfmt_skip: false,
+            footer: false,
+            footer_timestamp: false,
+            indent: Indent::default(),
+        }
+    }
+}
+
+/// Describes the shape of paginated methods.
+///
+/// A method is paginated when its result is a struct with both the `items` array field and the
+/// optional `next-cursor` field, and when it accepts the optional `cursor-param` parameter, of the
+/// same type as the cursor.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Pagination {
+    /// The name of the result field containing the items of a page.
+    ///
+    /// **Default:** `items`
+    #[serde(default = "defaults::pagination_items")]
+    pub items: String,
+    /// The name of the result field containing the cursor of the next page, if any.
+    ///
+    /// **Default:** `next_cursor`
+    #[serde(default = "defaults::pagination_next_cursor")]
+    pub next_cursor: String,
+    /// The name of the parameter receiving the cursor of the page to fetch.
+    ///
+    /// **Default:** `cursor`
+    #[serde(default = "defaults::pagination_cursor_param")]
+    pub cursor_param: String,
+}
+
+/// How the parameters of methods accepting both structures are deserialized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EitherParams {
+    /// The parameters are read with `deserialize_any`.
+    #[default]
+    Any,
+    /// The parameters are read with `deserialize_seq`.
+    ByPosition,
+    /// The parameters are read with `deserialize_map`.
+    ByName,
+}
+
+/// How the `async` methods of the generated traits are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AsyncStyle {
+    /// Methods are native `async fn`s, which requires Rust 1.75 or later.
+    #[default]
+    Native,
+    /// Methods are written with the `#[async_trait]` macro of the `async-trait` crate, and
+    /// return futures that are `Send`.
+    AsyncTrait,
+}
+
+/// The string fields trimmed by `generation.trim-strings`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum TrimStrings {
+    /// Whether all string fields are trimmed.
+    All(bool),
+    /// The paths of the fields to trim.
+    Fields(BTreeSet<String>),
+}
+
+impl TrimStrings {
+    /// Returns whether some fields may be trimmed.
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Self::All(all) => *all,
+            Self::Fields(fields) => !fields.is_empty(),
+        }
+    }
+
+    /// Returns whether the field at the provided path should be trimmed, if it is a string.
+    pub fn applies_to(&self, path: &str) -> bool {
+        match self {
+            Self::All(all) => *all,
+            Self::Fields(fields) => fields.contains(path),
+        }
+    }
+}
+
+impl Default for TrimStrings {
+    fn default() -> Self {
+        Self::All(false)
+    }
+}
+
+/// The indentation used in the generated code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum Indent {
+    /// Each indentation level is made of a number of spaces.
+    Spaces {
+        /// The number of spaces per indentation level.
+        ///
+        /// **Default:** `4`
+        #[serde(default = "defaults::indent_width")]
+        width: usize,
+    },
+    /// Each indentation level is made of a single tab.
+    Tabs,
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Self::Spaces {
+            width: defaults::indent_width(),
         }
     }
 }
@@ -271,13 +1066,285 @@ pub struct Config {
 
 /// Loads the configuration file from the provided path.
 ///
+/// Options listed in [`ENV_OVERRIDES`] may be overridden by environment variables.
+///
 /// Errors are simply returned as strings.
 pub fn load(path: &Path) -> Result<Config, String> {
     let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
-    let config = toml::from_str(&contents).map_err(|e| e.to_string())?;
+    let mut config = toml::from_str(&contents).map_err(|e| e.to_string())?;
+    apply_env_overrides(&mut config)?;
+    validate(&config)?;
     Ok(config)
 }
 
+/// Returns a boolean option of the [`Generation`] configuration.
+type GenerationFlag = fn(&mut Generation) -> &mut bool;
+
+/// The `generation` options that can be overridden by environment variables, along with the
+/// name of the corresponding variable.
+///
+/// The variables must be set to either `true` or `false`. They are documented in `--help` and in
+/// the README, which must be updated along with this list.
+const ENV_OVERRIDES: &[(&str, GenerationFlag)] = &[
+    ("OPENRPC_GEN_USE_CORE", |g| &mut g.use_core),
+    ("OPENRPC_GEN_NO_STD", |g| &mut g.no_std),
+    ("OPENRPC_GEN_METHOD_NAME_CONSTANTS", |g| {
+        &mut g.method_name_constants
+    }),
+    ("OPENRPC_GEN_RESULT_TYPES", |g| &mut g.result_types),
+    ("OPENRPC_GEN_PARAM_TYPES", |g| &mut g.param_types),
+    ("OPENRPC_GEN_ERROR_TYPES", |g| &mut g.error_types),
+    ("OPENRPC_GEN_CLIENT_TRAIT", |g| &mut g.client_trait),
+    ("OPENRPC_GEN_TRACING", |g| &mut g.tracing),
+];
+
+/// Overrides the options of the configuration with the environment variables listed in
+/// [`ENV_OVERRIDES`].
+fn apply_env_overrides(config: &mut Config) -> Result<(), String> {
+    for (var, option) in ENV_OVERRIDES {
+        let Some(value) = std::env::var_os(var) else {
+            continue;
+        };
+        *option(&mut config.generation) = match value.to_str() {
+            Some("true") => true,
+            Some("false") => false,
+            _ => {
+                return Err(format!(
+                    "invalid value {value:?} for `{var}`: expected `true` or `false`"
+                ))
+            }
+        };
+    }
+    Ok(())
+}
+
+/// Checks the parts of the configuration that cannot be validated during deserialization.
+fn validate(config: &Config) -> Result<(), String> {
+    for (field, predicate) in &config.generation.skip_if {
+        if !is_rust_path(predicate) {
+            return Err(format!(
+                "invalid predicate `{predicate}` for `{field}` in `generation.skip-if`: \
+                expected a path such as `Vec::is_empty`"
+            ));
+        }
+    }
+    for (ty, repr) in &config.generation.enum_repr {
+        if repr_capacity(repr).is_none() {
+            return Err(format!(
+                "invalid representation `{repr}` for `{ty}` in `generation.enum-repr`: \
+                expected `C` or a primitive integer type such as `u8`"
+            ));
+        }
+    }
+    for (ty, module) in &config.generation.type_serde_with {
+        if !is_rust_path(module) {
+            return Err(format!(
+                "invalid module `{module}` for `{ty}` in `generation.type-serde-with`: \
+                expected a path such as `crate::serde_felt`"
+            ));
+        }
+    }
+    if let Some(ref module) = config.generation.module_path {
+        if !is_rust_path(module) {
+            return Err(format!(
+                "invalid module `{module}` in `generation.module-path`: \
+                expected a path such as `crate::generated`"
+            ));
+        }
+    }
+    for (ty, krate) in &config.generation.serde_path_overrides {
+        if !is_rust_path(krate) {
+            return Err(format!(
+                "invalid crate `{krate}` for `{ty}` in `generation.serde-path-overrides`: \
+                expected a path such as `vendored::serde`"
+            ));
+        }
+    }
+    for (ty, template) in &config.generation.display_templates {
+        if let Err(err) = parse_display_template(template) {
+            return Err(format!(
+                "invalid template for `{ty}` in `generation.display-templates`: {err}"
+            ));
+        }
+    }
+    for (ty, fields) in &config.generation.remote_types {
+        if !is_rust_path(ty) {
+            return Err(format!(
+                "invalid type `{ty}` in `generation.remote-types`: \
+                expected a path such as `external::Point`"
+            ));
+        }
+        if fields.is_empty() {
+            return Err(format!(
+                "no fields provided for `{ty}` in `generation.remote-types`"
+            ));
+        }
+    }
+    if let Some(path) = config
+        .generation
+        .eq_ignore_fields
+        .keys()
+        .find(|path| config.generation.float_hash.contains(*path))
+    {
+        return Err(format!(
+            "`{path}` can't be in both `generation.float-hash` and `generation.eq-ignore-fields`"
+        ));
+    }
+    if !is_visibility(&config.generation.visibility) {
+        return Err(format!(
+            "invalid `generation.visibility` `{}`: expected `pub`, `pub(crate)`, `pub(super)`, \
+            `pub(self)` or `pub(in path)`",
+            config.generation.visibility
+        ));
+    }
+    if config.generation.no_std && !config.generation.use_core {
+        return Err("`generation.no-std` requires `generation.use-core`".into());
+    }
+    if config.generation.client_trait && !config.generation.param_types {
+        return Err("`generation.client-trait` requires `generation.param-types`".into());
+    }
+    if config.generation.method_markers
+        && !(config.generation.method_enum && config.generation.param_types)
+    {
+        return Err(
+            "`generation.method-markers` requires `generation.method-enum` and \
+            `generation.param-types`"
+                .into(),
+        );
+    }
+    if config.generation.response_enum && !config.generation.method_enum {
+        return Err("`generation.response-enum` requires `generation.method-enum`".into());
+    }
+    if config.generation.method_errors && !config.generation.error_types {
+        return Err("`generation.method-errors` requires `generation.error-types`".into());
+    }
+    if config.generation.request_type && !config.generation.id_type {
+        return Err("`generation.request-type` requires `generation.id-type`".into());
+    }
+    if config.generation.pagination.is_some() && !config.generation.client_trait {
+        return Err("`generation.pagination` requires `generation.client-trait`".into());
+    }
+    if config.generation.footer_timestamp && !config.generation.footer {
+        return Err("`generation.footer-timestamp` requires `generation.footer`".into());
+    }
+    if config.generation.outcome_types
+        && !(config.generation.result_types
+            && config.generation.error_types
+            && config.generation.open_errors)
+    {
+        return Err(
+            "`generation.outcome-types` requires `generation.result-types`, \
+            `generation.error-types` and `generation.open-errors`"
+                .into(),
+        );
+    }
+    Ok(())
+}
+
+/// Returns whether the provided string is a valid Rust visibility, such as `pub(crate)` or
+/// `pub(in crate::generated)`.
+fn is_visibility(s: &str) -> bool {
+    let Some(restriction) = s.strip_prefix("pub") else {
+        return false;
+    };
+    let restriction = restriction.trim();
+    if restriction.is_empty() {
+        return true;
+    }
+    let Some(restriction) = restriction
+        .strip_prefix('(')
+        .and_then(|r| r.strip_suffix(')'))
+        .map(str::trim)
+    else {
+        return false;
+    };
+    match restriction {
+        "crate" | "super" | "self" => true,
+        _ => restriction
+            .strip_prefix("in ")
+            .map(str::trim)
+            .is_some_and(|path| {
+                let first = path.split("::").next().unwrap_or_default();
+                matches!(first, "crate" | "super" | "self") && is_rust_path(path)
+            }),
+    }
+}
+
+/// Returns the number of discriminants, starting from zero, that fit the provided enum
+/// representation, or `None` if it is not a valid representation.
+///
+/// `C` enums are assumed to be `int`-sized, and `isize`/`usize` to be 32 bits wide.
+pub fn repr_capacity(repr: &str) -> Option<u128> {
+    Some(match repr {
+        "i8" => 1 << 7,
+        "u8" => 1 << 8,
+        "i16" => 1 << 15,
+        "u16" => 1 << 16,
+        "i32" | "isize" | "C" => 1 << 31,
+        "u32" | "usize" => 1 << 32,
+        "i64" => 1 << 63,
+        "u64" => 1 << 64,
+        "i128" => 1 << 127,
+        "u128" => u128::MAX,
+        _ => return None,
+    })
+}
+
+/// Parses a template of `generation.display-templates`.
+///
+/// Returns the equivalent Rust format string, with positional placeholders, along with the JSON
+/// names of the fields referenced by the placeholders in order.
+pub fn parse_display_template(template: &str) -> Result<(String, Vec<&str>), String> {
+    let mut format = String::new();
+    let mut fields = Vec::new();
+    let mut rest = template;
+
+    while let Some(i) = rest.find(['{', '}']) {
+        format.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            format.push_str(&rest[..2]);
+            rest = &rest[2..];
+            continue;
+        }
+        if rest.starts_with('}') {
+            return Err("unmatched `}`".into());
+        }
+        let end = rest.find('}').ok_or("unclosed `{`")?;
+        let (field, spec) = match rest[1..end].split_once(':') {
+            Some((field, spec)) => (field, Some(spec)),
+            None => (&rest[1..end], None),
+        };
+        if field.is_empty() {
+            return Err("placeholders must name a field".into());
+        }
+        format.push('{');
+        if let Some(spec) = spec {
+            format.push(':');
+            format.push_str(spec);
+        }
+        format.push('}');
+        fields.push(field);
+        rest = &rest[end + 1..];
+    }
+    format.push_str(rest);
+
+    Ok((format, fields))
+}
+
+/// Returns whether the provided string is a valid Rust path, such as `Vec::is_empty`.
+fn is_rust_path(s: &str) -> bool {
+    s.strip_prefix("::")
+        .unwrap_or(s)
+        .split("::")
+        .all(|segment| {
+            segment.starts_with(|c: char| c == '_' || c.is_ascii_alphabetic())
+                && segment
+                    .chars()
+                    .all(|c| c == '_' || c.is_ascii_alphanumeric())
+        })
+}
+
 /// Contains the default values for the configuration.
 mod defaults {
     pub fn integer() -> String {
@@ -316,6 +1383,34 @@ mod defaults {
         "num_as_hex".into()
     }
 
+    pub fn visibility() -> String {
+        "pub".into()
+    }
+
+    pub fn tracing_crate() -> String {
+        "tracing".into()
+    }
+
+    pub fn async_trait_crate() -> String {
+        "async_trait".into()
+    }
+
+    pub fn pagination_items() -> String {
+        "items".into()
+    }
+
+    pub fn pagination_next_cursor() -> String {
+        "next_cursor".into()
+    }
+
+    pub fn pagination_cursor_param() -> String {
+        "cursor".into()
+    }
+
+    pub fn indent_width() -> usize {
+        4
+    }
+
     pub fn global_derives() -> Vec<String> {
         vec![String::from("Clone"), String::from("Debug")]
     }
@@ -1,12 +1,21 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use crate::config::Config;
-use crate::parse::{EnumTag, EnumVariant, File, Path, TypeKind, TypeRef, TypeSource};
+use convert_case::{Case, Casing};
+
+use crate::config::{parse_display_template, repr_capacity, AnonNaming, Config, TrimStrings};
+use crate::gen::{CONSTRAINT_VIOLATION_TYPE, JSONRPC_VERSION_TYPE};
+use crate::parse::{
+    EnumTag, EnumVariant, File, Path, StructField, TypeKind, TypeRef, TypeSource,
+    ANONYMOUS_TYPE_NAME,
+};
 
 /// Fixes the provided file according to the provided configuration.
 pub fn fix(file: &mut File, config: &Config) -> Result<(), Vec<String>> {
     let mut errs = Vec::new();
 
+    if config.fixes.anon_naming == AnonNaming::ContentHash {
+        name_anonymous_types_by_hash(file);
+    }
     if config.fixes.strip_enum_variants {
         strip_enum_variants(file);
     }
@@ -14,17 +23,36 @@ pub fn fix(file: &mut File, config: &Config) -> Result<(), Vec<String>> {
     tag_enums(file, &config.fixes.tagged_enums, &mut errs);
     remove_things(file, &config.fixes.remove, &mut errs);
     replace_types(file, &config.fixes.replace, &mut errs);
+    if config.fixes.snake_case_fields {
+        snake_case_field_names(file);
+    }
     rename_things(file, &config.fixes.rename, &mut errs);
     flatten_fields(file, &config.fixes.flatten, &mut errs);
+    let keep_declared = config.generation.allof_flatten_refs;
     if config.fixes.auto_flatten_one_fields {
-        flatten_one_fields(file, &mut errs);
+        flatten_one_fields(file, keep_declared, &mut errs);
     }
     if config.fixes.auto_flatten_one_ref {
-        flatten_one_refs(file, &mut errs);
+        flatten_one_refs(file, keep_declared, &mut errs);
+    }
+    flatten_common_types(file, &config.generation.common_flatten, &mut errs);
+    if config.generation.split_access {
+        split_access(file);
+    }
+    if config.fixes.strip_read_only_params {
+        strip_read_only_params(file);
     }
     if config.fixes.remove_stray_types {
         remove_stray_types(file, &config.fixes.preserve);
     }
+    check_generated_names(file, config, &mut errs);
+    check_display_templates(file, &config.generation.display_templates, &mut errs);
+    check_float_hash(file, config, &mut errs);
+    check_variant_eq(file, &config.generation.variant_eq, &mut errs);
+    check_enum_repr(file, &config.generation.enum_repr, &mut errs);
+    if let TrimStrings::Fields(fields) = &config.generation.trim_strings {
+        check_trimmed_fields(file, config, fields, &mut errs);
+    }
 
     if !errs.is_empty() {
         return Err(errs);
@@ -33,6 +61,218 @@ pub fn fix(file: &mut File, config: &Config) -> Result<(), Vec<String>> {
     Ok(())
 }
 
+/// Names the fields of structs after the snake case form of their name in JSON, rather than its
+/// lowercase form.
+///
+/// Fields whose new name would collide with another field of the same struct are left as-is.
+fn snake_case_field_names(file: &mut File) {
+    for ty in file.types.values_mut() {
+        let TypeKind::Struct(s) = &mut ty.kind else {
+            continue;
+        };
+        let mut names = s
+            .fields
+            .values()
+            .map(|f| f.name.clone())
+            .collect::<BTreeSet<_>>();
+        for field in s.fields.values_mut() {
+            if field.name == field.name_in_json || field.name != field.name_in_json.to_lowercase() {
+                continue;
+            }
+            let name = field.name_in_json.to_case(Case::Snake);
+            if names.insert(name.clone()) {
+                names.remove(&field.name);
+                field.name = name;
+            }
+        }
+    }
+}
+
+/// Names the types defined by inline schemas without a `title` after a hash of their structure.
+///
+/// Types sharing the same name are disambiguated by a numeric suffix, in the order of their
+/// paths.
+fn name_anonymous_types_by_hash(file: &mut File) {
+    let anonymous = file
+        .types
+        .values()
+        .filter(|ty| ty.source == TypeSource::Anonymous && ty.name == ANONYMOUS_TYPE_NAME)
+        .map(|ty| {
+            let mut hash = Fnv::new();
+            hash_type(file, &ty.path, &mut Vec::new(), &mut hash);
+            (ty.path.clone(), hash.0)
+        })
+        .collect::<Vec<_>>();
+
+    let mut taken = file
+        .types
+        .values()
+        .filter(|ty| ty.name != ANONYMOUS_TYPE_NAME)
+        .map(|ty| ty.name.clone())
+        .collect::<BTreeSet<_>>();
+    let mut renamed = BTreeMap::new();
+    for (path, hash) in anonymous {
+        let hex = format!("{:06x}", hash & 0xff_ffff);
+        let base = format!("Anon{}{}", hex[..1].to_ascii_uppercase(), &hex[1..]);
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while !taken.insert(name.clone()) {
+            name = format!("{base}{suffix}");
+            suffix += 1;
+        }
+        file.types.get_mut(&path).unwrap().name = name.clone();
+        renamed.insert(path, name);
+    }
+
+    // Variants of untagged enums are named after their type when they have no title.
+    for ty in file.types.values_mut() {
+        let TypeKind::Enum(e) = &mut ty.kind else {
+            continue;
+        };
+        for variant in e.variants.values_mut() {
+            let Some(TypeRef::Ref(path)) = &variant.ty else {
+                continue;
+            };
+            if variant.name != path.rsplit('/').next().unwrap_or("").to_case(Case::Pascal) {
+                continue;
+            }
+            if let Some(name) = renamed.get(path) {
+                variant.name = name.clone();
+            }
+        }
+    }
+}
+
+/// A 64-bit FNV-1a hasher.
+///
+/// Unlike the hasher of the standard library, its output is guaranteed to remain the same
+/// across Rust versions and platforms.
+pub struct Fnv(u64);
+
+impl Fnv {
+    pub fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Writes a string, followed by a separator preventing ambiguities between consecutive
+    /// strings.
+    fn write_str(&mut self, s: &str) {
+        self.write(s.as_bytes());
+        self.write(&[0xff]);
+    }
+
+    /// Returns the hash of the bytes written so far.
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Hashes the structure of the type at the provided path.
+///
+/// Anonymous types are hashed through their structure, and other types through their path.
+/// `stack` contains the anonymous types being hashed, to handle recursive types.
+fn hash_type(file: &File, path: &Path, stack: &mut Vec<Path>, hash: &mut Fnv) {
+    if let Some(depth) = stack.iter().rposition(|p| p == path) {
+        hash.write_str("recursive");
+        hash.write_str(&(stack.len() - depth).to_string());
+        return;
+    }
+    let Some(ty) = file.types.get(path) else {
+        hash.write_str(path);
+        return;
+    };
+    if ty.source != TypeSource::Anonymous {
+        hash.write_str("ref");
+        hash.write_str(path);
+        return;
+    }
+
+    stack.push(path.clone());
+    match &ty.kind {
+        TypeKind::Struct(s) => {
+            hash.write_str("struct");
+            for (tag, value) in &s.tags {
+                hash.write_str(tag);
+                hash.write_str(value);
+            }
+            for field in s.fields.values() {
+                hash.write_str(&field.name_in_json);
+                hash.write(&[
+                    u8::from(field.required),
+                    u8::from(field.flatten),
+                    u8::from(field.read_only),
+                    u8::from(field.write_only),
+                ]);
+                hash_type_ref(file, &field.ty, stack, hash);
+            }
+        }
+        TypeKind::Enum(e) => {
+            hash.write_str("enum");
+            match &e.tag {
+                EnumTag::Normal => hash.write_str("normal"),
+                EnumTag::Untagged => hash.write_str("untagged"),
+                EnumTag::Tagged(tag) => hash.write_str(tag),
+            }
+            hash.write(&[u8::from(e.nullable)]);
+            for variant in e.variants.values() {
+                hash.write_str(variant.name_in_json.as_deref().unwrap_or(&variant.name));
+                if let Some(ty) = &variant.ty {
+                    hash_type_ref(file, ty, stack, hash);
+                }
+            }
+        }
+        TypeKind::Alias(a) => {
+            hash.write_str("alias");
+            hash_type_ref(file, &a.ty, stack, hash);
+        }
+        TypeKind::Tuple(t) => {
+            hash.write_str("tuple");
+            for item in &t.items {
+                hash_type_ref(file, item, stack, hash);
+            }
+        }
+    }
+    stack.pop();
+}
+
+/// Hashes the structure of the provided type reference.
+fn hash_type_ref(file: &File, r: &TypeRef, stack: &mut Vec<Path>, hash: &mut Fnv) {
+    match r {
+        TypeRef::Ref(path) => hash_type(file, path, stack, hash),
+        TypeRef::ExternalRef(name) => {
+            hash.write_str("external");
+            hash.write_str(name);
+        }
+        TypeRef::Keyword(value) => {
+            hash.write_str("keyword");
+            hash.write_str(value);
+        }
+        TypeRef::Integer { format_as_hex } => {
+            hash.write_str("integer");
+            hash.write(&[u8::from(*format_as_hex)]);
+        }
+        TypeRef::Array(inner, max_items) => {
+            hash.write_str("array");
+            hash.write(&max_items.unwrap_or(u64::MAX).to_le_bytes());
+            hash_type_ref(file, inner, stack, hash);
+        }
+        TypeRef::EncodedJson(inner) => {
+            hash.write_str("json");
+            hash_type_ref(file, inner, stack, hash);
+        }
+        TypeRef::Boolean | TypeRef::String | TypeRef::Number | TypeRef::Null => {
+            hash.write_str(r.name());
+        }
+    }
+}
+
 fn strip_enum_variants(file: &mut File) {
     for ty in file.types.values_mut() {
         if let TypeKind::Enum(en) = &mut ty.kind {
@@ -90,7 +330,7 @@ fn flatten_fields(file: &mut File, paths: &[String], errs: &mut Vec<String>) {
                         continue;
                     }
                 }
-                TypeKind::Enum(_) => continue,
+                TypeKind::Enum(_) | TypeKind::Tuple(_) => continue,
             }
 
             types.insert(path.clone());
@@ -111,7 +351,10 @@ fn flatten_fields(file: &mut File, paths: &[String], errs: &mut Vec<String>) {
     }
 }
 
-fn flatten_one_fields(file: &mut File, errs: &mut Vec<String>) {
+/// Flattens the fields referencing a struct with a single field.
+///
+/// When `keep_declared` is set, the types declared in `components/schemas` are left untouched.
+fn flatten_one_fields(file: &mut File, keep_declared: bool, errs: &mut Vec<String>) {
     let mut fields = Vec::new();
 
     for ty in file.types.values() {
@@ -128,6 +371,9 @@ fn flatten_one_fields(file: &mut File, errs: &mut Vec<String>) {
             let Some(target_ty) = file.types.get(r) else {
                 continue;
             };
+            if keep_declared && target_ty.source == TypeSource::Declared {
+                continue;
+            }
             let TypeKind::Struct(target_s) = &target_ty.kind else {
                 continue;
             };
@@ -145,7 +391,11 @@ fn flatten_one_fields(file: &mut File, errs: &mut Vec<String>) {
     }
 }
 
-fn flatten_one_refs(file: &mut File, errs: &mut Vec<String>) {
+/// Flattens the fields and aliases that are the only referent of their type.
+///
+/// When `keep_declared` is set, the fields referencing types declared in `components/schemas`
+/// are left untouched.
+fn flatten_one_refs(file: &mut File, keep_declared: bool, errs: &mut Vec<String>) {
     let mut fields = Vec::new();
     let mut aliases = Vec::new();
 
@@ -159,10 +409,11 @@ fn flatten_one_refs(file: &mut File, errs: &mut Vec<String>) {
                     let TypeRef::Ref(ty_path) = &field.ty else {
                         continue;
                     };
-                    if !matches!(
-                        file.types.get(ty_path).unwrap().kind,
-                        TypeKind::Alias(_) | TypeKind::Struct(_)
-                    ) {
+                    let target_ty = file.types.get(ty_path).unwrap();
+                    if !matches!(target_ty.kind, TypeKind::Alias(_) | TypeKind::Struct(_)) {
+                        continue;
+                    }
+                    if keep_declared && target_ty.source == TypeSource::Declared {
                         continue;
                     }
                     if count_refs(file, ty_path) == 1 {
@@ -223,10 +474,283 @@ fn flatten_one_refs(file: &mut File, errs: &mut Vec<String>) {
     }
 }
 
+/// Marks the fields referencing one of the provided struct types as flattened.
+fn flatten_common_types(file: &mut File, paths: &BTreeSet<String>, errs: &mut Vec<String>) {
+    let mut fields = Vec::new();
+
+    for path in paths {
+        let Some(TypeKind::Struct(common)) = file.types.get(path.as_str()).map(|ty| &ty.kind)
+        else {
+            errs.push(format!(
+                "\
+                can't flatten common type: struct not found:\n\
+                - path = {path}\n\
+                ",
+            ));
+            continue;
+        };
+        for ty in file.types.values() {
+            let TypeKind::Struct(s) = &ty.kind else {
+                continue;
+            };
+            for field in s.fields.values() {
+                if field.flatten
+                    || !matches!(file.unalias(&field.ty), TypeRef::Ref(r) if &**r == path)
+                {
+                    continue;
+                }
+                let collision = s.fields.values().find(|f| {
+                    !f.flatten
+                        && common
+                            .fields
+                            .values()
+                            .any(|c| c.name_in_json == f.name_in_json)
+                });
+                if let Some(collision) = collision {
+                    errs.push(format!(
+                        "\
+                        can't flatten common type: field already exists:\n\
+                        - path = {path}\n\
+                        - field = {}\n\
+                        ",
+                        collision.path,
+                    ));
+                    continue;
+                }
+                fields.push(field.path.clone());
+            }
+        }
+    }
+
+    for ty in file.types.values_mut() {
+        let TypeKind::Struct(s) = &mut ty.kind else {
+            continue;
+        };
+        for field in s.fields.values_mut() {
+            if fields.contains(&field.path) {
+                field.flatten = true;
+            }
+        }
+    }
+}
+
+/// Creates a copy of the structs used as method parameters without their `readOnly` fields, and
+/// makes the parameters reference those copies instead.
+///
+/// The original structs are left untouched, meaning that they can still be used as method
+/// results.
+fn strip_read_only_params(file: &mut File) {
+    let mut copies = BTreeMap::new();
+
+    for method in &mut file.methods {
+        for param in &mut method.params {
+            let Some(path) = param.ty.inner_path() else {
+                continue;
+            };
+            let Some(ty) = file.types.get(path) else {
+                continue;
+            };
+            let TypeKind::Struct(s) = &ty.kind else {
+                continue;
+            };
+            if !s.fields.values().any(|f| f.read_only) {
+                continue;
+            }
+
+            let copy_path = Path::from(format!("{path}/_request"));
+            if !copies.contains_key(&copy_path) {
+                let mut copy = ty.clone();
+                copy.path = copy_path.clone();
+                copy.name = format!("{}Request", ty.name);
+                copy.source = TypeSource::Anonymous;
+                if let TypeKind::Struct(s) = &mut copy.kind {
+                    s.fields.retain(|_, f| !f.read_only);
+                }
+                copies.insert(copy_path.clone(), copy);
+            }
+
+            retarget_ref(&mut param.ty, &copy_path);
+        }
+    }
+
+    file.types.append(&mut copies);
+}
+
+/// Splits the structs mixing `readOnly` and `writeOnly` fields, as well as the types referencing
+/// them, into a `*Request` copy without the `readOnly` fields and a `*Response` copy without the
+/// `writeOnly` fields.
+///
+/// Method parameters are made to reference the request copies, and method results the response
+/// copies. Copies are only created for the directions in which a type is actually used, and the
+/// original types are removed once nothing references them anymore.
+fn split_access(file: &mut File) {
+    // Find the types that must be split, starting with the structs mixing access modes and
+    // propagating to the types referencing them.
+    let mut to_split = file
+        .types
+        .values()
+        .filter(|ty| match &ty.kind {
+            TypeKind::Struct(s) => {
+                s.fields.values().any(|f| f.read_only) && s.fields.values().any(|f| f.write_only)
+            }
+            _ => false,
+        })
+        .map(|ty| ty.path.clone())
+        .collect::<BTreeSet<_>>();
+    loop {
+        let more = file
+            .types
+            .values()
+            .filter(|ty| !to_split.contains(&ty.path))
+            .filter(|ty| {
+                type_kind_refs(&ty.kind)
+                    .iter()
+                    .any(|r| r.inner_path().is_some_and(|p| to_split.contains(p)))
+            })
+            .map(|ty| ty.path.clone())
+            .collect::<Vec<_>>();
+        if more.is_empty() {
+            break;
+        }
+        to_split.extend(more);
+    }
+
+    let params = file
+        .methods
+        .iter()
+        .flat_map(|m| m.params.iter().filter_map(|p| p.ty.inner_path()).cloned())
+        .collect();
+    let results = file
+        .methods
+        .iter()
+        .filter_map(|m| m.result.as_ref().and_then(|r| r.ty.inner_path()).cloned())
+        .collect();
+
+    let mut copies = BTreeMap::new();
+    let mut split = |roots: Vec<Path>, suffix: &str, keep: fn(&StructField) -> bool| {
+        let renames = reachable_types(file, roots)
+            .into_iter()
+            .filter(|path| to_split.contains(path))
+            .map(|path| {
+                let copy_path = Path::from(format!("{path}/_{}", suffix.to_lowercase()));
+                (path, copy_path)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        for (path, copy_path) in &renames {
+            let mut copy = file.types[path].clone();
+            copy.path = copy_path.clone();
+            copy.name = format!("{}{suffix}", copy.name);
+            copy.source = TypeSource::Anonymous;
+            if let TypeKind::Struct(s) = &mut copy.kind {
+                s.fields.retain(|_, f| keep(f));
+            }
+            for r in type_kind_refs_mut(&mut copy.kind) {
+                remap_ref(r, &renames);
+            }
+            copies.insert(copy_path.clone(), copy);
+        }
+
+        renames
+    };
+    let request_renames = split(params, "Request", |f| !f.read_only);
+    let response_renames = split(results, "Response", |f| !f.write_only);
+
+    for method in &mut file.methods {
+        for param in &mut method.params {
+            remap_ref(&mut param.ty, &request_renames);
+        }
+        if let Some(result) = &mut method.result {
+            remap_ref(&mut result.ty, &response_renames);
+        }
+    }
+
+    file.types.append(&mut copies);
+
+    // The original types are not referenced by methods anymore, but they may still be referenced
+    // by types that were not split.
+    let roots = file
+        .types
+        .keys()
+        .filter(|path| !to_split.contains(*path))
+        .cloned()
+        .chain(method_type_refs(file))
+        .collect();
+    let referenced = reachable_types(file, roots);
+    file.types
+        .retain(|path, _| !to_split.contains(path) || referenced.contains(path));
+}
+
+/// Returns the paths of the types reachable from the provided roots, including the roots.
+fn reachable_types(file: &File, mut to_visit: Vec<Path>) -> BTreeSet<Path> {
+    let mut reachable = BTreeSet::new();
+    while let Some(path) = to_visit.pop() {
+        if !reachable.insert(path.clone()) {
+            continue;
+        }
+        if let Some(ty) = file.types.get(&path) {
+            to_visit.extend(
+                type_kind_refs(&ty.kind)
+                    .into_iter()
+                    .filter_map(|r| r.inner_path())
+                    .cloned(),
+            );
+        }
+    }
+    reachable
+}
+
+/// Returns the type references directly contained in the provided [`TypeKind`].
+fn type_kind_refs(kind: &TypeKind) -> Vec<&TypeRef> {
+    match kind {
+        TypeKind::Struct(s) => s.fields.values().map(|f| &f.ty).collect(),
+        TypeKind::Enum(e) => e.variants.values().filter_map(|v| v.ty.as_ref()).collect(),
+        TypeKind::Alias(a) => vec![&a.ty],
+        TypeKind::Tuple(t) => t.items.iter().collect(),
+    }
+}
+
+/// Returns the type references directly contained in the provided [`TypeKind`], mutably.
+fn type_kind_refs_mut(kind: &mut TypeKind) -> Vec<&mut TypeRef> {
+    match kind {
+        TypeKind::Struct(s) => s.fields.values_mut().map(|f| &mut f.ty).collect(),
+        TypeKind::Enum(e) => e
+            .variants
+            .values_mut()
+            .filter_map(|v| v.ty.as_mut())
+            .collect(),
+        TypeKind::Alias(a) => vec![&mut a.ty],
+        TypeKind::Tuple(t) => t.items.iter_mut().collect(),
+    }
+}
+
+/// Makes the provided [`TypeRef`] reference the type its inner type is mapped to in `renames`,
+/// if any.
+fn remap_ref(ty: &mut TypeRef, renames: &BTreeMap<Path, Path>) {
+    match ty {
+        TypeRef::Ref(path) => {
+            if let Some(to) = renames.get(path) {
+                *path = to.clone();
+            }
+        }
+        TypeRef::Array(inner, _) | TypeRef::EncodedJson(inner) => remap_ref(inner, renames),
+        _ => (),
+    }
+}
+
+/// Makes the provided [`TypeRef`] reference `to` instead of its current inner type.
+fn retarget_ref(ty: &mut TypeRef, to: &Path) {
+    match ty {
+        TypeRef::Ref(path) => *path = to.clone(),
+        TypeRef::Array(inner, _) | TypeRef::EncodedJson(inner) => retarget_ref(inner, to),
+        _ => (),
+    }
+}
+
 fn get_inner_ref(r: &TypeRef) -> Option<&Path> {
     match r {
         TypeRef::Ref(r) => Some(r),
-        TypeRef::Array(r) => get_inner_ref(r),
+        TypeRef::Array(r, _) | TypeRef::EncodedJson(r) => get_inner_ref(r),
         _ => None,
     }
 }
@@ -257,6 +781,13 @@ fn count_refs(file: &File, type_path: &str) -> usize {
                     count += 1;
                 }
             }
+            TypeKind::Tuple(t) => {
+                for item in &t.items {
+                    if get_inner_ref(item).is_some_and(|p| &**p == type_path) {
+                        count += 1;
+                    }
+                }
+            }
         }
     }
 
@@ -345,7 +876,7 @@ fn flatten_field(file: &mut File, path: &str) -> Result<(), String> {
             s.fields.remove(path);
             s.fields.append(&mut fields_to_add);
         }
-        TypeKind::Enum(_) => {
+        TypeKind::Enum(_) | TypeKind::Tuple(_) => {
             return Err(format!(
                 "\
             can't flatten: target type is not a struct:\n\
@@ -389,7 +920,7 @@ fn remove_thing(file: &mut File, path: &str) -> bool {
                     return true;
                 }
             }
-            TypeKind::Alias(_) => (),
+            TypeKind::Alias(_) | TypeKind::Tuple(_) => (),
         }
     }
 
@@ -424,7 +955,7 @@ fn rename_thing(file: &mut File, path: &str, by: &str) -> Result<(), String> {
                     return Ok(());
                 }
             }
-            TypeKind::Alias(_) => (),
+            TypeKind::Alias(_) | TypeKind::Tuple(_) => (),
         }
     }
 
@@ -457,7 +988,9 @@ fn replace_type(file: &mut File, path: &str, by: &str) -> bool {
             TypeRef::Ref(p) if &**p == src => {
                 *ty = TypeRef::ExternalRef(dst);
             }
-            TypeRef::Array(inner) => replace_ref(&mut *inner, src, dst),
+            TypeRef::Array(inner, _) | TypeRef::EncodedJson(inner) => {
+                replace_ref(&mut *inner, src, dst)
+            }
             _ => (),
         }
     }
@@ -479,6 +1012,11 @@ fn replace_type(file: &mut File, path: &str, by: &str) -> bool {
             TypeKind::Alias(a) => {
                 replace_ref(&mut a.ty, path, by.into());
             }
+            TypeKind::Tuple(t) => {
+                for item in &mut t.items {
+                    replace_ref(item, path, by.into());
+                }
+            }
         }
     }
 
@@ -496,66 +1034,109 @@ fn replace_type(file: &mut File, path: &str, by: &str) -> bool {
 }
 
 fn remove_stray_types(file: &mut File, preserve: &BTreeSet<String>) {
-    // The set of all nodes that are known not be stray types.
-    let mut not_stray = BTreeSet::new();
-    // Nodes to visit next.
-    let mut to_visit = file
+    let roots = file
         .types
         .values()
         .filter(|ty| ty.source == TypeSource::Method || preserve.contains(&*ty.path))
         .map(|ty| ty.path.clone())
-        .chain(
-            file.methods
-                .iter()
-                .filter_map(|m| m.result.as_ref().and_then(|r| r.ty.inner_path()).cloned()),
-        )
-        .chain(
-            file.methods
-                .iter()
-                .flat_map(|m| m.params.iter().filter_map(|p| p.ty.inner_path()).cloned()),
-        )
-        .collect::<Vec<_>>();
+        .chain(method_type_refs(file))
+        .collect();
 
-    fn take_ref_into_account(r: &TypeRef, to_visit: &mut Vec<Path>) {
-        if let Some(r) = r.inner_path() {
-            to_visit.push(r.clone());
-        }
-    }
-
-    // Visit the graph to find all the nodes that are not stray types.
-    while let Some(path) = to_visit.pop() {
-        if !not_stray.insert(path.clone()) {
-            continue;
-        }
-
-        let ty = match file.types.get(&path) {
-            Some(ty) => ty,
+    let not_stray = reachable_types(file, roots);
+    file.types.retain(|_, ty| not_stray.contains(&ty.path));
+}
 
-            // This branch can be taken if the user has removed a type that's
-            // still referenced by another type.
-            None => continue,
-        };
+/// Keeps only the methods whose name matches one of the provided glob patterns and the types
+/// whose path does, along with the types and errors they transitively reference.
+///
+/// A warning is returned for each pattern that matches nothing.
+pub fn retain_only(file: &mut File, patterns: &[String]) -> Vec<String> {
+    let warnings = patterns
+        .iter()
+        .filter(|pattern| {
+            !file.methods.iter().any(|m| glob_matches(pattern, &m.name))
+                && !file.types.keys().any(|path| glob_matches(pattern, path))
+        })
+        .map(|pattern| {
+            format!(
+                "\
+                pattern passed to `--only` matches no method or type\n\
+                - pattern = {pattern}\n\
+                "
+            )
+        })
+        .collect();
+
+    let matches = |s: &str| patterns.iter().any(|pattern| glob_matches(pattern, s));
+
+    file.methods.retain(|m| matches(&m.name));
+    let errors = file
+        .methods
+        .iter()
+        .flat_map(|m| m.errors.iter())
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    file.errors.retain(|path, _| errors.contains(path));
+
+    // Types defined inline in the schemas of the methods have their path rooted at those
+    // methods, so they are reached through the methods rather than kept on their own.
+    let roots = file
+        .types
+        .keys()
+        .filter(|path| matches(path))
+        .cloned()
+        .chain(method_type_refs(file))
+        .collect();
+    let reachable = reachable_types(file, roots);
+    file.types.retain(|path, _| reachable.contains(path));
+
+    warnings
+}
 
-        match &ty.kind {
-            TypeKind::Struct(s) => {
-                for field in s.fields.values() {
-                    take_ref_into_account(&field.ty, &mut to_visit);
-                }
+/// Returns whether the provided string matches the provided glob pattern, in which `*` matches
+/// any sequence of characters and `?` matches any single character.
+fn glob_matches(pattern: &str, s: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let s = s.chars().collect::<Vec<_>>();
+
+    // The position after the last `*` seen in the pattern, along with the position in the
+    // string it was matched against so far.
+    let mut backtrack = None;
+    let (mut p, mut i) = (0, 0);
+    while i < s.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                backtrack = Some((p, i));
             }
-            TypeKind::Enum(e) => {
-                for variant in e.variants.values() {
-                    if let Some(r) = &variant.ty {
-                        take_ref_into_account(r, &mut to_visit);
-                    }
-                }
-            }
-            TypeKind::Alias(r) => {
-                take_ref_into_account(&r.ty, &mut to_visit);
+            Some(&c) if c == '?' || c == s[i] => {
+                p += 1;
+                i += 1;
             }
+            _ => match backtrack {
+                Some((bp, bi)) => {
+                    p = bp;
+                    i = bi + 1;
+                    backtrack = Some((bp, bi + 1));
+                }
+                None => return false,
+            },
         }
     }
 
-    file.types.retain(|_, ty| not_stray.contains(&ty.path));
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns the paths of the types directly referenced by the parameters and results of the
+/// methods of the provided file.
+fn method_type_refs(file: &File) -> impl Iterator<Item = Path> + '_ {
+    file.methods.iter().flat_map(|m| {
+        m.params
+            .iter()
+            .map(|p| &p.ty)
+            .chain(m.result.as_ref().map(|r| &r.ty))
+            .filter_map(|ty| ty.inner_path().cloned())
+    })
 }
 
 fn tag_enums(file: &mut File, tagged: &BTreeMap<String, String>, errs: &mut Vec<String>) {
@@ -833,6 +1414,256 @@ fn find_keyword(file: &File, path: Path, name: &str) -> Result<FindKeywordResult
 
             find_keyword(file, r.clone(), name)
         }
+        TypeKind::Tuple(_) => Err(format!(
+            "\
+            failed to tag enum: tuples have no fields\n\
+            - path = {path}\n\
+            - name = {name}\n\
+            ",
+        )),
+    }
+}
+
+/// Checks that the fields referenced by `generation.display-templates` exist in the structs they
+/// are provided for.
+fn check_display_templates(
+    file: &File,
+    templates: &BTreeMap<String, String>,
+    errs: &mut Vec<String>,
+) {
+    for (path, template) in templates {
+        let Some(TypeKind::Struct(s)) = file.types.get(path.as_str()).map(|ty| &ty.kind) else {
+            errs.push(format!(
+                "\
+                can't generate `Display`: struct not found:\n\
+                - path = {path}\n\
+                ",
+            ));
+            continue;
+        };
+        // Templates are validated when the configuration is loaded.
+        let Ok((_, fields)) = parse_display_template(template) else {
+            continue;
+        };
+        for field in fields {
+            if !s.fields.values().any(|f| f.name_in_json == field) {
+                errs.push(format!(
+                    "\
+                    can't generate `Display`: field not found:\n\
+                    - path = {path}\n\
+                    - field = {field}\n\
+                    ",
+                ));
+            }
+        }
+    }
+}
+
+/// Checks that the enums listed in `generation.variant-eq` exist and don't already derive
+/// `PartialEq`.
+fn check_variant_eq(file: &File, paths: &BTreeSet<String>, errs: &mut Vec<String>) {
+    for path in paths {
+        match file.types.get(path.as_str()).map(|ty| &ty.kind) {
+            Some(TypeKind::Enum(e)) if e.copy => errs.push(format!(
+                "\
+                can't implement `PartialEq` by variant: enum already derives `PartialEq`:\n\
+                - path = {path}\n\
+                ",
+            )),
+            Some(TypeKind::Enum(_)) => (),
+            _ => errs.push(format!(
+                "\
+                can't implement `PartialEq` by variant: enum not found:\n\
+                - path = {path}\n\
+                ",
+            )),
+        }
+    }
+}
+
+/// Checks that the structs listed in `generation.float-hash` exist, and that the types their
+/// fields reference implement `Hash` as well.
+fn check_float_hash(file: &File, config: &Config, errs: &mut Vec<String>) {
+    let g = &config.generation;
+    let has_hash = |path: &Path| {
+        g.float_hash.contains(&**path)
+            || g.global_derives.iter().any(|d| d == "Hash")
+            || g.derives
+                .get(&**path)
+                .is_some_and(|derives| derives.iter().any(|d| d == "Hash"))
+    };
+
+    for path in &g.float_hash {
+        let Some(TypeKind::Struct(s)) = file.types.get(path.as_str()).map(|ty| &ty.kind) else {
+            errs.push(format!(
+                "\
+                can't implement `Hash` by bit patterns: struct not found:\n\
+                - path = {path}\n\
+                ",
+            ));
+            continue;
+        };
+
+        for field in s.fields.values() {
+            let mut to_visit = field
+                .ty
+                .inner_path()
+                .into_iter()
+                .cloned()
+                .collect::<Vec<_>>();
+            while let Some(nested) = to_visit.pop() {
+                match file.types.get(&nested).map(|ty| &ty.kind) {
+                    Some(TypeKind::Alias(a)) => to_visit.extend(a.ty.inner_path().cloned()),
+                    Some(TypeKind::Enum(e)) if e.copy => (),
+                    Some(_) if !has_hash(&nested) => errs.push(format!(
+                        "\
+                        can't implement `Hash` by bit patterns: a field references a type \
+                        without `Hash`, which should be listed in `generation.float-hash` \
+                        as well:\n\
+                        - path = {path}\n\
+                        - field = {}\n\
+                        - type = {nested}\n\
+                        ",
+                        field.name_in_json,
+                    )),
+                    _ => (),
+                }
+            }
+        }
+    }
+}
+
+/// Checks that the types generated by the enabled options, such as the unified `Error` enum, are
+/// not named like a type of the document, and that the variants they add next to the errors of
+/// the document are not named like one of them.
+fn check_generated_names(file: &File, config: &Config, errs: &mut Vec<String>) {
+    let g = &config.generation;
+    let has_methods = !file.methods.is_empty();
+    let generated = [
+        (
+            "Error",
+            "error-types",
+            g.error_types && (g.open_errors || !file.errors.is_empty()),
+        ),
+        ("Method", "method-enum", g.method_enum && has_methods),
+        (
+            "Response",
+            "response-enum",
+            g.method_enum && g.response_enum && has_methods,
+        ),
+        ("Id", "id-type", g.id_type),
+        ("Request", "request-type", g.request_type),
+        (JSONRPC_VERSION_TYPE, "jsonrpc-version", g.jsonrpc_version),
+        (
+            CONSTRAINT_VIOLATION_TYPE,
+            "validate-method",
+            g.validate_method,
+        ),
+        ("BoundedVec", "bounded-vec", g.bounded_vec),
+    ];
+    for ty in file.types.values() {
+        for (name, option, enabled) in generated {
+            if enabled && ty.name == name {
+                errs.push(format!(
+                    "\
+                    can't generate `{name}`: a type of the document has the same name:\n\
+                    - path = {}\n\
+                    - option = generation.{option}\n\
+                    ",
+                    ty.path,
+                ));
+            }
+        }
+    }
+
+    for error in file.errors.values() {
+        let in_outcome = g.outcome_types
+            && file
+                .methods
+                .iter()
+                .any(|m| m.result.is_some() && m.errors.contains(&error.path));
+        let variants = [
+            ("Unknown", "open-errors", g.error_types && g.open_errors),
+            ("Ok", "outcome-types", in_outcome),
+            ("Other", "outcome-types", in_outcome),
+        ];
+        for (name, option, enabled) in variants {
+            if enabled && error.name == name {
+                errs.push(format!(
+                    "\
+                    can't generate the `{name}` variant: an error of the document has the same name:\n\
+                    - path = {}\n\
+                    - option = generation.{option}\n\
+                    ",
+                    error.path,
+                ));
+            }
+        }
+    }
+}
+
+/// Checks that the enums listed in `generation.enum-repr` exist, and that the discriminants of
+/// their variants fit their representation.
+fn check_enum_repr(file: &File, reprs: &BTreeMap<String, String>, errs: &mut Vec<String>) {
+    for (path, repr) in reprs {
+        let Some(TypeKind::Enum(e)) = file.types.get(path.as_str()).map(|ty| &ty.kind) else {
+            errs.push(format!(
+                "\
+                can't set the representation: enum not found:\n\
+                - path = {path}\n\
+                ",
+            ));
+            continue;
+        };
+        // Representations are validated when the configuration is loaded.
+        let Some(capacity) = repr_capacity(repr) else {
+            continue;
+        };
+        if e.variants.len() as u128 > capacity {
+            errs.push(format!(
+                "\
+                can't set the representation: discriminants don't fit:\n\
+                - path = {path}\n\
+                - repr = {repr}\n\
+                - variants = {}\n\
+                ",
+                e.variants.len(),
+            ));
+        }
+    }
+}
+
+/// Checks that the fields listed in `generation.trim-strings` exist, are strings, and are not
+/// already deserialized by another module.
+fn check_trimmed_fields(
+    file: &File,
+    config: &Config,
+    paths: &BTreeSet<String>,
+    errs: &mut Vec<String>,
+) {
+    for path in paths {
+        let field = file.types.values().find_map(|ty| match &ty.kind {
+            TypeKind::Struct(s) => s.fields.get(path.as_str()),
+            _ => None,
+        });
+        let Some(field) = field.filter(|f| matches!(file.unalias(&f.ty), TypeRef::String)) else {
+            errs.push(format!(
+                "\
+                can't trim: string field not found:\n\
+                - path = {path}\n\
+                ",
+            ));
+            continue;
+        };
+        if let Some(attr) = field.ty.attributes(config, file, field.required).first() {
+            errs.push(format!(
+                "\
+                can't trim: field already has a custom deserializer:\n\
+                - path = {path}\n\
+                - attribute = {attr}\n\
+                ",
+            ));
+        }
     }
 }
 
@@ -1,12 +1,18 @@
 //! Contains the code that actually generates the Rust code.
 
 use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
 use std::io;
 
-use convert_case::{Case, Casing};
+use convert_case::{Boundary, Case, Casing};
 use open_rpc::ParamStructure;
 
-use crate::parse::{EnumTag, TypeDef, TypeKind, TypeRef};
+use crate::parse::{
+    remote_shim_names, Bound, EnumDef, EnumTag, EnumVariant, LinkParam, MethodParameter, Path,
+    StructDef, StructField, TupleDef, TypeDef, TypeKind, TypeRef, CAPPED_MODULE, EXTENSIONS_MODULE,
+    JSON_STRING_MODULE, LENIENT_BOOL_MODULE, SHARED_STR_MODULE, TRIMMED_STRING_MODULE,
+};
 
 /// Contains the state of the generator.
 struct Ctx<'a> {
@@ -19,15 +25,658 @@ struct Ctx<'a> {
 }
 
 impl<'a> Ctx<'a> {
+    /// Returns the additional names under which the provided method is available.
+    pub fn method_aliases(&self, method: &crate::parse::Method) -> &'a [String] {
+        self.config
+            .generation
+            .method_aliases
+            .get(&method.name)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the name of the provided method, without the configured prefix.
+    ///
+    /// This is used as the base of the identifiers generated for the method.
+    pub fn method_ident_base<'m>(&self, method: &'m crate::parse::Method) -> &'m str {
+        match self.config.generation.method_name_prefix {
+            Some(ref prefix) => method.name.strip_prefix(prefix).unwrap_or(&method.name),
+            None => &method.name,
+        }
+    }
+
+    /// Returns the type of the only variant of the provided enum, if it should be generated as a
+    /// newtype rather than an enum.
+    pub fn collapsed_variant(&self, e: &'a EnumDef) -> Option<&'a TypeRef> {
+        if !self.config.generation.collapse_single_variant || !matches!(e.tag, EnumTag::Untagged) {
+            return None;
+        }
+
+        let mut variants = e.variants.values();
+        match (variants.next(), variants.next()) {
+            (Some(variant), None) => variant.ty.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the path of the map type holding the vendor extensions of objects.
+    ///
+    /// `HashMap` is not available in `alloc`, so `BTreeMap` is used without `std`.
+    pub fn extensions_map(&self) -> &'static str {
+        if self.config.generation.no_std {
+            "alloc::collections::BTreeMap"
+        } else {
+            "std::collections::HashMap"
+        }
+    }
+
+    /// Returns whether an `extensions` field capturing the `x-` properties of the object should
+    /// be added to the provided struct.
+    pub fn captures_extensions(&self, s: &StructDef) -> bool {
+        self.config.generation.capture_extensions
+            && !self.is_transparent(s)
+            && s.fields
+                .values()
+                .all(|f| !f.flatten && f.name != "extensions")
+    }
+
+    /// Returns the only field of the provided struct, if `Deref` should be implemented to it.
+    pub fn wrapped_field(&self, s: &'a StructDef) -> Option<&'a StructField> {
+        if !self.config.generation.wrapper_deref {
+            return None;
+        }
+
+        let mut fields = s.fields.values();
+        match (fields.next(), fields.next()) {
+            (Some(field), None) => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the provided type is a number, looking through aliases.
+    pub fn is_numeric(&self, ty: &TypeRef) -> bool {
+        matches!(
+            self.file.unalias(ty),
+            TypeRef::Integer { .. } | TypeRef::Number
+        )
+    }
+
+    /// Returns the only field of the provided struct, if it is a required number and
+    /// `numeric-newtype-from` is enabled.
+    pub fn numeric_newtype_field(&self, s: &'a StructDef) -> Option<&'a StructField> {
+        if !self.config.generation.numeric_newtype_from || self.captures_extensions(s) {
+            return None;
+        }
+
+        let mut fields = s.fields.values();
+        match (fields.next(), fields.next()) {
+            (Some(field), None)
+                if field.required && !field.flatten && self.is_numeric(&field.ty) =>
+            {
+                Some(field)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the only field of the provided struct, if it is a required `Copy` field and
+    /// `const-constructors` is enabled.
+    pub fn const_newtype_field(&self, s: &'a StructDef) -> Option<&'a StructField> {
+        if !self.config.generation.const_constructors || self.captures_extensions(s) {
+            return None;
+        }
+
+        let mut fields = s.fields.values();
+        match (fields.next(), fields.next()) {
+            (Some(field), None)
+                if field.required && !field.flatten && self.is_copy(&field.ty, 0) =>
+            {
+                Some(field)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether the provided struct should be serialized as its only field.
+    pub fn is_transparent(&self, s: &StructDef) -> bool {
+        if !self.config.generation.auto_transparent || !s.tags.is_empty() {
+            return false;
+        }
+
+        let mut fields = s.fields.values();
+        match (fields.next(), fields.next()) {
+            (Some(field), None) => field.required && !field.flatten,
+            _ => false,
+        }
+    }
+
+    /// Returns the variants of the provided enum in the order in which they should be generated.
+    ///
+    /// `serde` tries the variants of untagged enums in order, so the most specific ones are put
+    /// first unless the enum requires its order to be preserved. Variants that are as specific
+    /// as each other keep the order of the document.
+    pub fn ordered_variants(&self, e: &'a EnumDef) -> Vec<&'a EnumVariant> {
+        let mut variants: Vec<_> = e.variants.values().collect();
+        variants.sort_by_key(|v| v.index);
+        if matches!(e.tag, EnumTag::Untagged) && !e.preserve_order {
+            variants.sort_by_key(|v| v.ty.as_ref().map(|ty| self.specificity(ty, 0)));
+        }
+        variants
+    }
+
+    /// Returns a key ordering types from the most specific to the least specific, in terms of
+    /// the JSON values they accept.
+    fn specificity(&self, r: &TypeRef, depth: usize) -> (u8, Reverse<usize>, Reverse<usize>) {
+        let rank = |rank| (rank, Reverse(0), Reverse(0));
+        match r {
+            TypeRef::Ref(path) if depth <= 32 => match self.file.types.get(path).map(|t| &t.kind) {
+                Some(TypeKind::Alias(a)) => self.specificity(&a.ty, depth + 1),
+                // Objects with more required fields reject more values.
+                Some(TypeKind::Struct(s)) => (
+                    0,
+                    Reverse(s.fields.values().filter(|f| f.required).count()),
+                    Reverse(s.fields.len()),
+                ),
+                Some(TypeKind::Tuple(_)) => rank(2),
+                _ => rank(1),
+            },
+            TypeRef::Ref(_) | TypeRef::ExternalRef(_) => rank(1),
+            TypeRef::Array(..) => rank(2),
+            TypeRef::Keyword(_) | TypeRef::Boolean | TypeRef::Null => rank(3),
+            TypeRef::Integer {
+                format_as_hex: false,
+            } => rank(3),
+            // Numbers also accept integers.
+            TypeRef::Number => rank(4),
+            // Hexadecimal integers are represented as strings.
+            TypeRef::Integer {
+                format_as_hex: true,
+            } => rank(4),
+            TypeRef::String | TypeRef::EncodedJson(_) => rank(5),
+        }
+    }
+
+    /// Returns whether the configuration already derives the provided trait for the type at
+    /// the provided path.
+    fn derives(&self, path: &str, name: &str) -> bool {
+        let generation = &self.config.generation;
+        generation.global_derives.iter().any(|d| d == name)
+            || generation
+                .derives
+                .get(path)
+                .is_some_and(|d| d.iter().any(|d| d == name))
+    }
+
+    /// Returns the variant of the provided enum that should be marked as `#[default]`, if any.
+    pub fn default_variant(&self, path: &str, e: &'a EnumDef) -> Option<&'a EnumVariant> {
+        if !self.config.generation.enum_defaults || self.derives(path, "Default") {
+            return None;
+        }
+
+        let default = e.default.as_deref()?;
+        e.variants
+            .values()
+            .find(|v| v.ty.is_none() && v.name_in_json.as_deref() == Some(default))
+    }
+
+    /// Returns whether a `Copy` implementation should be derived for the provided struct or
+    /// tuple.
+    pub fn infers_copy(&self, path: &str, kind: &TypeKind) -> bool {
+        self.config.generation.infer_copy
+            && !self.derives(path, "Copy")
+            && match kind {
+                TypeKind::Struct(s) => self.is_copy_struct(s, 0),
+                TypeKind::Tuple(t) => self.is_copy_tuple(t, 0),
+                _ => false,
+            }
+    }
+
+    /// Returns whether the provided field is the `"jsonrpc": "2.0"` marker of an envelope type,
+    /// represented by the generated `JsonRpcVersion` type.
+    pub fn is_jsonrpc_version(&self, field: &StructField) -> bool {
+        self.config.generation.jsonrpc_version
+            && field.name_in_json == "jsonrpc"
+            && !field.flatten
+            && matches!(self.file.unalias(&field.ty), TypeRef::Keyword(v) if v == "2.0")
+    }
+
+    /// Returns whether the provided field is a string that should be trimmed when deserialized.
+    ///
+    /// `attributes` are the attributes of the type of the field, which take precedence.
+    pub fn trims(&self, field: &StructField, attributes: &[String]) -> bool {
+        attributes.is_empty()
+            && self.config.generation.trim_strings.applies_to(&field.path)
+            && matches!(self.file.unalias(&field.ty), TypeRef::String)
+    }
+
+    /// Returns the condition under which a value of the provided type, bound to `value` by
+    /// reference, violates the provided bound.
+    ///
+    /// `None` is returned when the bound can't be checked for the type.
+    pub fn bound_violation(&self, ty: &TypeRef, bound: &Bound) -> Option<String> {
+        let ty = self.file.unalias(ty);
+        match bound {
+            Bound::Minimum(n, exclusive) | Bound::Maximum(n, exclusive) => {
+                if !self.is_numeric(ty) || !self.is_copy(ty, 0) {
+                    return None;
+                }
+                let literal = match ty {
+                    TypeRef::Integer { .. } => {
+                        let n = n
+                            .as_i64()
+                            .map(i128::from)
+                            .or_else(|| n.as_u64().map(i128::from))?;
+                        if n < 0 && self.config.primitives.integer.trim().starts_with('u') {
+                            return None;
+                        }
+                        n.to_string()
+                    }
+                    _ => format!("{:?}", n.as_f64()?),
+                };
+                let op = match (bound, exclusive) {
+                    (Bound::Minimum(..), false) => "<",
+                    (Bound::Minimum(..), true) => "<=",
+                    (_, false) => ">",
+                    (_, true) => ">=",
+                };
+                Some(format!("*value {op} {literal}"))
+            }
+            Bound::MinLength(n) | Bound::MaxLength(n) => {
+                if !matches!(ty, TypeRef::String) {
+                    return None;
+                }
+                match bound {
+                    Bound::MinLength(0) => None,
+                    Bound::MinLength(1) => Some("value.is_empty()".to_owned()),
+                    Bound::MinLength(_) => Some(format!("value.chars().count() < {n}")),
+                    _ => Some(format!("value.chars().count() > {n}")),
+                }
+            }
+            Bound::MinItems(n) | Bound::MaxItems(n) => {
+                if !matches!(ty, TypeRef::Array(..)) {
+                    return None;
+                }
+                match bound {
+                    Bound::MinItems(0) => None,
+                    Bound::MinItems(1) => Some("value.is_empty()".to_owned()),
+                    Bound::MinItems(_) => Some(format!("value.len() < {n}")),
+                    _ => Some(format!("value.len() > {n}")),
+                }
+            }
+        }
+    }
+
+    /// Returns whether a `validate` method should be generated for the provided struct.
+    pub fn has_validate(&self, s: &StructDef) -> bool {
+        self.config.generation.validate_method
+            && s.fields.values().any(|f| {
+                !f.flatten
+                    && !self.is_jsonrpc_version(f)
+                    && f.bounds
+                        .iter()
+                        .any(|b| self.bound_violation(&f.ty, b).is_some())
+            })
+    }
+
+    /// Returns the struct referenced by the provided type, looking through aliases.
+    fn referenced_struct(&self, mut r: &'a TypeRef) -> Option<(&'a Path, &'a StructDef)> {
+        for _ in 0..32 {
+            let TypeRef::Ref(path) = r else {
+                return None;
+            };
+            match &self.file.types.get(path)?.kind {
+                TypeKind::Alias(a) => r = &a.ty,
+                TypeKind::Struct(s) => return Some((path, s)),
+                TypeKind::Enum(_) | TypeKind::Tuple(_) => return None,
+            }
+        }
+        None
+    }
+
+    /// Returns the Rust expressions of the items of the default value of the provided field, if
+    /// it is an optional array with a default value.
+    ///
+    /// An empty list is returned when some of the items are not primitive values, which can't be
+    /// written as literals. The field then defaults to an empty array.
+    pub fn array_default(&self, field: &StructField) -> Option<Vec<String>> {
+        let Some(serde_json::Value::Array(items)) = &field.default else {
+            return None;
+        };
+        if field.required || field.flatten {
+            return None;
+        }
+        let TypeRef::Array(inner, max_items) = self.file.unalias(&field.ty) else {
+            return None;
+        };
+        if max_items.is_some() && self.config.generation.bounded_vec {
+            return None;
+        }
+        let inner = self.file.unalias(inner);
+        Some(
+            items
+                .iter()
+                .map(|item| inner.literal(item))
+                .collect::<Option<Vec<_>>>()
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Returns whether the provided field is always present in the generated struct, either
+    /// because it is required or because it has a default value.
+    pub fn is_required(&self, field: &StructField) -> bool {
+        field.required || self.array_default(field).is_some()
+    }
+
+    /// Returns the names of the result field holding the cursor of the next page and of the
+    /// parameter receiving it, if the provided method is paginated.
+    pub fn pagination_cursor(
+        &self,
+        method: &'a crate::parse::Method,
+    ) -> Option<(&'a str, &'a str)> {
+        let pagination = self.config.generation.pagination.as_ref()?;
+        let (_, result) = self.referenced_struct(&method.result.as_ref()?.ty)?;
+        let field = |name: &str| {
+            result
+                .fields
+                .values()
+                .find(|f| f.name_in_json == name && !f.flatten)
+        };
+
+        let items = field(&pagination.items)?;
+        if !matches!(self.file.unalias(&items.ty), TypeRef::Array(..)) {
+            return None;
+        }
+        let next_cursor = field(&pagination.next_cursor).filter(|f| !self.is_required(f))?;
+        let cursor_param = method
+            .params
+            .iter()
+            .find(|p| p.name_in_json == pagination.cursor_param && !p.required)?;
+        if self.type_ref_name(&next_cursor.ty, false) != self.type_ref_name(&cursor_param.ty, false)
+        {
+            return None;
+        }
+
+        Some((&next_cursor.name, &cursor_param.name))
+    }
+
+    /// Returns the method targeted by the provided link of `method`, along with the Rust
+    /// expressions initializing each of its parameters from a `result` variable.
+    ///
+    /// `None` is returned when the link can't be represented.
+    pub fn link_params(
+        &self,
+        method: &crate::parse::Method,
+        link: &crate::parse::Link,
+    ) -> Option<(&'a crate::parse::Method, Vec<(&'a str, String)>)> {
+        let target = self.file.methods.iter().find(|m| m.name == link.method)?;
+        let values = link.params.as_ref()?;
+        if values
+            .keys()
+            .any(|name| !target.params.iter().any(|p| p.name_in_json == *name))
+        {
+            return None;
+        }
+
+        let mut exprs = Vec::with_capacity(target.params.len());
+        for param in &target.params {
+            let expr = match values.get(&param.name_in_json) {
+                None | Some(LinkParam::Constant(serde_json::Value::Null)) if !param.required => {
+                    "None".into()
+                }
+                None | Some(LinkParam::Unsupported) => return None,
+                Some(LinkParam::Constant(value)) => {
+                    let literal = self.file.unalias(&param.ty).literal(value)?;
+                    if param.required {
+                        literal
+                    } else {
+                        format!("Some({literal})")
+                    }
+                }
+                Some(LinkParam::Field(pointer)) => {
+                    let (expr, field) = self.link_field(&method.result.as_ref()?.ty, pointer)?;
+                    // Aliases are transparent, so a field may initialize a parameter of an alias of
+                    // its type.
+                    let field_ty = self.file.unalias(&field.ty);
+                    if self.type_ref_name(field_ty, true)
+                        != self.type_ref_name(self.file.unalias(&param.ty), true)
+                    {
+                        return None;
+                    }
+                    let expr = if self.is_copy(&field.ty, 0) {
+                        expr
+                    } else {
+                        format!("{expr}.clone()")
+                    };
+                    match (self.is_required(field), param.required) {
+                        (true, false) => format!("Some({expr})"),
+                        (false, true) => return None,
+                        _ => expr,
+                    }
+                }
+            };
+            exprs.push((param.name.as_str(), expr));
+        }
+
+        Some((target, exprs))
+    }
+
+    /// Resolves the field of `ty` found by following the provided names in JSON, returning the
+    /// Rust expression accessing it from a `result` variable.
+    ///
+    /// All the fields along the way must be required.
+    fn link_field(
+        &self,
+        mut ty: &'a TypeRef,
+        pointer: &[String],
+    ) -> Option<(String, &'a StructField)> {
+        let mut expr = String::from("result");
+        let mut field = None;
+        for name in pointer {
+            if field.is_some_and(|f| !self.is_required(f)) {
+                return None;
+            }
+            let (_, s) = self.referenced_struct(ty)?;
+            let f = s
+                .fields
+                .values()
+                .find(|f| f.name_in_json == *name && !f.flatten)?;
+            expr.push('.');
+            expr.push_str(&f.name);
+            ty = &f.ty;
+            field = Some(f);
+        }
+        Some((expr, field?))
+    }
+
+    /// Returns whether the provided type is known to implement `Default`.
+    fn has_default(&self, ty: &TypeRef) -> bool {
+        match self.file.unalias(ty) {
+            TypeRef::Boolean
+            | TypeRef::Integer { .. }
+            | TypeRef::Number
+            | TypeRef::String
+            | TypeRef::Array(..) => true,
+            TypeRef::Ref(path) => match self.file.types.get(path).map(|ty| &ty.kind) {
+                Some(TypeKind::Struct(_)) => {
+                    self.derives(path, "Default") || self.infers_default(path)
+                }
+                Some(TypeKind::Enum(e)) => {
+                    self.derives(path, "Default") || self.default_variant(path, e).is_some()
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns the expression of the `*_or_default` accessor of the provided field, if one
+    /// should be generated.
+    pub fn or_default_expr(&self, field: &StructField) -> Option<String> {
+        if self.is_required(field) || field.flatten || self.is_nullable(&field.ty) {
+            return None;
+        }
+        let copy = self.is_copy(&field.ty, 0);
+        let value = if copy {
+            format!("self.{}", field.name)
+        } else {
+            format!("self.{}.clone()", field.name)
+        };
+        let literal = field
+            .default
+            .as_ref()
+            .and_then(|default| self.file.unalias(&field.ty).literal(default));
+        match literal {
+            Some(literal) if copy => Some(format!("{value}.unwrap_or({literal})")),
+            Some(literal) => Some(format!("{value}.unwrap_or_else(|| {literal})")),
+            None if self.has_default(&field.ty) => Some(format!("{value}.unwrap_or_default()")),
+            None => None,
+        }
+    }
+
+    /// Returns whether the provided struct may be entirely absent from its JSON representation,
+    /// meaning that all its fields are optional.
+    fn is_defaultable(&self, s: &'a StructDef, depth: usize) -> bool {
+        depth <= 32
+            && s.fields.values().all(|f| {
+                !f.required
+                    || f.flatten
+                        && self
+                            .referenced_struct(&f.ty)
+                            .is_some_and(|(_, s)| self.is_defaultable(s, depth + 1))
+            })
+    }
+
+    /// Returns whether the provided field is a required flattened struct that may be entirely
+    /// absent, and should therefore default when it is.
+    pub fn is_defaultable_flatten(&self, field: &'a StructField) -> bool {
+        field.flatten
+            && field.required
+            && self
+                .referenced_struct(&field.ty)
+                .is_some_and(|(_, s)| self.is_defaultable(s, 0))
+    }
+
+    /// Returns whether a `Default` implementation should be derived for the struct at the
+    /// provided path, because it is flattened in a field that defaults.
+    pub fn infers_default(&self, path: &str) -> bool {
+        !self.derives(path, "Default")
+            && self.file.types.values().any(|ty| match &ty.kind {
+                TypeKind::Struct(s) => s.fields.values().any(|f| {
+                    self.is_defaultable_flatten(f)
+                        && self
+                            .referenced_struct(&f.ty)
+                            .is_some_and(|(p, _)| &**p == path)
+                }),
+                _ => false,
+            })
+    }
+
+    fn is_copy_struct(&self, s: &StructDef, depth: usize) -> bool {
+        !self.captures_extensions(s)
+            && s.fields
+                .values()
+                .all(|f| f.required && self.is_copy(&f.ty, depth))
+    }
+
+    fn is_copy_tuple(&self, t: &TupleDef, depth: usize) -> bool {
+        t.items.iter().all(|ty| self.is_copy(ty, depth))
+    }
+
+    /// Returns whether the provided type is known to implement `Copy`.
+    fn is_copy(&self, r: &TypeRef, depth: usize) -> bool {
+        let primitives = &self.config.primitives;
+        match r {
+            TypeRef::Boolean => is_copy_primitive(&primitives.boolean),
+            TypeRef::Integer { .. } => is_copy_primitive(&primitives.integer),
+            TypeRef::Number => is_copy_primitive(&primitives.number),
+            TypeRef::Null => is_copy_primitive(&primitives.null),
+            TypeRef::String | TypeRef::Keyword(_) | TypeRef::Array(..) => false,
+            TypeRef::EncodedJson(_) => false,
+            TypeRef::ExternalRef(_) => false,
+            // Recursive types can't be `Copy` anyway.
+            TypeRef::Ref(_) if depth > 32 => false,
+            TypeRef::Ref(path) => match self.file.types.get(path).map(|ty| &ty.kind) {
+                Some(TypeKind::Alias(a)) => self.is_copy(&a.ty, depth + 1),
+                Some(TypeKind::Struct(s)) => {
+                    self.config.generation.infer_copy && self.is_copy_struct(s, depth + 1)
+                }
+                Some(TypeKind::Tuple(t)) => {
+                    self.config.generation.infer_copy && self.is_copy_tuple(t, depth + 1)
+                }
+                Some(TypeKind::Enum(e)) => {
+                    e.copy && !e.nullable && self.collapsed_variant(e).is_none()
+                }
+                None => false,
+            },
+        }
+    }
+
+    /// Returns whether the provided type is represented by the configured string type, looking
+    /// through aliases.
+    pub fn is_string<'b>(&'b self, mut ty: &'b TypeRef) -> bool {
+        for _ in 0..32 {
+            match ty {
+                TypeRef::String => return true,
+                TypeRef::Ref(path) => match self.file.types.get(path).map(|t| &t.kind) {
+                    Some(TypeKind::Alias(a)) => ty = &a.ty,
+                    _ => return false,
+                },
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    /// Returns the visibility of the generated items.
+    pub fn vis(&self) -> &'a str {
+        &self.config.generation.visibility
+    }
+
+    /// Returns the name of the module through which the standard library is accessed.
+    pub fn std_mod(&self) -> &'static str {
+        if self.config.generation.use_core {
+            "core"
+        } else {
+            "std"
+        }
+    }
+
+    /// Returns whether the provided [`TypeRef`] refers to a nullable enum.
+    ///
+    /// Aliases of nullable enums are not nullable themselves, as they already include the
+    /// optional template.
+    pub fn is_nullable(&self, r: &TypeRef) -> bool {
+        match r {
+            TypeRef::Ref(path) => matches!(
+                self.file.types.get(path).map(|ty| &ty.kind),
+                Some(TypeKind::Enum(e)) if e.nullable
+            ),
+            _ => false,
+        }
+    }
+
     /// Returns the name of the type referenced by the provided [`TypeRef`].
+    ///
+    /// References to nullable enums are always wrapped in the optional template.
     pub fn type_ref_name(&self, r: &'a TypeRef, required: bool) -> Cow<'a, str> {
-        if !required {
-            let inner = self.type_ref_name(r, true);
+        if !required || self.is_nullable(r) {
+            let inner = self.non_null_type_ref_name(r);
             return Cow::Owned(self.config.primitives.optional.replace("{}", &inner));
         }
 
+        self.non_null_type_ref_name(r)
+    }
+
+    /// Returns the name of the type referenced by the provided [`TypeRef`], ignoring whether
+    /// it is nullable.
+    fn non_null_type_ref_name(&self, r: &'a TypeRef) -> Cow<'a, str> {
         match r {
-            TypeRef::Array(inner) => Cow::Owned(
+            TypeRef::Array(inner, Some(max_items)) if self.config.generation.bounded_vec => {
+                Cow::Owned(format!(
+                    "BoundedVec<{}, {max_items}>",
+                    self.type_ref_name(inner, true)
+                ))
+            }
+            TypeRef::Array(inner, _) => Cow::Owned(
                 self.config
                     .primitives
                     .array
@@ -38,154 +687,4072 @@ impl<'a> Ctx<'a> {
             TypeRef::Null => Cow::Borrowed(&self.config.primitives.null),
             TypeRef::Number => Cow::Borrowed(&self.config.primitives.number),
             TypeRef::String => Cow::Borrowed(&self.config.primitives.string),
+            TypeRef::EncodedJson(inner) if self.config.generation.decode_json_strings => {
+                self.non_null_type_ref_name(inner)
+            }
+            TypeRef::EncodedJson(_) => Cow::Borrowed(&self.config.primitives.string),
             TypeRef::Keyword(val) => {
                 Cow::Owned(format!("{} /* {} */", &self.config.primitives.string, val))
             }
             TypeRef::Ref(path) => match self.file.types.get(path) {
-                Some(ty) => Cow::Borrowed(&ty.name),
+                Some(ty) => match self.config.generation.module_path {
+                    Some(ref module) => Cow::Owned(format!("{module}::{}", ty.name)),
+                    None => Cow::Borrowed(&ty.name),
+                },
                 None => Cow::Owned(format!("BrokenReference /* {path} */")),
             },
             TypeRef::ExternalRef(name) => Cow::Borrowed(name),
         }
     }
-}
 
-/// Generates a Rust file from the provided [`crate::parse::File`] and configuration.
-pub fn gen(
-    w: &mut dyn io::Write,
-    file: &crate::parse::File,
-    config: &crate::config::Config,
-) -> io::Result<()> {
-    let mut ctx = Ctx { file, config };
+    /// Returns a rough estimate of the size (in bytes) of the type referenced by the provided
+    /// [`TypeRef`].
+    ///
+    /// This is only used to decide which lints should be silenced on generated items and does
+    /// not need to be exact.
+    pub fn type_ref_size(&self, r: &TypeRef, required: bool) -> usize {
+        self.type_ref_size_rec(r, required, 0)
+    }
 
-    writeln!(
-        w,
-        "\
-        //\n\
-        // This file was automatically generated by openrpc-gen.\n\
-        //\n\
-        // Do not edit it manually and instead edit either the source OpenRPC document,\n\
-        // the configuration file, or open an issue or pull request on the openrpc-gen\n\
-        // GitHub repository.\n\
-        // \n\
-        //     https://github.com/nils-mathieu/openrpc-gen\n\
-        //\n\
-        "
-    )?;
+    fn type_ref_size_rec(&self, r: &TypeRef, required: bool, depth: usize) -> usize {
+        if !required {
+            return self.type_ref_size_rec(r, true, depth) + 8;
+        }
 
-    writeln!(w, "use serde::{{Serialize, Deserialize}};")?;
-    if ctx.config.generation.param_types && !ctx.file.methods.is_empty() {
-        writeln!(w, "use serde::ser::SerializeMap;")?;
+        let primitives = &self.config.primitives;
+        match r {
+            TypeRef::Array(..) => primitive_size(&primitives.array),
+            TypeRef::Boolean => primitive_size(&primitives.boolean),
+            TypeRef::Integer { .. } => primitive_size(&primitives.integer),
+            TypeRef::Null => primitive_size(&primitives.null),
+            TypeRef::Number => primitive_size(&primitives.number),
+            TypeRef::String | TypeRef::Keyword(_) => primitive_size(&primitives.string),
+            TypeRef::EncodedJson(inner) if self.config.generation.decode_json_strings => {
+                self.type_ref_size_rec(inner, true, depth)
+            }
+            TypeRef::EncodedJson(_) => primitive_size(&primitives.string),
+            TypeRef::ExternalRef(name) => primitive_size(name),
+            TypeRef::Ref(path) => match self.file.types.get(path) {
+                // Recursive types must be behind an indirection anyway.
+                Some(_) if depth > 32 => 8,
+                Some(ty) => self.type_kind_size(&ty.kind, depth + 1),
+                None => 0,
+            },
+        }
     }
-    for import in &ctx.config.generation.additional_imports {
-        writeln!(w, "use {import};")?;
+
+    fn type_kind_size(&self, kind: &TypeKind, depth: usize) -> usize {
+        match kind {
+            TypeKind::Alias(a) => self.type_ref_size_rec(&a.ty, true, depth),
+            TypeKind::Struct(s) => s
+                .fields
+                .values()
+                .map(|f| self.type_ref_size_rec(&f.ty, f.required, depth))
+                .sum(),
+            TypeKind::Tuple(t) => t
+                .items
+                .iter()
+                .map(|ty| self.type_ref_size_rec(ty, true, depth))
+                .sum(),
+            TypeKind::Enum(e) => {
+                e.variants
+                    .values()
+                    .filter_map(|v| v.ty.as_ref())
+                    .map(|ty| self.type_ref_size_rec(ty, true, depth))
+                    .max()
+                    .unwrap_or(0)
+                    + 8
+            }
+        }
     }
-    writeln!(w)?;
 
-    for ty in file.types.values() {
-        gen_type(w, &mut ctx, ty)?;
+    /// Returns whether the provided enum would trigger the `clippy::large_enum_variant` lint
+    /// once the payloads of the `boxed` variants are boxed.
+    ///
+    /// Like the lint, this compares the largest variant with the second largest one.
+    pub fn has_large_variant(&self, e: &EnumDef, boxed: &BTreeSet<&Path>) -> bool {
+        let mut sizes = e
+            .variants
+            .values()
+            .map(|v| match &v.ty {
+                Some(_) if boxed.contains(&v.path) => 8,
+                Some(ty) => self.type_ref_size(ty, true),
+                None => 0,
+            })
+            .collect::<Vec<_>>();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        match sizes.as_slice() {
+            [largest, second, ..] => largest - second >= LARGE_ENUM_VARIANT_THRESHOLD,
+            _ => false,
+        }
     }
-    for method in &file.methods {
-        gen_method(w, &mut ctx, method)?;
+
+    /// Returns the variants of the provided enum whose payload should be boxed.
+    ///
+    /// The largest variant is boxed until it is no longer large enough to trigger the
+    /// `clippy::large_enum_variant` lint. Payloads with custom serde attributes are never
+    /// boxed, as those expect the unboxed type.
+    pub fn boxed_variants(&self, e: &'a EnumDef) -> BTreeSet<&'a Path> {
+        let mut boxed = BTreeSet::new();
+        if !self.config.generation.box_large_variants || e.copy {
+            return boxed;
+        }
+
+        let mut sizes = e
+            .variants
+            .values()
+            .map(|v| {
+                let size = v.ty.as_ref().map_or(0, |ty| self.type_ref_size(ty, true));
+                let boxable =
+                    v.ty.as_ref()
+                        .is_some_and(|ty| ty.attributes(self.config, self.file, true).is_empty());
+                (&v.path, size, boxable)
+            })
+            .collect::<Vec<_>>();
+        loop {
+            sizes.sort_unstable_by_key(|&(_, size, _)| Reverse(size));
+            match sizes.as_mut_slice() {
+                [(path, largest, true), (_, second, _), ..]
+                    if *largest - *second >= LARGE_ENUM_VARIANT_THRESHOLD =>
+                {
+                    boxed.insert(*path);
+                    *largest = 8;
+                }
+                _ => return boxed,
+            }
+        }
     }
+}
 
-    Ok(())
+/// The default threshold of the `clippy::large_enum_variant` lint, in bytes.
+const LARGE_ENUM_VARIANT_THRESHOLD: usize = 200;
+
+/// The maximum number of parameters for which `From<(T1, T2, ...)>` is implemented on
+/// parameter types, matching the largest tuples supported by the standard library.
+const MAX_TUPLE_ARITY: usize = 12;
+
+/// The default threshold of the `clippy::too_many_lines` lint.
+const TOO_MANY_LINES_THRESHOLD: usize = 100;
+
+/// Returns a rough estimate of the size of the provided primitive type, in bytes.
+///
+/// Unknown types are assumed to be as large as a `String`.
+fn primitive_size(name: &str) -> usize {
+    match name.trim() {
+        "()" => 0,
+        "bool" | "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" | "f32" | "char" => 4,
+        "u64" | "i64" | "f64" | "usize" | "isize" => 8,
+        "u128" | "i128" => 16,
+        _ => 24,
+    }
 }
 
-/// Writes the provided type.
-fn gen_type(w: &mut dyn io::Write, ctx: &mut Ctx, ty: &TypeDef) -> io::Result<()> {
-    if ctx.config.debug_path {
-        writeln!(w, "// {}", ty.path)?;
+/// Returns whether the provided primitive type is known to implement `Copy`.
+fn is_copy_primitive(name: &str) -> bool {
+    matches!(
+        name.trim(),
+        "()" | "bool"
+            | "char"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "f32"
+            | "f64"
+    )
+}
+
+/// Converts the provided PascalCase identifier to snake_case.
+///
+/// Unlike a plain [`Case::Snake`] conversion, digits are kept attached to the word they follow
+/// (e.g. `AcceptedOnL1` becomes `accepted_on_l1`).
+fn snake_case(ident: &str) -> String {
+    ident
+        .from_case(Case::Pascal)
+        .without_boundaries(&Boundary::digits())
+        .to_case(Case::Snake)
+}
+
+/// The `rename_all` rules supported by `serde`, other than `PascalCase` (which leaves variant
+/// names unchanged).
+const RENAME_RULES: &[&str] = &[
+    "lowercase",
+    "UPPERCASE",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Applies the provided `rename_all` rule to a variant name, the way `serde` does.
+fn apply_rename_rule(rule: &str, variant: &str) -> String {
+    let snake = || {
+        let mut snake = String::new();
+        for (i, c) in variant.char_indices() {
+            if i > 0 && c.is_uppercase() {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        }
+        snake
+    };
+
+    match rule {
+        "lowercase" => variant.to_ascii_lowercase(),
+        "UPPERCASE" => variant.to_ascii_uppercase(),
+        "camelCase" => {
+            let mut chars = variant.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+        "snake_case" => snake(),
+        "SCREAMING_SNAKE_CASE" => snake().to_ascii_uppercase(),
+        "kebab-case" => snake().replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => snake().to_ascii_uppercase().replace('_', "-"),
+        _ => variant.to_owned(),
     }
-    if let Some(doc) = &ty.documentation {
-        writeln!(w, "/// {}", doc)?;
+}
+
+/// Returns the `rename_all` rule mapping the name of the most variants of the provided enum to
+/// their name in JSON, if one maps more of them than the variant names themselves.
+///
+/// The variants that are not mapped correctly by the rule are renamed individually.
+fn enum_rename_rule(e: &EnumDef) -> Option<&'static str> {
+    let mapped = |rule| {
+        e.variants
+            .values()
+            .filter(|v| !needs_rename(rule, v))
+            .count()
+    };
+
+    let mut best = (None, mapped(None));
+    for &rule in RENAME_RULES {
+        let count = mapped(Some(rule));
+        if count > best.1 {
+            best = (Some(rule), count);
+        }
     }
-    match &ty.kind {
-        TypeKind::Alias(alias) => {
-            writeln!(
-                w,
-                "pub type {} = {};",
-                ty.name,
-                ctx.type_ref_name(&alias.ty, true)
-            )?;
+    best.0
+}
+
+/// Returns whether the provided variant needs its own `rename` attribute when its enum uses the
+/// provided `rename_all` rule.
+fn needs_rename(rule: Option<&str>, v: &EnumVariant) -> bool {
+    let wire_name = v.name_in_json.as_deref().unwrap_or(&v.name);
+    match rule {
+        Some(rule) => apply_rename_rule(rule, &v.name) != wire_name,
+        None => v.name != wire_name,
+    }
+}
+
+/// The `rename_all` rules supported by `serde` that change snake case field names.
+const FIELD_RENAME_RULES: &[&str] = &[
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Applies the provided `rename_all` rule to a field name, the way `serde` does.
+fn apply_field_rename_rule(rule: &str, field: &str) -> String {
+    let field = field.strip_prefix("r#").unwrap_or(field);
+    let pascal = || {
+        let mut pascal = String::new();
+        let mut capitalize = true;
+        for c in field.chars() {
+            if c == '_' {
+                capitalize = true;
+            } else if capitalize {
+                pascal.push(c.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                pascal.push(c);
+            }
+        }
+        pascal
+    };
+
+    match rule {
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "PascalCase" => pascal(),
+        "camelCase" => {
+            let pascal = pascal();
+            let mut chars = pascal.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_ascii_uppercase().replace('_', "-"),
+        _ => field.to_owned(),
+    }
+}
+
+/// Returns the `rename_all` rule mapping the name of the most fields of the provided struct to
+/// their name in JSON, if one maps more of them than the field names themselves.
+///
+/// Flattened fields are ignored, as their name doesn't appear in JSON.
+fn struct_rename_rule(s: &StructDef) -> Option<&'static str> {
+    let mapped = |rule| {
+        s.fields
+            .values()
+            .filter(|f| !f.flatten && !field_needs_rename(rule, f))
+            .count()
+    };
+
+    let mut best = (None, mapped(None));
+    for &rule in FIELD_RENAME_RULES {
+        let count = mapped(Some(rule));
+        if count > best.1 {
+            best = (Some(rule), count);
+        }
+    }
+    best.0
+}
+
+/// Returns whether the provided field needs its own `rename` attribute when its struct uses the
+/// provided `rename_all` rule.
+fn field_needs_rename(rule: Option<&str>, f: &StructField) -> bool {
+    match rule {
+        Some(rule) => apply_field_rename_rule(rule, &f.name) != f.name_in_json,
+        None => f.name != f.name_in_json,
+    }
+}
+
+/// Formats the provided elements as a tuple, including the trailing comma of 1-tuples.
+fn tuple_of(elements: Vec<String>) -> String {
+    if let [element] = &elements[..] {
+        format!("({element},)")
+    } else {
+        format!("({})", elements.join(", "))
+    }
+}
+
+/// Counts the number of non-empty lines in the provided generated code.
+fn count_lines(code: &[u8]) -> usize {
+    code.split(|&b| b == b'\n')
+        .filter(|line| line.iter().any(|b| !b.is_ascii_whitespace()))
+        .count()
+}
+
+/// The number of spaces making up an indentation level in the code written by the generator.
+const GENERATOR_INDENT_WIDTH: usize = 4;
+
+/// A writer that converts the four-space indentation used by the generator into the configured
+/// [`Indent`](crate::config::Indent).
+///
+/// Lines starting inside a string literal are written as-is, as their leading spaces are part of
+/// the string.
+struct IndentWriter<'a> {
+    inner: &'a mut dyn io::Write,
+    indent: crate::config::Indent,
+    /// The number of indentation levels added to every line that is not blank.
+    base_level: usize,
+    /// Whether the next byte written is at the start of a line.
+    at_line_start: bool,
+    /// The number of leading spaces seen so far on the current line.
+    pending_spaces: usize,
+    /// The token the last byte written is part of.
+    token: Token,
+}
+
+/// The kind of token being written by an [`IndentWriter`], as far as telling string literals
+/// apart from the rest of the code is concerned.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Token {
+    /// Anything that is not covered by the other variants.
+    Code,
+    /// An identifier, after which an `r` doesn't start a raw string.
+    Ident,
+    /// A `/`, which may start a comment.
+    Slash,
+    /// A comment running to the end of the line.
+    LineComment,
+    /// A block comment, ending at `*/`.
+    BlockComment {
+        /// Whether the last byte of the comment was a `*`.
+        star: bool,
+    },
+    /// A `'`, starting either a character literal or a lifetime.
+    Quote,
+    /// The character following a `'`, which is a character literal if a `'` follows.
+    QuoteChar,
+    /// The `\` starting an escape sequence in a character literal.
+    CharEscape,
+    /// A character literal, after an escape sequence.
+    Char,
+    /// The `r` and `#`s that may prefix a raw string literal, with the number of `#`.
+    RawPrefix(usize),
+    /// A string literal.
+    Str {
+        /// Whether the last byte of the string was an unescaped `\`.
+        escaped: bool,
+    },
+    /// A raw string literal, with the number of `#` closing it.
+    RawStr(usize),
+    /// The end of a raw string literal, with the number of `#` left to close it.
+    RawStrEnd(usize, usize),
+}
+
+impl Token {
+    /// Returns the token the provided byte is part of, when it follows this token.
+    fn next(self, b: u8) -> Self {
+        let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+        match self {
+            Self::Slash if b == b'/' => Self::LineComment,
+            Self::Slash if b == b'*' => Self::BlockComment { star: false },
+            Self::LineComment if b != b'\n' => Self::LineComment,
+            Self::BlockComment { star: true } if b == b'/' => Self::Code,
+            Self::BlockComment { .. } => Self::BlockComment { star: b == b'*' },
+            Self::Quote if b == b'\\' => Self::CharEscape,
+            Self::Quote => Self::QuoteChar,
+            Self::QuoteChar if b == b'\'' => Self::Code,
+            Self::CharEscape => Self::Char,
+            Self::Char if b == b'\'' => Self::Code,
+            Self::Char => Self::Char,
+            Self::RawPrefix(hashes) if b == b'#' => Self::RawPrefix(hashes + 1),
+            Self::RawPrefix(hashes) if b == b'"' => Self::RawStr(hashes),
+            Self::Str { escaped: false } if b == b'"' => Self::Code,
+            Self::Str { escaped } => Self::Str {
+                escaped: !escaped && b == b'\\',
+            },
+            Self::RawStr(0) if b == b'"' => Self::Code,
+            Self::RawStr(hashes) | Self::RawStrEnd(hashes, _) if b == b'"' => {
+                Self::RawStrEnd(hashes, hashes)
+            }
+            Self::RawStrEnd(_, 1) if b == b'#' => Self::Code,
+            Self::RawStrEnd(hashes, left) if b == b'#' => Self::RawStrEnd(hashes, left - 1),
+            Self::RawStr(hashes) | Self::RawStrEnd(hashes, _) => Self::RawStr(hashes),
+            Self::Ident if is_ident(b) => Self::Ident,
+            _ => match b {
+                b'/' => Self::Slash,
+                b'\'' => Self::Quote,
+                b'"' => Self::Str { escaped: false },
+                b'r' if self != Self::Ident => Self::RawPrefix(0),
+                _ if is_ident(b) => Self::Ident,
+                _ => Self::Code,
+            },
+        }
+    }
+
+    /// Returns whether the token is part of a string literal.
+    fn in_string(self) -> bool {
+        matches!(
+            self,
+            Self::Str { .. } | Self::RawStr(_) | Self::RawStrEnd(..)
+        )
+    }
+}
+
+impl<'a> IndentWriter<'a> {
+    pub fn new(
+        inner: &'a mut dyn io::Write,
+        indent: crate::config::Indent,
+        base_level: usize,
+    ) -> Self {
+        Self {
+            inner,
+            indent,
+            base_level,
+            at_line_start: true,
+            pending_spaces: 0,
+            token: Token::Code,
+        }
+    }
+
+    /// Writes the indentation corresponding to `spaces` leading spaces.
+    fn write_indent(&mut self, spaces: usize) -> io::Result<()> {
+        let levels = self.base_level + spaces / GENERATOR_INDENT_WIDTH;
+        let rest = spaces % GENERATOR_INDENT_WIDTH;
+        let (unit, count) = match self.indent {
+            crate::config::Indent::Spaces { width } => (b' ', levels * width),
+            crate::config::Indent::Tabs => (b'\t', levels),
+        };
+        write_repeated(self.inner, unit, count)?;
+        write_repeated(self.inner, b' ', rest)
+    }
+}
+
+/// Writes `count` times the provided byte.
+fn write_repeated(w: &mut dyn io::Write, b: u8, mut count: usize) -> io::Result<()> {
+    let buf = [b; 64];
+    while count > 0 {
+        let n = count.min(buf.len());
+        w.write_all(&buf[..n])?;
+        count -= n;
+    }
+    Ok(())
+}
+
+impl io::Write for IndentWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // The start of the bytes of `buf` that are yet to be written as-is.
+        let mut start = 0;
+        for (i, &b) in buf.iter().enumerate() {
+            let in_string = self.token.in_string();
+            self.token = self.token.next(b);
+            if !self.at_line_start {
+                self.at_line_start = b == b'\n';
+                continue;
+            }
+            match b {
+                _ if in_string => {
+                    self.at_line_start = b == b'\n';
+                    continue;
+                }
+                b' ' => {
+                    self.inner.write_all(&buf[start..i])?;
+                    start = i + 1;
+                    self.pending_spaces += 1;
+                    continue;
+                }
+                // Blank lines are written without any indentation.
+                b'\n' => (),
+                _ => {
+                    self.inner.write_all(&buf[start..i])?;
+                    start = i;
+                    self.write_indent(self.pending_spaces)?;
+                    self.at_line_start = false;
+                }
+            }
+            self.pending_spaces = 0;
+        }
+        self.inner.write_all(&buf[start..])?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Writes the provided documentation as doc comments, one per line, each preceded by `indent`.
+fn gen_doc(w: &mut dyn io::Write, indent: &str, doc: &str) -> io::Result<()> {
+    for line in doc.lines() {
+        if line.is_empty() {
+            writeln!(w, "{indent}///")?;
+        } else {
+            writeln!(w, "{indent}/// {line}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Generates a Rust file from the provided [`crate::parse::File`] and configuration.
+pub fn gen(
+    w: &mut dyn io::Write,
+    file: &crate::parse::File,
+    config: &crate::config::Config,
+) -> io::Result<()> {
+    let mut ctx = Ctx { file, config };
+    let vis = ctx.vis();
+    writeln!(
+        w,
+        "\
+        //\n\
+        // This file was automatically generated by openrpc-gen.\n\
+        //\n\
+        // Do not edit it manually and instead edit either the source OpenRPC document,\n\
+        // the configuration file, or open an issue or pull request on the openrpc-gen\n\
+        // GitHub repository.\n\
+        // \n\
+        //     https://github.com/nils-mathieu/openrpc-gen\n\
+        //\n\
+        "
+    )?;
+
+    if config.generation.rustfmt_skip {
+        writeln!(w, "#[rustfmt::skip]")?;
+        writeln!(w, "mod {RUSTFMT_SKIP_MODULE} {{")?;
+        let mut inner = IndentWriter::new(w, config.generation.indent, 1);
+        gen_items(&mut inner, &mut ctx)?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        if vis != "pub" {
+            // Restricted re-exports are reported when nothing uses them.
+            writeln!(w, "#[allow(unused_imports)]")?;
+        }
+        writeln!(w, "{vis} use self::{RUSTFMT_SKIP_MODULE}::*;")?;
+    } else {
+        gen_items(
+            &mut IndentWriter::new(w, config.generation.indent, 0),
+            &mut ctx,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Writes the footer of the generated file, recording the version of openrpc-gen and the
+/// provided hash of the input document.
+///
+/// The time at which the file was generated is included when `timestamp` is set.
+///
+/// The generated items already end with an empty line, which separates them from the footer.
+pub fn gen_footer(w: &mut dyn io::Write, document_hash: u64, timestamp: bool) -> io::Result<()> {
+    writeln!(w, "//")?;
+    writeln!(
+        w,
+        "// Generated by openrpc-gen {}.",
+        env!("CARGO_PKG_VERSION")
+    )?;
+    writeln!(w, "// Document hash (FNV-1a): {document_hash:016x}")?;
+    if timestamp {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        writeln!(w, "// Generated at: {secs} (Unix time)")?;
+    }
+    writeln!(w, "//")?;
+
+    Ok(())
+}
+
+/// The name of the module wrapping the generated items when `rustfmt-skip` is enabled.
+const RUSTFMT_SKIP_MODULE: &str = "generated";
+
+/// Writes the imports and items of the generated file.
+fn gen_items(w: &mut dyn io::Write, ctx: &mut Ctx) -> io::Result<()> {
+    let file = ctx.file;
+
+    writeln!(w, "use serde::{{Serialize, Deserialize}};")?;
+    if ctx.config.generation.param_types {
+        let methods = &ctx.file.methods;
+        if methods
+            .iter()
+            .any(|m| m.param_structure != ParamStructure::ByPosition)
+        {
+            writeln!(w, "use serde::ser::SerializeMap;")?;
+        }
+        if methods
+            .iter()
+            .any(|m| m.param_structure == ParamStructure::ByPosition)
+        {
+            writeln!(w, "use serde::ser::SerializeSeq;")?;
+        }
+    }
+    if ctx.config.generation.no_std {
+        writeln!(w, "#[allow(unused_imports)]")?;
+        writeln!(w, "use alloc::{{string::String, vec, vec::Vec}};")?;
+        if ctx.config.generation.box_large_variants {
+            writeln!(w, "#[allow(unused_imports)]")?;
+            writeln!(w, "use alloc::boxed::Box;")?;
+        }
+    }
+    for import in &ctx.config.generation.additional_imports {
+        writeln!(w, "use {import};")?;
+    }
+    writeln!(w)?;
+
+    if ctx.config.primitives.is_shared_string() {
+        gen_shared_str_module(w, ctx)?;
+    }
+    if ctx.config.generation.decode_json_strings {
+        gen_json_string_module(w, ctx.config.generation.no_std)?;
+    }
+    if ctx.config.generation.lenient_bool {
+        gen_lenient_bool_module(w, ctx.std_mod())?;
+    }
+    if ctx.config.generation.trim_strings.is_enabled() {
+        gen_trimmed_string_module(w, ctx.config.generation.no_std)?;
+    }
+    if let Some(max) = ctx.config.generation.max_collection_size {
+        gen_capped_module(w, max, ctx.std_mod(), ctx.config.generation.no_std)?;
+    }
+    if ctx.config.generation.capture_extensions {
+        gen_extensions_module(w, ctx)?;
+    }
+    if ctx.config.generation.bounded_vec {
+        gen_bounded_vec(w, ctx.vis(), ctx.std_mod(), ctx.config.generation.arbitrary)?;
+    }
+    for (path, fields) in &ctx.config.generation.remote_types {
+        gen_remote_shim(w, ctx.vis(), path, fields)?;
+    }
+
+    for ty in file.types.values() {
+        gen_type(w, ctx, ty)?;
+    }
+    if ctx.config.generation.id_type {
+        gen_id_type(w, ctx.vis(), ctx.std_mod())?;
+    }
+    if ctx.config.generation.request_type {
+        gen_request_type(w, ctx.vis())?;
+    }
+    if ctx.config.generation.jsonrpc_version {
+        gen_jsonrpc_version(w, ctx)?;
+    }
+    if ctx.config.generation.validate_method {
+        gen_constraint_violation(w, ctx)?;
+    }
+    // An open `Error` enum is useful even when no error is declared, as it still captures the
+    // errors returned by the server.
+    if ctx.config.generation.error_types
+        && (ctx.config.generation.open_errors || !file.errors.is_empty())
+    {
+        gen_error_type(w, ctx.vis(), file, ctx.config.generation.open_errors)?;
+    }
+    if ctx.config.generation.error_code_constants {
+        gen_error_codes_module(w, ctx.vis(), file)?;
+    }
+    for method in &file.methods {
+        gen_method(w, ctx, method)?;
+    }
+    if ctx.config.generation.link_helpers && ctx.config.generation.param_types {
+        gen_link_helpers(w, ctx)?;
+    }
+    if ctx.config.generation.method_enum && !file.methods.is_empty() {
+        gen_method_enum(w, ctx)?;
+        if ctx.config.generation.method_markers {
+            gen_method_markers(w, ctx)?;
+        }
+        if ctx.config.generation.response_enum {
+            gen_response_enum(w, ctx)?;
+        }
+    }
+    if ctx.config.generation.method_specs {
+        gen_method_specs(w, ctx)?;
+    }
+    if ctx.config.generation.client_trait {
+        gen_client_trait(w, ctx)?;
+    }
+    if ctx.config.generation.emit_existence_checks && !file.types.is_empty() {
+        gen_existence_checks(w, file)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a test-only constant naming each type defined by the document once.
+fn gen_existence_checks(w: &mut dyn io::Write, file: &crate::parse::File) -> io::Result<()> {
+    writeln!(
+        w,
+        "/// Checks that every type defined by the document exists."
+    )?;
+    writeln!(w, "#[cfg(test)]")?;
+    writeln!(w, "const _: fn() = || {{")?;
+    for ty in file.types.values() {
+        writeln!(w, "    let _: Option<{}> = None;", ty.name)?;
+    }
+    writeln!(w, "}};")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Returns the `Serialize` and `Deserialize` derives, using the provided `serde` crate path if
+/// any.
+fn serde_derives(serde_crate: Option<&str>) -> Cow<'static, str> {
+    match serde_crate {
+        Some(krate) => Cow::Owned(format!("{krate}::Serialize, {krate}::Deserialize")),
+        None => Cow::Borrowed("Serialize, Deserialize"),
+    }
+}
+
+/// Writes the attribute deriving `arbitrary::Arbitrary` when the `arbitrary` feature is enabled,
+/// if requested.
+fn gen_arbitrary_derive(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    if ctx.config.generation.arbitrary {
+        writeln!(
+            w,
+            "#[cfg_attr(feature = \"arbitrary\", derive(arbitrary::Arbitrary))]"
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes the `#[serde(crate = "...")]` attribute pointing the derives at the provided `serde`
+/// crate path, if any.
+fn gen_serde_crate(w: &mut dyn io::Write, serde_crate: Option<&str>) -> io::Result<()> {
+    if let Some(krate) = serde_crate {
+        writeln!(w, "#[serde(crate = \"{krate}\")]")?;
+    }
+    Ok(())
+}
+
+/// Writes the provided type.
+fn gen_type(w: &mut dyn io::Write, ctx: &mut Ctx, ty: &TypeDef) -> io::Result<()> {
+    let vis = ctx.vis();
+    if ctx.config.debug_path {
+        writeln!(w, "// {}", ty.path)?;
+    }
+    for constraint in &ty.constraints {
+        writeln!(w, "// Unsupported constraint, not enforced: {constraint}")?;
+    }
+    let mut documented = false;
+    if let Some(doc) = &ty.documentation {
+        gen_doc(w, "", doc)?;
+        documented = true;
+    }
+    if let TypeKind::Alias(alias) = &ty.kind {
+        if ctx.config.generation.alias_underlying_note {
+            if documented {
+                writeln!(w, "///")?;
+            }
+            writeln!(w, "/// Alias for `{}`.", ctx.type_ref_name(&alias.ty, true))?;
+            documented = true;
+        }
+    }
+    if ctx.config.generation.source_links {
+        if documented {
+            writeln!(w, "///")?;
+        }
+        writeln!(w, "/// Generated from #{}", ty.pointer)?;
+    }
+    let serde_crate = ctx
+        .config
+        .generation
+        .serde_path_overrides
+        .get(&*ty.path)
+        .map(String::as_str);
+    let serde_derives = serde_derives(serde_crate);
+    match &ty.kind {
+        TypeKind::Alias(alias) => {
+            writeln!(
+                w,
+                "{vis} type {} = {};",
+                ty.name,
+                ctx.type_ref_name(&alias.ty, true)
+            )?;
         }
         TypeKind::Struct(s) => {
-            writeln!(w, "#[derive(Debug, Clone, Serialize, Deserialize)]")?;
-            writeln!(w, "pub struct {} {{", ty.name)?;
+            writeln!(w, "#[derive(Debug, Clone, {serde_derives})]")?;
+            gen_serde_crate(w, serde_crate)?;
+            gen_arbitrary_derive(w, ctx)?;
+            if ctx.infers_copy(&ty.path, &ty.kind) {
+                writeln!(w, "#[derive(Copy)]")?;
+            }
+            if ctx.infers_default(&ty.path) {
+                writeln!(w, "#[derive(Default)]")?;
+            }
+            let mut rename_all = None;
+            if ctx.is_transparent(s) {
+                writeln!(w, "#[serde(transparent)]")?;
+            } else if ctx.config.generation.infer_rename_all {
+                rename_all = struct_rename_rule(s);
+            }
+            if let Some(rule) = rename_all {
+                writeln!(w, "#[serde(rename_all = \"{rule}\")]")?;
+            }
+            writeln!(w, "{vis} struct {} {{", ty.name)?;
             for field in s.fields.values() {
                 if ctx.config.debug_path {
                     writeln!(w, "    // {}", field.path)?;
                 }
+                for constraint in &field.constraints {
+                    writeln!(
+                        w,
+                        "    // Unsupported constraint, not enforced: {constraint}"
+                    )?;
+                }
                 if let Some(doc) = &field.documentation {
-                    writeln!(w, "    /// {}", doc)?;
+                    gen_doc(w, "    ", doc)?;
                 }
-                let name = ctx.type_ref_name(&field.ty, field.required);
-                if !field.required {
-                    writeln!(w, "    #[serde(default)]")?;
+                let required = ctx.is_required(field);
+                let name = match ctx.is_jsonrpc_version(field) {
+                    true if required => Cow::Borrowed(JSONRPC_VERSION_TYPE),
+                    true => Cow::Owned(
+                        ctx.config
+                            .primitives
+                            .optional
+                            .replace("{}", JSONRPC_VERSION_TYPE),
+                    ),
+                    false => ctx.type_ref_name(&field.ty, required),
+                };
+                match ctx.array_default(field) {
+                    Some(items) if !items.is_empty() => {
+                        let default_fn = default_fn_name(&ty.name, field);
+                        writeln!(w, "    #[serde(default = \"{default_fn}\")]")?;
+                    }
+                    _ if !field.required => writeln!(w, "    #[serde(default)]")?,
+                    _ => (),
                 }
                 if field.flatten {
                     writeln!(w, "    #[serde(flatten)]")?;
                 }
-                if field.name != field.name_in_json {
+                if ctx.is_defaultable_flatten(field) {
+                    writeln!(w, "    #[serde(default)]")?;
+                }
+                if field_needs_rename(rename_all, field) {
                     writeln!(w, "    #[serde(rename = \"{}\")]", field.name_in_json)?;
                 }
-                for attr in field.ty.attributes(ctx.config, ctx.file) {
+                if let Some(predicate) = ctx.config.generation.skip_if.get(&*field.path) {
+                    writeln!(w, "    #[serde(skip_serializing_if = \"{predicate}\")]")?;
+                }
+                let attrs = match ctx.is_jsonrpc_version(field) {
+                    true => Vec::new(),
+                    false => field.ty.attributes(ctx.config, ctx.file, required),
+                };
+                if ctx.trims(field, &attrs) {
+                    let deserialize = if required {
+                        "deserialize"
+                    } else {
+                        "option::deserialize"
+                    };
+                    writeln!(
+                        w,
+                        "    #[serde(deserialize_with = \"{TRIMMED_STRING_MODULE}::{deserialize}\")]"
+                    )?;
+                }
+                for attr in attrs {
                     writeln!(w, "    {}", attr)?;
                 }
                 writeln!(w, "    pub {}: {},", field.name, name)?;
             }
+            if ctx.captures_extensions(s) {
+                writeln!(
+                    w,
+                    "    /// The vendor extensions (`x-` properties) of the object."
+                )?;
+                writeln!(w, "    #[serde(flatten, with = \"{EXTENSIONS_MODULE}\")]")?;
+                writeln!(
+                    w,
+                    "    pub extensions: {}<String, serde_json::Value>,",
+                    ctx.extensions_map()
+                )?;
+            }
             writeln!(w, "}}")?;
+            for field in s.fields.values() {
+                match ctx.array_default(field) {
+                    Some(items) if !items.is_empty() => {
+                        writeln!(w)?;
+                        gen_array_default_fn(w, ctx, &ty.name, field, &items)?;
+                    }
+                    _ => (),
+                }
+            }
+            if ctx.config.generation.float_hash.contains(&*ty.path) {
+                writeln!(w)?;
+                gen_float_hash(w, ctx, &ty.name, s)?;
+            }
+            if let Some(ignored) = ctx.config.generation.eq_ignore_fields.get(&*ty.path) {
+                writeln!(w)?;
+                gen_partial_eq_ignoring(w, ctx, &ty.name, s, ignored)?;
+            }
+            if ctx.config.generation.str_partial_eq && ctx.is_transparent(s) {
+                let field = s.fields.values().next().unwrap();
+                if ctx.is_string(&field.ty) {
+                    writeln!(w)?;
+                    gen_str_partial_eq(w, &ty.name, &format!("&*self.{}", field.name))?;
+                }
+            }
+            if let Some(field) = ctx.wrapped_field(s) {
+                writeln!(w)?;
+                gen_wrapper_deref(w, ctx, &ty.name, field)?;
+            }
+            if let Some(field) = ctx.numeric_newtype_field(s) {
+                writeln!(w)?;
+                gen_numeric_from(w, ctx, &ty.name, &field.ty, &field.name)?;
+            }
+            if let Some(field) = ctx.const_newtype_field(s) {
+                writeln!(w)?;
+                gen_const_new(w, ctx, &ty.name, &field.ty, &field.name)?;
+            }
+            if ctx.config.generation.option_default_accessors
+                && s.fields.values().any(|f| ctx.or_default_expr(f).is_some())
+            {
+                writeln!(w)?;
+                gen_or_default_accessors(w, ctx, &ty.name, s)?;
+            }
+            if let Some(template) = ctx.config.generation.display_templates.get(&*ty.path) {
+                writeln!(w)?;
+                gen_display_template(w, ctx, &ty.name, s, template)?;
+            }
+            if ctx.has_validate(s) {
+                writeln!(w)?;
+                gen_validate_method(w, ctx, &ty.name, s)?;
+            }
+        }
+        TypeKind::Tuple(t) => {
+            writeln!(w, "#[derive(Debug, Clone, {serde_derives})]")?;
+            gen_serde_crate(w, serde_crate)?;
+            gen_arbitrary_derive(w, ctx)?;
+            if ctx.infers_copy(&ty.path, &ty.kind) {
+                writeln!(w, "#[derive(Copy)]")?;
+            }
+            let items = t
+                .items
+                .iter()
+                .map(|item| {
+                    let mut attrs = item.attributes(ctx.config, ctx.file, true).join(" ");
+                    if !attrs.is_empty() {
+                        attrs.push(' ');
+                    }
+                    format!("{attrs}pub {}", ctx.type_ref_name(item, true))
+                })
+                .collect::<Vec<_>>();
+            if let [item] = t.items.as_slice() {
+                // `serde` represents single-field tuple structs as their field rather than as an
+                // array. A 1-tuple is used as the JSON representation instead.
+                let shared = item.holds_shared_string(ctx.config, ctx.file);
+                let item = ctx.type_ref_name(item, true);
+                let (repr, wrap, unwrap) = match shared {
+                    true => (
+                        Cow::Owned(format!("{SHARED_STR_MODULE}::De<{item}>")),
+                        format!("{SHARED_STR_MODULE}::De(value.0)"),
+                        "item.0",
+                    ),
+                    false => (item.clone(), "value.0".to_owned(), "item"),
+                };
+                writeln!(w, "#[serde(from = \"({repr},)\", into = \"({repr},)\")]")?;
+                writeln!(w, "{vis} struct {}(pub {item});", ty.name)?;
+                writeln!(w)?;
+                writeln!(w, "impl From<({repr},)> for {} {{", ty.name)?;
+                writeln!(w, "    fn from((item,): ({repr},)) -> Self {{")?;
+                writeln!(w, "        Self({unwrap})")?;
+                writeln!(w, "    }}")?;
+                writeln!(w, "}}")?;
+                writeln!(w)?;
+                writeln!(w, "impl From<{}> for ({repr},) {{", ty.name)?;
+                writeln!(w, "    fn from(value: {}) -> Self {{", ty.name)?;
+                writeln!(w, "        ({wrap},)")?;
+                writeln!(w, "    }}")?;
+                writeln!(w, "}}")?;
+            } else {
+                writeln!(w, "{vis} struct {}({});", ty.name, items.join(", "))?;
+            }
+            if let [item] = t.items.as_slice() {
+                if ctx.config.generation.numeric_newtype_from && ctx.is_numeric(item) {
+                    writeln!(w)?;
+                    gen_numeric_from(w, ctx, &ty.name, item, "0")?;
+                }
+                if ctx.config.generation.const_constructors && ctx.is_copy(item, 0) {
+                    writeln!(w)?;
+                    gen_const_new(w, ctx, &ty.name, item, "0")?;
+                }
+            }
+            if ctx.config.generation.tuple_accessors && !t.items.is_empty() {
+                writeln!(w)?;
+                gen_tuple_accessors(w, ctx, &ty.name, t)?;
+            }
+        }
+        TypeKind::Enum(e) if ctx.collapsed_variant(e).is_some() => {
+            let inner = ctx.collapsed_variant(e).unwrap();
+            writeln!(w, "#[derive({serde_derives})]")?;
+            gen_serde_crate(w, serde_crate)?;
+            gen_arbitrary_derive(w, ctx)?;
+            for global_derive in &ctx.config.generation.global_derives {
+                writeln!(w, "#[derive({global_derive})]")?;
+            }
+            if let Some(derives) = ctx.config.generation.derives.get(&*ty.path) {
+                for derive in derives {
+                    writeln!(w, "#[derive({derive})]")?;
+                }
+            }
+            writeln!(w, "#[serde(transparent)]")?;
+            let mut attrs = inner.attributes(ctx.config, ctx.file, true).join(" ");
+            if !attrs.is_empty() {
+                attrs.push(' ');
+            }
+            writeln!(
+                w,
+                "{vis} struct {}({attrs}pub {});",
+                ty.name,
+                ctx.type_ref_name(inner, true)
+            )?;
+            if ctx.config.generation.str_partial_eq && ctx.is_string(inner) {
+                writeln!(w)?;
+                gen_str_partial_eq(w, &ty.name, "&*self.0")?;
+            }
+            if ctx.config.generation.numeric_newtype_from && ctx.is_numeric(inner) {
+                writeln!(w)?;
+                gen_numeric_from(w, ctx, &ty.name, inner, "0")?;
+            }
+            if ctx.config.generation.const_constructors && ctx.is_copy(inner, 0) {
+                writeln!(w)?;
+                gen_const_new(w, ctx, &ty.name, inner, "0")?;
+            }
+        }
+        TypeKind::Enum(e) => {
+            writeln!(w, "#[derive({serde_derives})]")?;
+            gen_serde_crate(w, serde_crate)?;
+            gen_arbitrary_derive(w, ctx)?;
+            if e.copy {
+                writeln!(w, "#[derive(Copy, PartialEq, Eq, Hash)]")?;
+            }
+            let default_variant = ctx.default_variant(&ty.path, e);
+            if default_variant.is_some() {
+                writeln!(w, "#[derive(Default)]")?;
+            }
+            for global_derive in &ctx.config.generation.global_derives {
+                writeln!(w, "#[derive({global_derive})]")?;
+            }
+            if let Some(derives) = ctx.config.generation.derives.get(&*ty.path) {
+                for derive in derives {
+                    writeln!(w, "#[derive({derive})]")?;
+                }
+            }
+            if let Some(repr) = ctx.config.generation.enum_repr.get(&*ty.path) {
+                writeln!(w, "#[repr({repr})]")?;
+            }
+            let boxed = ctx.boxed_variants(e);
+            if ctx.has_large_variant(e, &boxed) {
+                writeln!(w, "#[allow(clippy::large_enum_variant)]")?;
+            }
+            match &e.tag {
+                EnumTag::Normal => (),
+                EnumTag::Tagged(tag) => {
+                    writeln!(w, "#[serde(tag = \"{}\")]", tag)?;
+                }
+                EnumTag::Untagged => {
+                    let order = ctx
+                        .ordered_variants(e)
+                        .iter()
+                        .map(|v| v.name.as_str())
+                        .collect::<Vec<_>>();
+                    writeln!(w, "// Variants are tried in order: {}.", order.join(", "))?;
+                    writeln!(w, "#[serde(untagged)]")?;
+                }
+            }
+            let rename_all = enum_rename_rule(e);
+            if let Some(rule) = rename_all {
+                writeln!(w, "#[serde(rename_all = \"{rule}\")]")?;
+            }
+            writeln!(w, "{vis} enum {} {{", ty.name)?;
+            for variant in ctx.ordered_variants(e) {
+                if ctx.config.debug_path {
+                    writeln!(w, "    // {}", variant.path)?;
+                }
+                if let Some(doc) = &variant.documentation {
+                    gen_doc(w, "    ", doc)?;
+                }
+                if needs_rename(rename_all, variant) {
+                    let name_in_json = variant.name_in_json.as_deref().unwrap_or(&variant.name);
+                    writeln!(w, "    #[serde(rename = \"{}\")]", name_in_json)?;
+                }
+                if default_variant.is_some_and(|d| d.path == variant.path) {
+                    writeln!(w, "    #[default]")?;
+                }
+                if let Some(inner) = &variant.ty {
+                    for attr in inner.attributes(ctx.config, ctx.file, true) {
+                        writeln!(w, "    {}", attr)?;
+                    }
+                    let mut inner = ctx.type_ref_name(inner, true);
+                    if boxed.contains(&variant.path) {
+                        inner = Cow::Owned(format!("Box<{inner}>"));
+                    }
+                    writeln!(w, "    {}({}),", variant.name, inner)?;
+                } else {
+                    writeln!(w, "    {},", variant.name)?;
+                }
+            }
+            writeln!(w, "}}")?;
+            if ctx.config.generation.variant_eq.contains(&*ty.path) {
+                writeln!(w)?;
+                gen_variant_eq(w, ctx, &ty.name)?;
+            }
+            if ctx.config.generation.variant_constructors
+                && !matches!(e.tag, EnumTag::Untagged)
+                && e.variants.values().any(|v| v.ty.is_some())
+            {
+                writeln!(w)?;
+                gen_variant_constructors(w, ctx, &ty.name, e)?;
+            }
+            if ctx.config.generation.str_partial_eq
+                && matches!(e.tag, EnumTag::Normal)
+                && e.variants.values().all(|v| v.ty.is_none())
+            {
+                writeln!(w)?;
+                gen_enum_as_str(w, &ty.name, e)?;
+                writeln!(w)?;
+                gen_str_partial_eq(w, &ty.name, "self.as_str()")?;
+            }
+        }
+    }
+    if ctx.config.generation.value_tryfrom && !matches!(ty.kind, TypeKind::Alias(_)) {
+        writeln!(w)?;
+        gen_value_tryfrom(w, &ty.name)?;
+    }
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `PartialEq` and `Eq` implementations of the provided enum, comparing only the
+/// variants of its values.
+fn gen_variant_eq(w: &mut dyn io::Write, ctx: &Ctx, name: &str) -> io::Result<()> {
+    let std_mod = ctx.std_mod();
+
+    writeln!(w, "impl PartialEq for {name} {{")?;
+    writeln!(
+        w,
+        "    /// Returns whether both values are the same variant, regardless of their payloads."
+    )?;
+    writeln!(w, "    fn eq(&self, other: &Self) -> bool {{")?;
+    writeln!(
+        w,
+        "        {std_mod}::mem::discriminant(self) == {std_mod}::mem::discriminant(other)"
+    )?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl Eq for {name} {{}}")?;
+
+    Ok(())
+}
+
+/// Writes the `From` conversions between the provided numeric newtype and its inner type, stored
+/// in the `field` field.
+fn gen_numeric_from(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    name: &str,
+    inner: &TypeRef,
+    field: &str,
+) -> io::Result<()> {
+    let inner = ctx.type_ref_name(inner, true);
+    let construct = match field {
+        "0" => "Self(value)".to_owned(),
+        "value" => "Self { value }".to_owned(),
+        _ => format!("Self {{ {field}: value }}"),
+    };
+
+    writeln!(w, "impl From<{inner}> for {name} {{")?;
+    writeln!(w, "    #[inline]")?;
+    writeln!(w, "    fn from(value: {inner}) -> Self {{")?;
+    writeln!(w, "        {construct}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl From<{name}> for {inner} {{")?;
+    writeln!(w, "    #[inline]")?;
+    writeln!(w, "    fn from(value: {name}) -> Self {{")?;
+    writeln!(w, "        value.{field}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes the `const fn new` constructor of the provided newtype, whose inner type is stored in
+/// the `field` field.
+fn gen_const_new(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    name: &str,
+    inner: &TypeRef,
+    field: &str,
+) -> io::Result<()> {
+    let inner = ctx.type_ref_name(inner, true);
+    let construct = match field {
+        "0" => "Self(value)".to_owned(),
+        "value" => "Self { value }".to_owned(),
+        _ => format!("Self {{ {field}: value }}"),
+    };
+
+    writeln!(w, "impl {name} {{")?;
+    writeln!(w, "    /// Creates a new [`{name}`] from its inner value.")?;
+    writeln!(w, "    #[inline]")?;
+    writeln!(w, "    pub const fn new(value: {inner}) -> Self {{")?;
+    writeln!(w, "        {construct}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes the `validate` method of the provided struct, checking the bounds of its fields.
+fn gen_validate_method(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    name: &str,
+    s: &StructDef,
+) -> io::Result<()> {
+    writeln!(w, "impl {name} {{")?;
+    writeln!(
+        w,
+        "    /// Checks the bounds declared by the schemas of the fields of [`{name}`]."
+    )?;
+    writeln!(w, "    ///")?;
+    writeln!(
+        w,
+        "    /// All the violated bounds are returned, in the order of the fields."
+    )?;
+    writeln!(
+        w,
+        "    pub fn validate(&self) -> Result<(), Vec<{CONSTRAINT_VIOLATION_TYPE}>> {{"
+    )?;
+    writeln!(w, "        let mut violations = Vec::new();")?;
+    for field in s.fields.values() {
+        if field.flatten || ctx.is_jsonrpc_version(field) {
+            continue;
+        }
+        let checks = field
+            .bounds
+            .iter()
+            .filter_map(|b| Some((b, ctx.bound_violation(&field.ty, b)?)))
+            .collect::<Vec<_>>();
+        if checks.is_empty() {
+            continue;
+        }
+        if ctx.is_required(field) {
+            writeln!(w, "        {{")?;
+            writeln!(w, "            let value = &self.{};", field.name)?;
+        } else {
+            writeln!(w, "        if let Some(value) = &self.{} {{", field.name)?;
+        }
+        for (bound, condition) in checks {
+            let limit = match bound {
+                Bound::Minimum(n, _) | Bound::Maximum(n, _) => n.to_string(),
+                Bound::MinLength(n)
+                | Bound::MaxLength(n)
+                | Bound::MinItems(n)
+                | Bound::MaxItems(n) => n.to_string(),
+            };
+            writeln!(w, "            if {condition} {{")?;
+            writeln!(
+                w,
+                "                violations.push({CONSTRAINT_VIOLATION_TYPE} {{ field: {:?}, keyword: \"{}\", limit: \"{limit}\" }});",
+                field.name_in_json,
+                bound.keyword(),
+            )?;
+            writeln!(w, "            }}")?;
+        }
+        writeln!(w, "        }}")?;
+    }
+    writeln!(w, "        if violations.is_empty() {{")?;
+    writeln!(w, "            Ok(())")?;
+    writeln!(w, "        }} else {{")?;
+    writeln!(w, "            Err(violations)")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes the `*_or_default` accessors of the optional fields of the provided struct.
+fn gen_or_default_accessors(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    name: &str,
+    s: &StructDef,
+) -> io::Result<()> {
+    writeln!(w, "impl {name} {{")?;
+    let mut first = true;
+    for field in s.fields.values() {
+        let Some(expr) = ctx.or_default_expr(field) else {
+            continue;
+        };
+        if !first {
+            writeln!(w)?;
+        }
+        first = false;
+        match &field.default {
+            Some(default) => writeln!(
+                w,
+                "    /// Returns `{}`, or its default value `{default}` when it is absent.",
+                field.name
+            )?,
+            None => writeln!(
+                w,
+                "    /// Returns `{}`, or the default value of its type when it is absent.",
+                field.name
+            )?,
+        }
+        writeln!(w, "    #[inline]")?;
+        writeln!(
+            w,
+            "    pub fn {}_or_default(&self) -> {} {{",
+            field.name,
+            ctx.type_ref_name(&field.ty, true)
+        )?;
+        writeln!(w, "        {expr}")?;
+        writeln!(w, "    }}")?;
+    }
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes accessor methods returning the items of the provided tuple struct by name.
+fn gen_tuple_accessors(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    name: &str,
+    t: &TupleDef,
+) -> io::Result<()> {
+    writeln!(w, "impl {name} {{")?;
+    for (i, item) in t.items.iter().enumerate() {
+        if i != 0 {
+            writeln!(w)?;
+        }
+        match &t.documentation[i] {
+            Some(doc) => gen_doc(w, "    ", doc)?,
+            None => writeln!(w, "    /// Returns the item at index {i} of the tuple.")?,
+        }
+        writeln!(w, "    #[inline]")?;
+        writeln!(
+            w,
+            "    pub fn {}(&self) -> &{} {{",
+            t.names[i],
+            ctx.type_ref_name(item, true)
+        )?;
+        writeln!(w, "        &self.{i}")?;
+        writeln!(w, "    }}")?;
+    }
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes a `Display` implementation for the provided struct from the provided template of
+/// `generation.display-templates`.
+fn gen_display_template(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    name: &str,
+    s: &StructDef,
+    template: &str,
+) -> io::Result<()> {
+    let std_mod = ctx.std_mod();
+    // Templates and the fields they reference are validated before generating anything.
+    let (format, fields) = crate::config::parse_display_template(template).unwrap();
+    let mut args = String::new();
+    for field in fields {
+        let field = s.fields.values().find(|f| f.name_in_json == field).unwrap();
+        args.push_str(&format!(", self.{}", field.name));
+    }
+
+    writeln!(w, "impl {std_mod}::fmt::Display for {name} {{")?;
+    writeln!(
+        w,
+        "    fn fmt(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
+    )?;
+    writeln!(w, "        write!(f, {format:?}{args})")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes `TryFrom` implementations converting the provided type from and to
+/// `serde_json::Value`.
+fn gen_value_tryfrom(w: &mut dyn io::Write, name: &str) -> io::Result<()> {
+    writeln!(w, "impl TryFrom<serde_json::Value> for {name} {{")?;
+    writeln!(w, "    type Error = serde_json::Error;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {{"
+    )?;
+    writeln!(w, "        serde_json::from_value(value)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl TryFrom<{name}> for serde_json::Value {{")?;
+    writeln!(w, "    type Error = serde_json::Error;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    fn try_from(value: {name}) -> Result<Self, Self::Error> {{"
+    )?;
+    writeln!(w, "        serde_json::to_value(value)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes an `as_str` method returning the name in JSON of the variants of the provided enum.
+///
+/// All the variants of the enum must be unit variants.
+fn gen_enum_as_str(w: &mut dyn io::Write, name: &str, e: &EnumDef) -> io::Result<()> {
+    writeln!(w, "impl {name} {{")?;
+    writeln!(
+        w,
+        "    /// Returns the JSON representation of this value, without quotes."
+    )?;
+    writeln!(w, "    pub const fn as_str(&self) -> &'static str {{")?;
+    writeln!(w, "        match self {{")?;
+    for variant in e.variants.values() {
+        let name_in_json = variant.name_in_json.as_deref().unwrap_or(&variant.name);
+        writeln!(
+            w,
+            "            Self::{} => \"{}\",",
+            variant.name,
+            name_in_json.escape_default()
+        )?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes `PartialEq<str>` and `PartialEq<&str>` implementations for the provided type.
+///
+/// `as_str` is an expression of type `&str` computed from `self`.
+fn gen_str_partial_eq(w: &mut dyn io::Write, name: &str, as_str: &str) -> io::Result<()> {
+    writeln!(w, "impl PartialEq<str> for {name} {{")?;
+    writeln!(w, "    fn eq(&self, other: &str) -> bool {{")?;
+    writeln!(w, "        {as_str} == other")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl PartialEq<&str> for {name} {{")?;
+    writeln!(w, "    fn eq(&self, other: &&str) -> bool {{")?;
+    writeln!(w, "        {as_str} == *other")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes the `Deref` and `DerefMut` implementations of a struct to its only field.
+fn gen_wrapper_deref(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    name: &str,
+    field: &StructField,
+) -> io::Result<()> {
+    let std_mod = ctx.std_mod();
+    let target = ctx.type_ref_name(&field.ty, ctx.is_required(field));
+
+    writeln!(w, "impl {std_mod}::ops::Deref for {name} {{")?;
+    writeln!(w, "    type Target = {target};")?;
+    writeln!(w)?;
+    writeln!(w, "    fn deref(&self) -> &Self::Target {{")?;
+    writeln!(w, "        &self.{}", field.name)?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl {std_mod}::ops::DerefMut for {name} {{")?;
+    writeln!(w, "    fn deref_mut(&mut self) -> &mut Self::Target {{")?;
+    writeln!(w, "        &mut self.{}", field.name)?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes the examples of the provided parameter as a section of its documentation.
+fn gen_param_examples(w: &mut dyn io::Write, param: &MethodParameter) -> io::Result<()> {
+    if param.examples.is_empty() {
+        return Ok(());
+    }
+
+    if param.documentation.is_some() {
+        writeln!(w, "    ///")?;
+    }
+    if param.examples.len() == 1 {
+        writeln!(w, "    /// # Example")?;
+    } else {
+        writeln!(w, "    /// # Examples")?;
+    }
+    for example in &param.examples {
+        writeln!(w, "    ///")?;
+        writeln!(w, "    /// ```json")?;
+        writeln!(w, "    /// {example}")?;
+        writeln!(w, "    /// ```")?;
+    }
+    Ok(())
+}
+
+/// Returns the name of the function providing the default value of the provided field.
+fn default_fn_name(struct_name: &str, field: &StructField) -> String {
+    format!(
+        "default_{}_{}",
+        struct_name.to_case(Case::Snake),
+        field.name
+    )
+}
+
+/// Writes the function providing the default value of the provided field, made of the provided
+/// items.
+fn gen_array_default_fn(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    struct_name: &str,
+    field: &StructField,
+    items: &[String],
+) -> io::Result<()> {
+    writeln!(
+        w,
+        "fn {}() -> {} {{",
+        default_fn_name(struct_name, field),
+        ctx.type_ref_name(&field.ty, true)
+    )?;
+    writeln!(w, "    [{}].into_iter().collect()", items.join(", "))?;
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+/// Writes a `PartialEq` implementation for the provided struct, comparing all its fields except
+/// the ignored ones.
+fn gen_partial_eq_ignoring(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    name: &str,
+    s: &StructDef,
+    ignored: &BTreeSet<String>,
+) -> io::Result<()> {
+    let mut compared = s
+        .fields
+        .values()
+        .filter(|f| !ignored.contains(&f.name_in_json))
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    if ctx.captures_extensions(s) {
+        compared.push("extensions");
+    }
+
+    writeln!(w, "impl PartialEq for {name} {{")?;
+    if compared.is_empty() {
+        writeln!(w, "    fn eq(&self, _other: &Self) -> bool {{")?;
+        writeln!(w, "        true")?;
+    } else {
+        writeln!(w, "    fn eq(&self, other: &Self) -> bool {{")?;
+    }
+    for (i, field) in compared.iter().enumerate() {
+        let and = if i == 0 { "" } else { "    && " };
+        writeln!(w, "        {and}self.{field} == other.{field}")?;
+    }
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Writes `PartialEq`, `Eq` and `Hash` implementations for the provided struct, comparing and
+/// hashing floating point numbers by their bit patterns.
+fn gen_float_hash(w: &mut dyn io::Write, ctx: &Ctx, name: &str, s: &StructDef) -> io::Result<()> {
+    let std_mod = ctx.std_mod();
+    let key = |field: &crate::parse::StructField, expr: &str| {
+        let expr = format!("{expr}.{}", field.name);
+        float_key(ctx, &field.ty, ctx.is_required(field), &expr).unwrap_or(expr)
+    };
+
+    writeln!(w, "impl PartialEq for {name} {{")?;
+    writeln!(w, "    fn eq(&self, other: &Self) -> bool {{")?;
+    if s.fields.is_empty() {
+        writeln!(w, "        true")?;
+    }
+    for (i, field) in s.fields.values().enumerate() {
+        let and = if i == 0 { "" } else { "    && " };
+        writeln!(
+            w,
+            "        {and}{} == {}",
+            key(field, "self"),
+            key(field, "other")
+        )?;
+    }
+    // The extensions can't be hashed, but leaving them out of the hash is consistent with
+    // comparing them.
+    if ctx.captures_extensions(s) {
+        let and = if s.fields.is_empty() { "" } else { "    && " };
+        writeln!(w, "        {and}self.extensions == other.extensions")?;
+    }
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl Eq for {name} {{}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl {std_mod}::hash::Hash for {name} {{")?;
+    writeln!(
+        w,
+        "    fn hash<H: {std_mod}::hash::Hasher>(&self, state: &mut H) {{"
+    )?;
+    for field in s.fields.values() {
+        writeln!(
+            w,
+            "        {std_mod}::hash::Hash::hash(&{}, state);",
+            key(field, "self")
+        )?;
+    }
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+/// Returns an expression computing a value that can be compared and hashed in place of `expr`,
+/// if its type contains floating point numbers.
+fn float_key(ctx: &Ctx, ty: &TypeRef, required: bool, expr: &str) -> Option<String> {
+    if !required {
+        let key = float_key(ctx, ty, true, "v")?;
+        return Some(format!("{expr}.as_ref().map(|v| {key})"));
+    }
+
+    match ty {
+        TypeRef::Number => Some(format!("{expr}.to_bits()")),
+        TypeRef::Array(inner, _) => {
+            let key = float_key(ctx, inner, true, "v")?;
+            Some(format!("{expr}.iter().map(|v| {key}).collect::<Vec<_>>()"))
+        }
+        TypeRef::Ref(path) => match ctx.file.types.get(path).map(|ty| &ty.kind) {
+            Some(TypeKind::Alias(a)) => float_key(ctx, &a.ty, true, expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Writes the `*Outcome` enum of the provided method, combining its result and the errors it
+/// may return.
+fn gen_outcome_type(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    method: &crate::parse::Method,
+    ident_base: &str,
+) -> io::Result<()> {
+    let vis = ctx.vis();
+    let ident = format!("{}Outcome", ident_base.to_case(Case::Pascal));
+    let result = format!("{}Result", ident_base.to_case(Case::Pascal));
+    let errors = method
+        .errors
+        .iter()
+        .filter_map(|path| ctx.file.errors.get(path))
+        .collect::<Vec<_>>();
+
+    writeln!(
+        w,
+        "/// The outcome of a call to `{}`, deserialized from a JSON-RPC response.",
+        method.name
+    )?;
+    writeln!(w, "#[derive(Debug, Clone)]")?;
+    writeln!(w, "{vis} enum {ident} {{")?;
+    writeln!(w, "    /// The call succeeded.")?;
+    writeln!(w, "    Ok({result}),")?;
+    for error in &errors {
+        gen_doc(w, "    ", &error.message)?;
+        writeln!(w, "    {},", error.name)?;
+    }
+    writeln!(w, "    /// An error that is not declared for this method.")?;
+    writeln!(w, "    Other(Error),")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl {ident} {{")?;
+    writeln!(
+        w,
+        "    /// Converts this outcome into a [`Result`], using the [`Error`] type for errors."
+    )?;
+    writeln!(
+        w,
+        "    pub fn into_result(self) -> Result<{result}, Error> {{"
+    )?;
+    writeln!(w, "        match self {{")?;
+    writeln!(w, "            Self::Ok(result) => Ok(result),")?;
+    for error in &errors {
+        writeln!(w, "            Self::{0} => Err(Error::{0}),", error.name)?;
+    }
+    writeln!(w, "            Self::Other(error) => Err(error),")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl<'de> Deserialize<'de> for {ident} {{")?;
+    writeln!(
+        w,
+        "    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        D: serde::Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        #[derive(Deserialize)]")?;
+    writeln!(w, "        struct Helper {{")?;
+    writeln!(w, "            #[serde(default)]")?;
+    writeln!(w, "            result: serde_json::Value,")?;
+    writeln!(w, "            #[serde(default)]")?;
+    writeln!(w, "            error: Option<Error>,")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        let helper = Helper::deserialize(deserializer)?;"
+    )?;
+    writeln!(w, "        match helper.error {{")?;
+    for error in &errors {
+        writeln!(
+            w,
+            "            Some(Error::{0}) => Ok(Self::{0}),",
+            error.name
+        )?;
+    }
+    writeln!(w, "            Some(error) => Ok(Self::Other(error)),")?;
+    if method
+        .result
+        .as_ref()
+        .is_some_and(|r| r.ty.holds_shared_string(ctx.config, ctx.file))
+    {
+        writeln!(
+            w,
+            "            None => serde_json::from_value::<{SHARED_STR_MODULE}::De<{result}>>(helper.result)"
+        )?;
+        writeln!(w, "                .map(|result| Self::Ok(result.0))")?;
+    } else {
+        writeln!(
+            w,
+            "            None => serde_json::from_value(helper.result)"
+        )?;
+        writeln!(w, "                .map(Self::Ok)")?;
+    }
+    writeln!(w, "                .map_err(serde::de::Error::custom),")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// The name of the type generated by `jsonrpc-version`.
+pub const JSONRPC_VERSION_TYPE: &str = "JsonRpcVersion";
+
+/// The name of the type reporting the bounds violated by a value in `validate` methods.
+pub const CONSTRAINT_VIOLATION_TYPE: &str = "ConstraintViolation";
+
+/// Writes the type reporting the bounds violated by a value in `validate` methods.
+fn gen_constraint_violation(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let vis = ctx.vis();
+    let std_mod = ctx.std_mod();
+
+    writeln!(
+        w,
+        "/// A bound declared by the schema of a field that its value violates."
+    )?;
+    writeln!(w, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(w, "{vis} struct {CONSTRAINT_VIOLATION_TYPE} {{")?;
+    writeln!(w, "    /// The name of the field in JSON.")?;
+    writeln!(w, "    pub field: &'static str,")?;
+    writeln!(
+        w,
+        "    /// The keyword declaring the bound, such as `maxLength`."
+    )?;
+    writeln!(w, "    pub keyword: &'static str,")?;
+    writeln!(w, "    /// The value of the keyword.")?;
+    writeln!(w, "    pub limit: &'static str,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "impl {std_mod}::fmt::Display for {CONSTRAINT_VIOLATION_TYPE} {{"
+    )?;
+    writeln!(
+        w,
+        "    fn fmt(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
+    )?;
+    writeln!(
+        w,
+        "        write!(f, \"`{{}}` violates `{{}}: {{}}`\", self.field, self.keyword, self.limit)"
+    )?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `JsonRpcVersion` type, representing the `"2.0"` version of the protocol.
+fn gen_jsonrpc_version(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let vis = ctx.vis();
+    let std_mod = ctx.std_mod();
+
+    writeln!(w, "/// The `\"2.0\"` version of the JSON-RPC protocol.")?;
+    writeln!(w, "///")?;
+    writeln!(
+        w,
+        "/// This always serializes as `\"2.0\"` and fails to deserialize from any other value."
+    )?;
+    writeln!(
+        w,
+        "#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]"
+    )?;
+    gen_arbitrary_derive(w, ctx)?;
+    writeln!(w, "{vis} struct {JSONRPC_VERSION_TYPE};")?;
+    writeln!(w)?;
+    writeln!(w, "impl Serialize for {JSONRPC_VERSION_TYPE} {{")?;
+    writeln!(
+        w,
+        "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "        serializer.serialize_str(\"2.0\")")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "impl<'de> Deserialize<'de> for {JSONRPC_VERSION_TYPE} {{"
+    )?;
+    writeln!(
+        w,
+        "    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+    )?;
+    writeln!(w, "        struct Visitor;")?;
+    writeln!(w)?;
+    writeln!(w, "        impl serde::de::Visitor<'_> for Visitor {{")?;
+    writeln!(w, "            type Value = {JSONRPC_VERSION_TYPE};")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn expecting(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
+    )?;
+    writeln!(w, "                f.write_str(\"\\\"2.0\\\"\")")?;
+    writeln!(w, "            }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {{"
+    )?;
+    writeln!(w, "                if v == \"2.0\" {{")?;
+    writeln!(w, "                    Ok({JSONRPC_VERSION_TYPE})")?;
+    writeln!(w, "                }} else {{")?;
+    writeln!(
+        w,
+        "                    Err(E::invalid_value(serde::de::Unexpected::Str(v), &self))"
+    )?;
+    writeln!(w, "                }}")?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(w, "        deserializer.deserialize_str(Visitor)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `Id` type, representing the identifier of a JSON-RPC request.
+fn gen_id_type(w: &mut dyn io::Write, vis: &str, std_mod: &str) -> io::Result<()> {
+    writeln!(w, "/// The identifier of a JSON-RPC request.")?;
+    writeln!(w, "///")?;
+    writeln!(
+        w,
+        "/// A `null` identifier is distinct from a missing one, which marks a notification."
+    )?;
+    writeln!(w, "#[derive(Debug, Clone, PartialEq, Eq, Hash)]")?;
+    writeln!(w, "{vis} enum Id {{")?;
+    writeln!(w, "    /// A non-negative integer identifier.")?;
+    writeln!(w, "    Unsigned(u64),")?;
+    writeln!(w, "    /// A negative integer identifier.")?;
+    writeln!(w, "    Signed(i64),")?;
+    writeln!(w, "    /// A string identifier.")?;
+    writeln!(w, "    String(String),")?;
+    writeln!(w, "    /// The `null` identifier.")?;
+    writeln!(w, "    Null,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl Serialize for Id {{")?;
+    writeln!(
+        w,
+        "    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        S: serde::Serializer,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        match self {{")?;
+    writeln!(
+        w,
+        "            Self::Unsigned(id) => serializer.serialize_u64(*id),"
+    )?;
+    writeln!(
+        w,
+        "            Self::Signed(id) => serializer.serialize_i64(*id),"
+    )?;
+    writeln!(
+        w,
+        "            Self::String(id) => serializer.serialize_str(id),"
+    )?;
+    writeln!(w, "            Self::Null => serializer.serialize_unit(),")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl<'de> Deserialize<'de> for Id {{")?;
+    writeln!(
+        w,
+        "    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        D: serde::Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        struct Visitor;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        impl<'de> serde::de::Visitor<'de> for Visitor {{"
+    )?;
+    writeln!(w, "            type Value = Id;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn expecting(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
+    )?;
+    writeln!(
+        w,
+        "                f.write_str(\"an integer, a string or null\")"
+    )?;
+    writeln!(w, "            }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {{"
+    )?;
+    writeln!(w, "                Ok(Id::Unsigned(v))")?;
+    writeln!(w, "            }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {{"
+    )?;
+    writeln!(w, "                match u64::try_from(v) {{")?;
+    writeln!(w, "                    Ok(v) => Ok(Id::Unsigned(v)),")?;
+    writeln!(w, "                    Err(_) => Ok(Id::Signed(v)),")?;
+    writeln!(w, "                }}")?;
+    writeln!(w, "            }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {{"
+    )?;
+    writeln!(w, "                Ok(Id::String(v.into()))")?;
+    writeln!(w, "            }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {{"
+    )?;
+    writeln!(w, "                Ok(Id::String(v))")?;
+    writeln!(w, "            }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn visit_unit<E>(self) -> Result<Self::Value, E> {{"
+    )?;
+    writeln!(w, "                Ok(Id::Null)")?;
+    writeln!(w, "            }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn visit_none<E>(self) -> Result<Self::Value, E> {{"
+    )?;
+    writeln!(w, "                Ok(Id::Null)")?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(w, "        deserializer.deserialize_any(Visitor)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `*Error` enum of the provided method, with one variant per error it declares,
+/// along with its conversions from and to the `Error` enum.
+fn gen_method_error_type(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    method: &crate::parse::Method,
+    ident_base: &str,
+) -> io::Result<()> {
+    let vis = ctx.vis();
+    let ident = format!("{}Error", ident_base.to_case(Case::Pascal));
+    let mut errors = method
+        .errors
+        .iter()
+        .map(|path| &ctx.file.errors[path])
+        .collect::<Vec<_>>();
+    errors.sort_by_key(|e| e.code);
+    errors.dedup_by_key(|e| e.code);
+
+    writeln!(
+        w,
+        "/// An error that may be returned by the `{}` method.",
+        method.name
+    )?;
+    writeln!(w, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(w, "{vis} enum {ident} {{")?;
+    for error in &errors {
+        gen_doc(w, "    ", &error.message)?;
+        writeln!(w, "    {},", error.name)?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl {ident} {{")?;
+    writeln!(w, "    /// Returns the code of this error.")?;
+    writeln!(w, "    pub const fn code(&self) -> i64 {{")?;
+    writeln!(w, "        match self {{")?;
+    for error in &errors {
+        writeln!(w, "            Self::{} => {},", error.name, error.code)?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl From<{ident}> for Error {{")?;
+    writeln!(w, "    fn from(error: {ident}) -> Self {{")?;
+    writeln!(w, "        match error {{")?;
+    for error in &errors {
+        writeln!(w, "            {ident}::{0} => Self::{0},", error.name)?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl TryFrom<Error> for {ident} {{")?;
+    writeln!(w, "    type Error = Error;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    /// Returns the provided error back if the method does not declare it."
+    )?;
+    writeln!(w, "    fn try_from(error: Error) -> Result<Self, Error> {{")?;
+    writeln!(w, "        match error {{")?;
+    for error in &errors {
+        writeln!(w, "            Error::{0} => Ok(Self::{0}),", error.name)?;
+    }
+    if ctx.config.generation.open_errors || errors.len() < ctx.file.errors.len() {
+        writeln!(w, "            error => Err(error),")?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `Request` type, representing a JSON-RPC request or notification.
+///
+/// Notifications are serialized without an `id` member, as required by JSON-RPC 2.0.
+fn gen_request_type(w: &mut dyn io::Write, vis: &str) -> io::Result<()> {
+    writeln!(
+        w,
+        "/// A JSON-RPC request, or a notification when it has no identifier."
+    )?;
+    writeln!(w, "#[derive(Debug, Clone)]")?;
+    writeln!(w, "{vis} struct Request<P> {{")?;
+    writeln!(
+        w,
+        "    /// The identifier of the request, or `None` for a notification."
+    )?;
+    writeln!(w, "    pub id: Option<Id>,")?;
+    writeln!(w, "    /// The name of the method to call.")?;
+    writeln!(w, "    pub method: String,")?;
+    writeln!(w, "    /// The parameters of the call.")?;
+    writeln!(w, "    pub params: P,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl<P> Request<P> {{")?;
+    writeln!(w, "    /// Creates a new request expecting a response.")?;
+    writeln!(
+        w,
+        "    pub fn new(id: Id, method: impl Into<String>, params: P) -> Self {{"
+    )?;
+    writeln!(w, "        Self {{")?;
+    writeln!(w, "            id: Some(id),")?;
+    writeln!(w, "            method: method.into(),")?;
+    writeln!(w, "            params,")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    /// Creates a new notification, which expects no response."
+    )?;
+    writeln!(
+        w,
+        "    pub fn notification(method: impl Into<String>, params: P) -> Self {{"
+    )?;
+    writeln!(w, "        Self {{")?;
+    writeln!(w, "            id: None,")?;
+    writeln!(w, "            method: method.into(),")?;
+    writeln!(w, "            params,")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    /// Returns whether this call is a notification.")?;
+    writeln!(w, "    pub fn is_notification(&self) -> bool {{")?;
+    writeln!(w, "        self.id.is_none()")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl<P: Serialize> Serialize for Request<P> {{")?;
+    writeln!(
+        w,
+        "    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        S: serde::Serializer,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        use serde::ser::SerializeMap;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        let len = if self.id.is_some() {{ 4 }} else {{ 3 }};"
+    )?;
+    writeln!(
+        w,
+        "        let mut map = serializer.serialize_map(Some(len))?;"
+    )?;
+    writeln!(w, "        map.serialize_entry(\"jsonrpc\", \"2.0\")?;")?;
+    writeln!(w, "        map.serialize_entry(\"method\", &self.method)?;")?;
+    writeln!(w, "        map.serialize_entry(\"params\", &self.params)?;")?;
+    writeln!(w, "        if let Some(id) = &self.id {{")?;
+    writeln!(w, "            map.serialize_entry(\"id\", id)?;")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "        map.end()")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// The error codes reserved by the JSON-RPC 2.0 specification.
+const STANDARD_ERROR_CODES: &[(&str, i64, &str)] = &[
+    (
+        "PARSE_ERROR",
+        -32700,
+        "Invalid JSON was received by the server.",
+    ),
+    (
+        "INVALID_REQUEST",
+        -32600,
+        "The JSON sent is not a valid request object.",
+    ),
+    (
+        "METHOD_NOT_FOUND",
+        -32601,
+        "The method does not exist or is not available.",
+    ),
+    ("INVALID_PARAMS", -32602, "Invalid method parameters."),
+    ("INTERNAL_ERROR", -32603, "Internal JSON-RPC error."),
+];
+
+/// Writes the `error_codes` module, with one constant per standard JSON-RPC error code and per
+/// error declared in the document.
+///
+/// Declared errors take precedence over the standard constants sharing their code or name.
+fn gen_error_codes_module(
+    w: &mut dyn io::Write,
+    vis: &str,
+    file: &crate::parse::File,
+) -> io::Result<()> {
+    let mut errors = file.errors.values().collect::<Vec<_>>();
+    errors.sort_by_key(|e| e.code);
+    let declared = errors
+        .iter()
+        .map(|e| (e.name.to_case(Case::UpperSnake), e.code, e.message.as_str()))
+        .collect::<Vec<_>>();
+
+    writeln!(w, "/// The codes of the errors that methods may return.")?;
+    writeln!(w, "{vis} mod error_codes {{")?;
+    for &(name, code, message) in STANDARD_ERROR_CODES {
+        if declared.iter().any(|(n, c, _)| n == name || *c == code) {
+            continue;
+        }
+        gen_doc(w, "    ", message)?;
+        writeln!(w, "    pub const {name}: i64 = {code};")?;
+    }
+    for (name, code, message) in &declared {
+        gen_doc(w, "    ", message)?;
+        writeln!(w, "    pub const {name}: i64 = {code};")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `Error` enum, with one variant per error that methods may return.
+///
+/// When `open` is set, the enum includes an `Unknown` variant for the codes that are not
+/// declared in the document.
+fn gen_error_type(
+    w: &mut dyn io::Write,
+    vis: &str,
+    file: &crate::parse::File,
+    open: bool,
+) -> io::Result<()> {
+    let mut errors = file.errors.values().collect::<Vec<_>>();
+    errors.sort_by_key(|e| e.code);
+
+    writeln!(w, "/// An error that may be returned by a method.")?;
+    if open {
+        writeln!(w, "#[derive(Debug, Clone, PartialEq)]")?;
+        writeln!(w, "#[non_exhaustive]")?;
+    } else {
+        writeln!(w, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    }
+    writeln!(w, "{vis} enum Error {{")?;
+    for error in &errors {
+        gen_doc(w, "    ", &error.message)?;
+        writeln!(w, "    {},", error.name)?;
+    }
+    if open {
+        writeln!(
+            w,
+            "    /// An error whose code is not declared in the OpenRPC document."
+        )?;
+        writeln!(w, "    Unknown {{")?;
+        writeln!(w, "        /// The code of the error.")?;
+        writeln!(w, "        code: i64,")?;
+        writeln!(w, "        /// The message of the error.")?;
+        writeln!(w, "        message: String,")?;
+        writeln!(w, "        /// Additional information about the error.")?;
+        writeln!(w, "        data: Option<serde_json::Value>,")?;
+        writeln!(w, "    }},")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl Error {{")?;
+    writeln!(w, "    /// Returns the code of this error.")?;
+    writeln!(w, "    pub const fn code(&self) -> i64 {{")?;
+    writeln!(w, "        match self {{")?;
+    for error in &errors {
+        writeln!(w, "            Self::{} => {},", error.name, error.code)?;
+    }
+    if open {
+        writeln!(w, "            Self::Unknown {{ code, .. }} => *code,")?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    /// Returns the message of this error, as declared in the OpenRPC document."
+    )?;
+    if open {
+        writeln!(w, "    pub fn message(&self) -> &str {{")?;
+    } else {
+        writeln!(w, "    pub const fn message(&self) -> &'static str {{")?;
+    }
+    writeln!(w, "        match self {{")?;
+    for error in &errors {
+        writeln!(
+            w,
+            "            Self::{} => {:?},",
+            error.name, error.message
+        )?;
+    }
+    if open {
+        writeln!(w, "            Self::Unknown {{ message, .. }} => message,")?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl Serialize for Error {{")?;
+    writeln!(
+        w,
+        "    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        S: serde::Serializer,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        use serde::ser::SerializeMap as _;")?;
+    writeln!(w)?;
+    writeln!(w, "        let mut map = serializer.serialize_map(None)?;")?;
+    writeln!(w, "        map.serialize_entry(\"code\", &self.code())?;")?;
+    writeln!(
+        w,
+        "        map.serialize_entry(\"message\", self.message())?;"
+    )?;
+    if open {
+        writeln!(
+            w,
+            "        if let Self::Unknown {{ data: Some(data), .. }} = self {{"
+        )?;
+        writeln!(w, "            map.serialize_entry(\"data\", data)?;")?;
+        writeln!(w, "        }}")?;
+    }
+    writeln!(w, "        map.end()")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl<'de> Deserialize<'de> for Error {{")?;
+    writeln!(
+        w,
+        "    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        D: serde::Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        #[derive(Deserialize)]")?;
+    writeln!(w, "        struct Helper {{")?;
+    writeln!(w, "            code: i64,")?;
+    if open {
+        writeln!(w, "            #[serde(default)]")?;
+        writeln!(w, "            message: String,")?;
+        writeln!(w, "            #[serde(default)]")?;
+        writeln!(w, "            data: Option<serde_json::Value>,")?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        let helper = Helper::deserialize(deserializer)?;"
+    )?;
+    if errors.is_empty() {
+        // Only possible for open errors.
+        writeln!(w, "        Ok(Self::Unknown {{")?;
+        writeln!(w, "            code: helper.code,")?;
+        writeln!(w, "            message: helper.message,")?;
+        writeln!(w, "            data: helper.data,")?;
+        writeln!(w, "        }})")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        return Ok(());
+    }
+    writeln!(w, "        match helper.code {{")?;
+    for error in &errors {
+        writeln!(w, "            {} => Ok(Self::{}),", error.code, error.name)?;
+    }
+    if open {
+        writeln!(w, "            code => Ok(Self::Unknown {{")?;
+        writeln!(w, "                code,")?;
+        writeln!(w, "                message: helper.message,")?;
+        writeln!(w, "                data: helper.data,")?;
+        writeln!(w, "            }}),")?;
+    } else {
+        writeln!(
+            w,
+            "            code => Err(serde::de::Error::custom(format_args!(\"unknown error code: {{code}}\"))),"
+        )?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `Method` enum, with one variant per OpenRPC method.
+fn gen_method_enum(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let vis = ctx.vis();
+    let variants = ctx
+        .file
+        .methods
+        .iter()
+        .map(|m| (m, ctx.method_ident_base(m).to_case(Case::Pascal)))
+        .collect::<Vec<_>>();
+
+    writeln!(w, "/// A method of the API.")?;
+    writeln!(w, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(w, "{vis} enum Method {{")?;
+    for (method, variant) in &variants {
+        writeln!(w, "    /// `{}`", method.name)?;
+        writeln!(w, "    {variant},")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl Method {{")?;
+    writeln!(
+        w,
+        "    /// Returns the method with the provided name, which may be an alias."
+    )?;
+    writeln!(w, "    pub fn from_name(name: &str) -> Option<Self> {{")?;
+    writeln!(w, "        match name {{")?;
+    for (method, variant) in &variants {
+        let mut pattern = format!("{:?}", method.name);
+        for alias in ctx.method_aliases(method) {
+            pattern.push_str(&format!(" | {alias:?}"));
+        }
+        writeln!(w, "            {pattern} => Some(Self::{variant}),")?;
+    }
+    writeln!(w, "            _ => None,")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    /// Returns the canonical name of the method.")?;
+    writeln!(w, "    pub const fn name(&self) -> &'static str {{")?;
+    writeln!(w, "        match self {{")?;
+    for (method, variant) in &variants {
+        writeln!(w, "            Self::{variant} => {:?},", method.name)?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `Response` enum, with one variant per OpenRPC method holding its result.
+///
+/// Results don't name the method they come from, so the enum is deserialized through
+/// `Response::deserialize_for` rather than a `Deserialize` implementation.
+fn gen_response_enum(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let vis = ctx.vis();
+    let variants = ctx
+        .file
+        .methods
+        .iter()
+        .map(|m| {
+            let result = match &m.result {
+                Some(result) => ctx.type_ref_name(&result.ty, true),
+                None => Cow::Borrowed("()"),
+            };
+            let size = m
+                .result
+                .as_ref()
+                .map_or(0, |result| ctx.type_ref_size(&result.ty, true));
+            (
+                m,
+                ctx.method_ident_base(m).to_case(Case::Pascal),
+                result,
+                size,
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut sizes = variants.iter().map(|v| v.3).collect::<Vec<_>>();
+    sizes.sort_unstable_by_key(|&size| Reverse(size));
+    let holds_shared_string = |m: &crate::parse::Method| {
+        m.result
+            .as_ref()
+            .is_some_and(|result| result.ty.holds_shared_string(ctx.config, ctx.file))
+    };
+
+    writeln!(w, "/// The result of a method of the API.")?;
+    writeln!(w, "#[derive(Debug, Clone)]")?;
+    if let [largest, second, ..] = sizes[..] {
+        if largest - second >= LARGE_ENUM_VARIANT_THRESHOLD {
+            writeln!(w, "#[allow(clippy::large_enum_variant)]")?;
+        }
+    }
+    writeln!(w, "{vis} enum Response {{")?;
+    for (method, variant, result, _) in &variants {
+        writeln!(w, "    /// The result of `{}`.", method.name)?;
+        writeln!(w, "    {variant}({result}),")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl Response {{")?;
+    writeln!(w, "    /// Deserializes the result of the provided method.")?;
+    writeln!(
+        w,
+        "    pub fn deserialize_for<'de, D>(method: Method, deserializer: D) -> Result<Self, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        D: serde::Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        match method {{")?;
+    for (method, variant, result, _) in &variants {
+        if holds_shared_string(method) {
+            writeln!(
+                w,
+                "            Method::{variant} => {SHARED_STR_MODULE}::deserialize(deserializer).map(Self::{variant}),"
+            )?;
+        } else {
+            writeln!(
+                w,
+                "            Method::{variant} => <{result}>::deserialize(deserializer).map(Self::{variant}),"
+            )?;
+        }
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    /// Returns the method this is the result of.")?;
+    writeln!(w, "    pub const fn method(&self) -> Method {{")?;
+    writeln!(w, "        match self {{")?;
+    for (_, variant, _, _) in &variants {
+        writeln!(w, "            Self::{variant}(_) => Method::{variant},")?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl Serialize for Response {{")?;
+    writeln!(
+        w,
+        "    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        S: serde::Serializer,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        match self {{")?;
+    for (method, variant, _, _) in &variants {
+        if holds_shared_string(method) {
+            writeln!(
+                w,
+                "            Self::{variant}(result) => {SHARED_STR_MODULE}::serialize(result, serializer),"
+            )?;
+        } else {
+            writeln!(
+                w,
+                "            Self::{variant}(result) => result.serialize(serializer),"
+            )?;
+        }
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the sealed `RpcMethod` trait, along with one unit type implementing it per OpenRPC
+/// method.
+fn gen_method_markers(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let vis = ctx.vis();
+
+    writeln!(w, "mod sealed {{")?;
+    writeln!(w, "    pub trait Sealed {{}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "/// A method of the API, tied to its parameter and result types."
+    )?;
+    writeln!(w, "///")?;
+    writeln!(
+        w,
+        "/// This trait is sealed and implemented by one unit type per method."
+    )?;
+    writeln!(w, "{vis} trait RpcMethod: sealed::Sealed {{")?;
+    writeln!(
+        w,
+        "    /// The variant of [`Method`] describing the method."
+    )?;
+    writeln!(w, "    const METHOD: Method;")?;
+    writeln!(w, "    /// The parameters of the method.")?;
+    writeln!(w, "    type Params;")?;
+    writeln!(w, "    /// The result of the method.")?;
+    writeln!(w, "    type Result;")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    for method in &ctx.file.methods {
+        let pascal = ctx.method_ident_base(method).to_case(Case::Pascal);
+        let result = match &method.result {
+            Some(result) => ctx.type_ref_name(&result.ty, true),
+            None => Cow::Borrowed("()"),
+        };
+
+        writeln!(w, "/// The `{}` method.", method.name)?;
+        writeln!(
+            w,
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]"
+        )?;
+        writeln!(w, "{vis} struct {pascal}Method;")?;
+        writeln!(w)?;
+        writeln!(w, "impl sealed::Sealed for {pascal}Method {{}}")?;
+        writeln!(w)?;
+        writeln!(w, "impl RpcMethod for {pascal}Method {{")?;
+        writeln!(w, "    const METHOD: Method = Method::{pascal};")?;
+        writeln!(w, "    type Params = {pascal}Params;")?;
+        writeln!(w, "    type Result = {result};")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the `MethodSpec` type and the `method_specs` function listing the methods of the API.
+fn gen_method_specs(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let vis = ctx.vis();
+
+    writeln!(
+        w,
+        "/// The structure in which the parameters of a method are passed."
+    )?;
+    writeln!(w, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(w, "{vis} enum ParamStructure {{")?;
+    writeln!(w, "    /// Parameters are passed as an object.")?;
+    writeln!(w, "    ByName,")?;
+    writeln!(w, "    /// Parameters are passed as an array.")?;
+    writeln!(w, "    ByPosition,")?;
+    writeln!(w, "    /// Parameters may be passed either way.")?;
+    writeln!(w, "    Either,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "/// Describes a method of the API.")?;
+    writeln!(w, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(w, "{vis} struct MethodSpec {{")?;
+    writeln!(w, "    /// The canonical name of the method.")?;
+    writeln!(w, "    pub name: &'static str,")?;
+    writeln!(
+        w,
+        "    /// The other names under which the method is available."
+    )?;
+    writeln!(w, "    pub aliases: &'static [&'static str],")?;
+    writeln!(
+        w,
+        "    /// The structure in which the parameters are passed."
+    )?;
+    writeln!(w, "    pub param_structure: ParamStructure,")?;
+    writeln!(w, "    /// The number of parameters that must be provided.")?;
+    writeln!(w, "    pub required_params: usize,")?;
+    writeln!(w, "    /// The total number of parameters.")?;
+    writeln!(w, "    pub params: usize,")?;
+    writeln!(
+        w,
+        "    /// Whether the method is a notification, which does not return a result."
+    )?;
+    writeln!(w, "    pub notification: bool,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "/// Returns the specification of every method of the API."
+    )?;
+    writeln!(w, "{vis} fn method_specs() -> &'static [MethodSpec] {{")?;
+    writeln!(w, "    &[")?;
+    for method in &ctx.file.methods {
+        let aliases = ctx
+            .method_aliases(method)
+            .iter()
+            .map(|alias| format!("{alias:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let param_structure = match method.param_structure {
+            ParamStructure::ByName => "ByName",
+            ParamStructure::ByPosition => "ByPosition",
+            ParamStructure::Either => "Either",
+        };
+        writeln!(w, "        MethodSpec {{")?;
+        writeln!(w, "            name: {:?},", method.name)?;
+        writeln!(w, "            aliases: &[{aliases}],")?;
+        writeln!(
+            w,
+            "            param_structure: ParamStructure::{param_structure},"
+        )?;
+        writeln!(
+            w,
+            "            required_params: {},",
+            method.params.iter().filter(|p| p.required).count()
+        )?;
+        writeln!(w, "            params: {},", method.params.len())?;
+        writeln!(w, "            notification: {},", method.result.is_none())?;
+        writeln!(w, "        }},")?;
+    }
+    writeln!(w, "    ]")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `Client` trait, with one method per OpenRPC method.
+fn gen_client_trait(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let vis = ctx.vis();
+    let async_trait = ctx.config.generation.async_style == crate::config::AsyncStyle::AsyncTrait;
+
+    writeln!(w, "/// A client able to call the methods of the API.")?;
+    if async_trait {
+        writeln!(
+            w,
+            "#[{}::async_trait]",
+            ctx.config.generation.async_trait_crate
+        )?;
+        // The default methods borrow `self` in futures that are `Send`.
+        writeln!(w, "{vis} trait Client: Sync {{")?;
+    } else {
+        writeln!(w, "#[allow(async_fn_in_trait)]")?;
+        writeln!(w, "{vis} trait Client {{")?;
+    }
+    writeln!(w, "    /// The error returned when a call fails.")?;
+    writeln!(w, "    type Error;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    /// Calls the method with the provided name and parameters, returning its result."
+    )?;
+    writeln!(
+        w,
+        "    async fn call<P, R>(&self, method: &str, params: P) -> Result<R, Self::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    if async_trait {
+        // The futures returned by `#[async_trait]` methods are `Send`, and hold the parameters.
+        writeln!(w, "        P: Serialize + Send,")?;
+    } else {
+        writeln!(w, "        P: Serialize,")?;
+    }
+    writeln!(w, "        R: serde::de::DeserializeOwned;")?;
+
+    for method in &ctx.file.methods {
+        let ident_base = ctx.method_ident_base(method);
+        let result = match &method.result {
+            Some(result) => ctx.type_ref_name(&result.ty, true),
+            None => Cow::Borrowed("()"),
+        };
+
+        writeln!(w)?;
+        match &method.documentation {
+            Some(doc) => gen_doc(w, "    ", doc)?,
+            None => writeln!(w, "    /// Calls the `{}` method.", method.name)?,
+        }
+        if ctx.config.generation.error_types && !method.errors.is_empty() {
+            writeln!(w, "    ///")?;
+            writeln!(w, "    /// # Errors")?;
+            writeln!(w, "    ///")?;
+            writeln!(
+                w,
+                "    /// The server may respond with the following errors:"
+            )?;
+            writeln!(w, "    ///")?;
+            for path in &method.errors {
+                writeln!(w, "    /// - [`Error::{}`]", ctx.file.errors[path].name)?;
+            }
+        }
+        if ctx.config.generation.tracing {
+            writeln!(
+                w,
+                "    #[{}::instrument(name = \"{}\", skip_all)]",
+                ctx.config.generation.tracing_crate, method.name
+            )?;
+        }
+        writeln!(
+            w,
+            "    async fn {}(&self, params: {}Params) -> Result<{result}, Self::Error> {{",
+            ident_base.to_case(Case::Snake),
+            ident_base.to_case(Case::Pascal),
+        )?;
+        writeln!(w, "        self.call(\"{}\", params).await", method.name)?;
+        writeln!(w, "    }}")?;
+    }
+
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes a `From` implementation building the parameters of a method from a tuple.
+fn gen_params_from_tuple(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    method: &crate::parse::Method,
+    ident: &str,
+) -> io::Result<()> {
+    let tuple = tuple_of(
+        method
+            .params
+            .iter()
+            .map(|p| ctx.type_ref_name(&p.ty, p.required).into_owned())
+            .collect(),
+    );
+    let pattern = tuple_of(method.params.iter().map(|p| p.name.clone()).collect());
+
+    writeln!(w, "impl From<{tuple}> for {ident} {{")?;
+    writeln!(w, "    #[inline]")?;
+    writeln!(w, "    fn from({pattern}: {tuple}) -> Self {{")?;
+    writeln!(w, "        Self {{")?;
+    for param in &method.params {
+        writeln!(w, "            {},", param.name)?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes a macro building the parameters of a method from `key: value` pairs.
+///
+/// The pairs are munched one by one, recording which optional parameters have been provided in
+/// a list of `name flag` pairs (`!` when provided, `_` otherwise). The missing ones are then
+/// filled with their default value, while missing required parameters are reported by the
+/// compiler as missing fields of the struct literal.
+fn gen_params_macro(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    method: &crate::parse::Method,
+    ident_base: &str,
+    ident: &str,
+) -> io::Result<()> {
+    let vis = ctx.vis();
+    let name = format!("{}_params", ident_base.to_case(Case::Snake));
+    let optional = method
+        .params
+        .iter()
+        .filter(|p| !p.required)
+        .map(|p| p.name.as_str())
+        .collect::<Vec<_>>();
+    let state = |flags: &[String]| {
+        optional
+            .iter()
+            .zip(flags)
+            .map(|(p, f)| format!("{p} {f}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    // Qualifying the macro and the type lets the macro be used without importing them.
+    let (this, target) = match ctx.config.generation.module_path {
+        Some(ref module) => (format!("{module}::{name}"), format!("{module}::{ident}")),
+        None => (name.clone(), ident.to_owned()),
+    };
+
+    writeln!(w, "/// Builds a [`{ident}`] from `key: value` pairs.")?;
+    writeln!(w, "///")?;
+    writeln!(
+        w,
+        "/// Omitted optional parameters are set to their default value."
+    )?;
+    if ctx.config.generation.module_path.is_none() {
+        writeln!(w, "///")?;
+        writeln!(
+            w,
+            "/// Both this macro and [`{ident}`] must be in scope where it is used."
+        )?;
+    }
+    writeln!(w, "#[allow(unused_macros)]")?;
+    writeln!(w, "macro_rules! {name} {{")?;
+    for (i, param) in optional.iter().enumerate() {
+        let pattern = (0..optional.len())
+            .map(|j| format!("$f{j}:tt"))
+            .collect::<Vec<_>>();
+        let flags = (0..optional.len())
+            .map(|j| if i == j { "!".into() } else { format!("$f{j}") })
+            .collect::<Vec<_>>();
+        writeln!(
+            w,
+            "    (@munch {{ $($acc:tt)* }} [{}] {param} : $value:expr $(, $($rest:tt)*)?) => {{",
+            state(&pattern),
+        )?;
+        writeln!(
+            w,
+            "        {this}!(@munch {{ $($acc)* {param}: From::from($value), }} [{}] $($($rest)*)?)",
+            state(&flags),
+        )?;
+        writeln!(w, "    }};")?;
+    }
+    writeln!(
+        w,
+        "    (@munch {{ $($acc:tt)* }} $state:tt $key:ident : $value:expr $(, $($rest:tt)*)?) => {{"
+    )?;
+    writeln!(
+        w,
+        "        {this}!(@munch {{ $($acc)* $key: $value, }} $state $($($rest)*)?)"
+    )?;
+    writeln!(w, "    }};")?;
+    writeln!(w, "    (@munch {{ $($acc:tt)* }} $state:tt) => {{")?;
+    writeln!(w, "        {this}!(@fill {{ $($acc)* }} $state)")?;
+    writeln!(w, "    }};")?;
+    writeln!(
+        w,
+        "    (@fill {{ $($acc:tt)* }} [$param:ident _ $($rest:tt)*]) => {{"
+    )?;
+    writeln!(
+        w,
+        "        {this}!(@fill {{ $($acc)* $param: Default::default(), }} [$($rest)*])"
+    )?;
+    writeln!(w, "    }};")?;
+    writeln!(
+        w,
+        "    (@fill {{ $($acc:tt)* }} [$param:ident ! $($rest:tt)*]) => {{"
+    )?;
+    writeln!(w, "        {this}!(@fill {{ $($acc)* }} [$($rest)*])")?;
+    writeln!(w, "    }};")?;
+    writeln!(w, "    (@fill {{ $($acc:tt)* }} []) => {{")?;
+    writeln!(w, "        {target} {{ $($acc)* }}")?;
+    writeln!(w, "    }};")?;
+    writeln!(w, "    ($($key:ident : $value:expr),* $(,)?) => {{")?;
+    writeln!(
+        w,
+        "        {this}!(@munch {{}} [{}] $($key : $value),*)",
+        state(&vec!["_".into(); optional.len()]),
+    )?;
+    writeln!(w, "    }};")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "#[allow(unused_imports)]")?;
+    if vis == "pub" {
+        writeln!(w, "pub(crate) use {name};")?;
+    } else {
+        writeln!(w, "{vis} use {name};")?;
+    }
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes a `from_rpc_params` function reading the parameters of a method from a JSON value,
+/// dispatching on whether it is an array or an object.
+fn gen_params_from_value(
+    w: &mut dyn io::Write,
+    method: &crate::parse::Method,
+    ident: &str,
+) -> io::Result<()> {
+    writeln!(w, "impl {ident} {{")?;
+    writeln!(
+        w,
+        "    /// Reads the parameters of `{}` from the `params` of a request.",
+        method.name
+    )?;
+    writeln!(w, "    ///")?;
+    writeln!(
+        w,
+        "    /// Arrays are read as positional parameters, and objects as named parameters."
+    )?;
+    writeln!(
+        w,
+        "    pub fn from_rpc_params(value: serde_json::Value) -> Result<Self, serde_json::Error> {{"
+    )?;
+    writeln!(w, "        use serde::de::value::{{MapAccessDeserializer, MapDeserializer, SeqAccessDeserializer, SeqDeserializer}};")?;
+    writeln!(w)?;
+    writeln!(w, "        match value {{")?;
+    writeln!(w, "            serde_json::Value::Array(items) => Self::deserialize(SeqAccessDeserializer::new(SeqDeserializer::new(items.into_iter()))),")?;
+    writeln!(w, "            serde_json::Value::Object(map) => Self::deserialize(MapAccessDeserializer::new(MapDeserializer::new(map.into_iter()))),")?;
+    writeln!(w, "            value => Self::deserialize(value),")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the wrapper applying the serde attributes of the parameter at index `i` when it is
+/// serialized, returning the expression to serialize in its place.
+///
+/// Nothing is written when the parameter has no such attribute.
+fn gen_param_serialize_wrapper(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    param: &MethodParameter,
+    i: usize,
+) -> io::Result<String> {
+    let Some(serialize) = param
+        .ty
+        .serialize_with(ctx.config, ctx.file, param.required)
+    else {
+        return Ok(format!("&self.{}", param.name));
+    };
+
+    let ty = ctx.type_ref_name(&param.ty, param.required);
+    writeln!(w, "        struct Param{i}<'a>(&'a {ty});")?;
+    writeln!(w)?;
+    writeln!(w, "        impl Serialize for Param{i}<'_> {{")?;
+    writeln!(
+        w,
+        "            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "                {serialize}(self.0, serializer)")?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+
+    Ok(format!("&Param{i}(&self.{})", param.name))
+}
+
+/// Writes an `into_values` method returning the parameters of a method as positional JSON values.
+fn gen_params_into_values(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    method: &crate::parse::Method,
+    ident: &str,
+) -> io::Result<()> {
+    writeln!(w, "impl {ident} {{")?;
+    writeln!(
+        w,
+        "    /// Returns the parameters as a list of JSON values, in the order expected by `{}`.",
+        method.name
+    )?;
+    writeln!(w, "    ///")?;
+    writeln!(w, "    /// # Panics")?;
+    writeln!(w, "    ///")?;
+    writeln!(
+        w,
+        "    /// This function panics if one of the parameters cannot be represented as JSON."
+    )?;
+    writeln!(
+        w,
+        "    pub fn into_values(self) -> Vec<serde_json::Value> {{"
+    )?;
+    // The values go through the same wrappers as the `Serialize` implementation, so that both
+    // agree on the representation of each parameter.
+    let values = method
+        .params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| gen_param_serialize_wrapper(w, ctx, param, i))
+        .collect::<io::Result<Vec<_>>>()?;
+    writeln!(w, "        vec![")?;
+    for (param, value) in method.params.iter().zip(&values) {
+        // `self` is owned, its fields and the wrappers can be passed by value.
+        let value = value.strip_prefix('&').unwrap_or(value);
+        writeln!(
+            w,
+            "            serde_json::to_value({value}).expect(\"failed to serialize `{}`\"),",
+            param.name_in_json
+        )?;
+    }
+    writeln!(w, "        ]")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the `BoundedVec` type, used for arrays with a maximum number of items.
+fn gen_bounded_vec(
+    w: &mut dyn io::Write,
+    vis: &str,
+    std_mod: &str,
+    arbitrary: bool,
+) -> io::Result<()> {
+    writeln!(w, "/// A `Vec` holding at most `N` items.")?;
+    writeln!(w, "#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]")?;
+    writeln!(w, "#[serde(transparent)]")?;
+    writeln!(w, "{vis} struct BoundedVec<T, const N: usize>(Vec<T>);")?;
+    writeln!(w)?;
+    writeln!(w, "#[allow(dead_code)]")?;
+    writeln!(w, "impl<T, const N: usize> BoundedVec<T, N> {{")?;
+    writeln!(
+        w,
+        "    /// Creates a new [`BoundedVec`], or gives the provided vector back if it holds more"
+    )?;
+    writeln!(w, "    /// than `N` items.")?;
+    writeln!(w, "    pub fn new(vec: Vec<T>) -> Result<Self, Vec<T>> {{")?;
+    writeln!(w, "        if vec.len() <= N {{")?;
+    writeln!(w, "            Ok(Self(vec))")?;
+    writeln!(w, "        }} else {{")?;
+    writeln!(w, "            Err(vec)")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    /// Returns the inner vector.")?;
+    writeln!(w, "    pub fn into_inner(self) -> Vec<T> {{")?;
+    writeln!(w, "        self.0")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl<T, const N: usize> Default for BoundedVec<T, N> {{")?;
+    writeln!(w, "    fn default() -> Self {{")?;
+    writeln!(w, "        Self(Vec::new())")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "impl<T, const N: usize> {std_mod}::ops::Deref for BoundedVec<T, N> {{"
+    )?;
+    writeln!(w, "    type Target = [T];")?;
+    writeln!(w)?;
+    writeln!(w, "    fn deref(&self) -> &[T] {{")?;
+    writeln!(w, "        &self.0")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for BoundedVec<T, N> {{"
+    )?;
+    writeln!(
+        w,
+        "    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+    )?;
+    writeln!(w, "        let vec = Vec::<T>::deserialize(deserializer)?;")?;
+    writeln!(w, "        Self::new(vec).map_err(|vec| {{")?;
+    writeln!(w, "            serde::de::Error::custom(format_args!(")?;
+    writeln!(
+        w,
+        "                \"expected at most {{N}} items, found {{}}\","
+    )?;
+    writeln!(w, "                vec.len()")?;
+    writeln!(w, "            ))")?;
+    writeln!(w, "        }})")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    if arbitrary {
+        writeln!(w, "#[cfg(feature = \"arbitrary\")]")?;
+        writeln!(
+            w,
+            "impl<'a, T: arbitrary::Arbitrary<'a>, const N: usize> arbitrary::Arbitrary<'a> for BoundedVec<T, N> {{"
+        )?;
+        writeln!(
+            w,
+            "    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {{"
+        )?;
+        writeln!(w, "        let len = u.int_in_range(0..=N)?;")?;
+        writeln!(w, "        (0..len)")?;
+        writeln!(w, "            .map(|_| T::arbitrary(u))")?;
+        writeln!(w, "            .collect::<arbitrary::Result<Vec<T>>>()")?;
+        writeln!(w, "            .map(Self)")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the `remote` shim of the external type at the provided path, along with the module
+/// (de)serializing optional values through it.
+fn gen_remote_shim(
+    w: &mut dyn io::Write,
+    vis: &str,
+    path: &str,
+    fields: &BTreeMap<String, String>,
+) -> io::Result<()> {
+    let (shim, option_module) = remote_shim_names(path);
+
+    writeln!(w, "/// Mirrors [`{path}`] to (de)serialize it.")?;
+    writeln!(w, "#[derive(Serialize, Deserialize)]")?;
+    writeln!(w, "#[serde(remote = \"{path}\")]")?;
+    writeln!(w, "{vis} struct {shim} {{")?;
+    for (name, ty) in fields {
+        writeln!(w, "    pub {name}: {ty},")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "#[allow(dead_code)]")?;
+    writeln!(w, "mod {option_module} {{")?;
+    writeln!(
+        w,
+        "    use serde::{{Deserialize, Deserializer, Serialize, Serializer}};"
+    )?;
+    writeln!(w)?;
+    writeln!(w, "    struct Ref<'a>(&'a {path});")?;
+    writeln!(w)?;
+    writeln!(w, "    impl Serialize for Ref<'_> {{")?;
+    writeln!(
+        w,
+        "        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "            super::{shim}::serialize(self.0, serializer)"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    #[derive(Deserialize)]")?;
+    writeln!(
+        w,
+        "    struct Owned(#[serde(with = \"super::{shim}\")] {path});"
+    )?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn serialize<S>(value: &Option<{path}>, serializer: S) -> Result<S::Ok, S::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        S: Serializer,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        value.as_ref().map(Ref).serialize(serializer)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<{path}>, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        D: Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(
+        w,
+        "        Ok(Option::<Owned>::deserialize(deserializer)?.map(|v| v.0))"
+    )?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the module used to (de)serialize the vendor extensions of objects, keeping only their
+/// `x-` properties.
+fn gen_extensions_module(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let map = ctx.extensions_map();
+    let (_, map_name) = map.rsplit_once("::").unwrap();
+    let std_mod = ctx.std_mod();
+
+    writeln!(w, "#[allow(dead_code)]")?;
+    writeln!(w, "mod {EXTENSIONS_MODULE} {{")?;
+    if ctx.config.generation.no_std {
+        writeln!(w, "    use alloc::string::String;")?;
+    }
+    writeln!(w, "    use {map};")?;
+    writeln!(w)?;
+    writeln!(w, "    use serde::de::{{IgnoredAny, MapAccess, Visitor}};")?;
+    writeln!(w, "    use serde::{{Deserializer, Serializer}};")?;
+    writeln!(w, "    use serde_json::Value;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn serialize<S>(value: &{map_name}<String, Value>, serializer: S) -> Result<S::Ok, S::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        S: Serializer,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        serializer.collect_map(value)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn deserialize<'de, D>(deserializer: D) -> Result<{map_name}<String, Value>, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        D: Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        struct Extensions;")?;
+    writeln!(w)?;
+    writeln!(w, "        impl<'de> Visitor<'de> for Extensions {{")?;
+    writeln!(w, "            type Value = {map_name}<String, Value>;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn expecting(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
+    )?;
+    writeln!(w, "                f.write_str(\"an object\")")?;
+    writeln!(w, "            }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {{"
+    )?;
+    writeln!(w, "                let mut extensions = {map_name}::new();")?;
+    writeln!(
+        w,
+        "                while let Some(key) = map.next_key::<String>()? {{"
+    )?;
+    writeln!(w, "                    if key.starts_with(\"x-\") {{")?;
+    writeln!(
+        w,
+        "                        extensions.insert(key, map.next_value()?);"
+    )?;
+    writeln!(w, "                    }} else {{")?;
+    writeln!(
+        w,
+        "                        map.next_value::<IgnoredAny>()?;"
+    )?;
+    writeln!(w, "                    }}")?;
+    writeln!(w, "                }}")?;
+    writeln!(w, "                Ok(extensions)")?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(w, "        deserializer.deserialize_map(Extensions)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the module used to deserialize booleans from JSON booleans, from the strings `"true"`
+/// and `"false"`, and from the integers `0` and `1`.
+fn gen_lenient_bool_module(w: &mut dyn io::Write, std_mod: &str) -> io::Result<()> {
+    writeln!(w, "#[allow(dead_code)]")?;
+    writeln!(w, "mod {LENIENT_BOOL_MODULE} {{")?;
+    writeln!(w, "    use serde::de::{{Error, Unexpected, Visitor}};")?;
+    writeln!(w, "    use serde::Deserializer;")?;
+    writeln!(w)?;
+    writeln!(w, "    struct LenientBool;")?;
+    writeln!(w)?;
+    writeln!(w, "    impl<'de> Visitor<'de> for LenientBool {{")?;
+    writeln!(w, "        type Value = bool;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn expecting(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
+    )?;
+    writeln!(
+        w,
+        "            f.write_str(\"a boolean, \\\"true\\\", \\\"false\\\", 0 or 1\")"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn visit_bool<E: Error>(self, v: bool) -> Result<bool, E> {{"
+    )?;
+    writeln!(w, "            Ok(v)")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn visit_str<E: Error>(self, v: &str) -> Result<bool, E> {{"
+    )?;
+    writeln!(w, "            match v {{")?;
+    writeln!(w, "                \"true\" => Ok(true),")?;
+    writeln!(w, "                \"false\" => Ok(false),")?;
+    writeln!(
+        w,
+        "                _ => Err(E::invalid_value(Unexpected::Str(v), &self)),"
+    )?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "        }}")?;
+    for (ty, unexpected) in [("u64", "Unsigned"), ("i64", "Signed")] {
+        writeln!(w)?;
+        writeln!(
+            w,
+            "        fn visit_{ty}<E: Error>(self, v: {ty}) -> Result<bool, E> {{"
+        )?;
+        writeln!(w, "            match v {{")?;
+        writeln!(w, "                0 => Ok(false),")?;
+        writeln!(w, "                1 => Ok(true),")?;
+        writeln!(
+            w,
+            "                _ => Err(E::invalid_value(Unexpected::{unexpected}(v), &self)),"
+        )?;
+        writeln!(w, "            }}")?;
+        writeln!(w, "        }}")?;
+    }
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        T: From<bool>,")?;
+    writeln!(w, "        D: Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(
+        w,
+        "        deserializer.deserialize_any(LenientBool).map(T::from)"
+    )?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    pub mod option {{")?;
+    writeln!(w, "        use serde::{{Deserialize, Deserializer}};")?;
+    writeln!(w)?;
+    writeln!(w, "        #[derive(Deserialize)]")?;
+    writeln!(
+        w,
+        "        struct Lenient(#[serde(deserialize_with = \"super::deserialize\")] bool);"
+    )?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>"
+    )?;
+    writeln!(w, "        where")?;
+    writeln!(w, "            T: From<bool>,")?;
+    writeln!(w, "            D: Deserializer<'de>,")?;
+    writeln!(w, "        {{")?;
+    writeln!(
+        w,
+        "            Ok(Option::<Lenient>::deserialize(deserializer)?.map(|b| T::from(b.0)))"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the module used to deserialize strings without their leading and trailing
+/// whitespace.
+fn gen_trimmed_string_module(w: &mut dyn io::Write, no_std: bool) -> io::Result<()> {
+    writeln!(w, "#[allow(dead_code)]")?;
+    writeln!(w, "mod {TRIMMED_STRING_MODULE} {{")?;
+    if no_std {
+        writeln!(w, "    use alloc::string::String;")?;
+    }
+    writeln!(w, "    use serde::{{Deserialize, Deserializer}};")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        T: From<String>,")?;
+    writeln!(w, "        D: Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        let s = String::deserialize(deserializer)?;")?;
+    writeln!(w, "        if s.trim().len() == s.len() {{")?;
+    writeln!(w, "            Ok(T::from(s))")?;
+    writeln!(w, "        }} else {{")?;
+    writeln!(w, "            Ok(T::from(s.trim().into()))")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    pub mod option {{")?;
+    if no_std {
+        writeln!(w, "        use alloc::string::String;")?;
+    }
+    writeln!(w, "        use serde::{{Deserialize, Deserializer}};")?;
+    writeln!(w)?;
+    writeln!(w, "        #[derive(Deserialize)]")?;
+    writeln!(
+        w,
+        "        struct Trimmed(#[serde(deserialize_with = \"super::deserialize\")] String);"
+    )?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>"
+    )?;
+    writeln!(w, "        where")?;
+    writeln!(w, "            T: From<String>,")?;
+    writeln!(w, "            D: Deserializer<'de>,")?;
+    writeln!(w, "        {{")?;
+    writeln!(
+        w,
+        "            Ok(Option::<Trimmed>::deserialize(deserializer)?.map(|s| T::from(s.0)))"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the module used to deserialize strings and arrays holding at most `max` bytes or
+/// items.
+fn gen_capped_module(
+    w: &mut dyn io::Write,
+    max: usize,
+    std_mod: &str,
+    no_std: bool,
+) -> io::Result<()> {
+    writeln!(w, "#[allow(dead_code)]")?;
+    writeln!(w, "mod {CAPPED_MODULE} {{")?;
+    if no_std {
+        writeln!(w, "    use alloc::string::String;")?;
+        writeln!(w, "    use alloc::vec::Vec;")?;
+    }
+    writeln!(w, "    use {std_mod}::marker::PhantomData;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    use serde::de::{{Deserialize, Deserializer, Error, SeqAccess, Visitor}};"
+    )?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    /// The maximum number of items of arrays, and of bytes of strings."
+    )?;
+    writeln!(w, "    pub const MAX_SIZE: usize = {max};")?;
+    writeln!(w)?;
+    writeln!(w, "    struct CappedString;")?;
+    writeln!(w)?;
+    writeln!(w, "    impl Visitor<'_> for CappedString {{")?;
+    writeln!(w, "        type Value = String;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn expecting(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
+    )?;
+    writeln!(
+        w,
+        "            write!(f, \"a string of at most {{MAX_SIZE}} bytes\")"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn visit_str<E: Error>(self, v: &str) -> Result<String, E> {{"
+    )?;
+    writeln!(w, "            if v.len() > MAX_SIZE {{")?;
+    writeln!(
+        w,
+        "                return Err(E::invalid_length(v.len(), &self));"
+    )?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "            Ok(v.into())")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    struct CappedSeq<T>(PhantomData<T>);")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    impl<'de, T: Deserialize<'de>> Visitor<'de> for CappedSeq<T> {{"
+    )?;
+    writeln!(w, "        type Value = Vec<T>;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn expecting(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
+    )?;
+    writeln!(
+        w,
+        "            write!(f, \"a sequence of at most {{MAX_SIZE}} items\")"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<T>, A::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "            let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(MAX_SIZE));"
+    )?;
+    writeln!(
+        w,
+        "            while let Some(item) = seq.next_element()? {{"
+    )?;
+    writeln!(w, "                if vec.len() == MAX_SIZE {{")?;
+    writeln!(
+        w,
+        "                    return Err(A::Error::invalid_length(MAX_SIZE + 1, &self));"
+    )?;
+    writeln!(w, "                }}")?;
+    writeln!(w, "                vec.push(item);")?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "            Ok(vec)")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn string<'de, T, D>(deserializer: D) -> Result<T, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        T: From<String>,")?;
+    writeln!(w, "        D: Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(
+        w,
+        "        deserializer.deserialize_str(CappedString).map(T::from)"
+    )?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn seq<'de, C, T, D>(deserializer: D) -> Result<C, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        C: FromIterator<T>,")?;
+    writeln!(w, "        T: Deserialize<'de>,")?;
+    writeln!(w, "        D: Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        deserializer")?;
+    writeln!(w, "            .deserialize_seq(CappedSeq(PhantomData))")?;
+    writeln!(w, "            .map(|vec| vec.into_iter().collect())")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    pub mod option {{")?;
+    if no_std {
+        writeln!(w, "        use alloc::string::String;")?;
+    }
+    writeln!(w, "        use {std_mod}::marker::PhantomData;")?;
+    writeln!(w)?;
+    writeln!(w, "        use serde::{{Deserialize, Deserializer}};")?;
+    writeln!(w)?;
+    writeln!(w, "        struct Capped<C, T>(C, PhantomData<T>);")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        impl<'de, C: FromIterator<T>, T: Deserialize<'de>> Deserialize<'de> for Capped<C, T> {{"
+    )?;
+    writeln!(
+        w,
+        "            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "                super::seq(deserializer).map(|c| Capped(c, PhantomData))"
+    )?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(w, "        #[derive(Deserialize)]")?;
+    writeln!(
+        w,
+        "        struct CappedString(#[serde(deserialize_with = \"super::string\")] String);"
+    )?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        pub fn string<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>"
+    )?;
+    writeln!(w, "        where")?;
+    writeln!(w, "            T: From<String>,")?;
+    writeln!(w, "            D: Deserializer<'de>,")?;
+    writeln!(w, "        {{")?;
+    writeln!(
+        w,
+        "            Ok(Option::<CappedString>::deserialize(deserializer)?.map(|s| T::from(s.0)))"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        pub fn seq<'de, C, T, D>(deserializer: D) -> Result<Option<C>, D::Error>"
+    )?;
+    writeln!(w, "        where")?;
+    writeln!(w, "            C: FromIterator<T>,")?;
+    writeln!(w, "            T: Deserialize<'de>,")?;
+    writeln!(w, "            D: Deserializer<'de>,")?;
+    writeln!(w, "        {{")?;
+    writeln!(
+        w,
+        "            Ok(Option::<Capped<C, T>>::deserialize(deserializer)?.map(|c| c.0))"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the module used to (de)serialize shared string types (such as `Arc<str>`) without
+/// requiring the `rc` feature of `serde`.
+///
+/// The module implements its `Shared` trait for the shared string type, and for optional values
+/// and arrays of types implementing it, so that it can be used for any type holding shared
+/// strings.
+fn gen_shared_str_module(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let string = &ctx.config.primitives.string;
+    let array = ctx.config.primitives.array.replace("{}", "T");
+    writeln!(w, "#[allow(dead_code)]")?;
+    writeln!(w, "mod {SHARED_STR_MODULE} {{")?;
+    if ctx.config.generation.no_std {
+        writeln!(w, "    use alloc::string::String;")?;
+        writeln!(w, "    use alloc::vec::Vec;")?;
+    }
+    writeln!(
+        w,
+        "    use serde::{{Deserialize, Deserializer, Serialize, Serializer}};"
+    )?;
+    writeln!(w)?;
+    writeln!(w, "    #[allow(unused_imports)]")?;
+    writeln!(w, "    use super::*;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    /// A type holding shared strings, (de)serialized through this module."
+    )?;
+    writeln!(w, "    pub trait Shared: Sized {{")?;
+    writeln!(
+        w,
+        "        fn serialize_shared<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;"
+    )?;
+    writeln!(
+        w,
+        "        fn deserialize_shared<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;"
+    )?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    impl Shared for {string} {{")?;
+    writeln!(
+        w,
+        "        fn serialize_shared<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "            serializer.serialize_str(self)")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn deserialize_shared<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+    )?;
+    if ctx.config.generation.max_collection_size.is_some() {
+        writeln!(
+            w,
+            "            super::{CAPPED_MODULE}::string::<String, D>(deserializer).map(Self::from)"
+        )?;
+    } else {
+        writeln!(
+            w,
+            "            String::deserialize(deserializer).map(Self::from)"
+        )?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    impl<T: Shared> Shared for Option<T> {{")?;
+    writeln!(
+        w,
+        "        fn serialize_shared<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "            match self {{")?;
+    writeln!(
+        w,
+        "                Some(value) => serializer.serialize_some(&Ser(value)),"
+    )?;
+    writeln!(w, "                None => serializer.serialize_none(),")?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn deserialize_shared<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "            Ok(Option::<De<T>>::deserialize(deserializer)?.map(|value| value.0))"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    impl<T: Shared> Shared for {array} {{")?;
+    gen_shared_str_seq(w, ctx, "")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    if ctx.config.generation.bounded_vec {
+        writeln!(
+            w,
+            "    impl<T: Shared, const N: usize> Shared for super::BoundedVec<T, N> {{"
+        )?;
+        gen_shared_str_seq(w, ctx, ";\n            Self::new(items).map_err(|items| {\n                serde::de::Error::custom(format_args!(\"expected at most {N} items, found {}\", items.len()))\n            })")?;
+        writeln!(w, "    }}")?;
+        writeln!(w)?;
+    }
+    writeln!(
+        w,
+        "    /// Serializes the referenced value through [`Shared`]."
+    )?;
+    writeln!(w, "    pub struct Ser<'a, T>(pub &'a T);")?;
+    writeln!(w)?;
+    writeln!(w, "    impl<T: Shared> Serialize for Ser<'_, T> {{")?;
+    writeln!(
+        w,
+        "        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "            self.0.serialize_shared(serializer)")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    /// Deserializes and serializes the wrapped value through [`Shared`]."
+    )?;
+    writeln!(w, "    pub struct De<T>(pub T);")?;
+    writeln!(w)?;
+    writeln!(w, "    impl<T: Shared> Serialize for De<T> {{")?;
+    writeln!(
+        w,
+        "        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "            self.0.serialize_shared(serializer)")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    impl<'de, T: Shared> Deserialize<'de> for De<T> {{")?;
+    writeln!(
+        w,
+        "        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+    )?;
+    writeln!(w, "            T::deserialize_shared(deserializer).map(De)")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn serialize<T: Shared, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "        value.serialize_shared(serializer)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn deserialize<'de, T: Shared, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {{"
+    )?;
+    writeln!(w, "        T::deserialize_shared(deserializer)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the methods implementing the `Shared` trait of the shared string module for a
+/// sequence type.
+///
+/// The deserialized items are collected into the sequence unless `finish` is provided, in which
+/// case they are bound to `items` and `finish` is appended to build the sequence from them.
+fn gen_shared_str_seq(w: &mut dyn io::Write, ctx: &Ctx, finish: &str) -> io::Result<()> {
+    writeln!(
+        w,
+        "        fn serialize_shared<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "            serializer.collect_seq(self.iter().map(Ser))"
+    )?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        fn deserialize_shared<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+    )?;
+    let items = if ctx.config.generation.max_collection_size.is_some() {
+        format!("super::{CAPPED_MODULE}::seq::<Vec<De<T>>, De<T>, D>(deserializer)")
+    } else {
+        "Vec::<De<T>>::deserialize(deserializer)".to_owned()
+    };
+    if finish.is_empty() {
+        writeln!(
+            w,
+            "            Ok({items}?.into_iter().map(|item| item.0).collect())"
+        )?;
+    } else {
+        writeln!(w, "            let items = {items}?.into_iter().map(|item| item.0).collect::<Vec<T>>(){finish}")?;
+    }
+    writeln!(w, "        }}")?;
+
+    Ok(())
+}
+
+/// Writes the module used to (de)serialize values encoded as JSON strings.
+fn gen_json_string_module(w: &mut dyn io::Write, no_std: bool) -> io::Result<()> {
+    writeln!(w, "#[allow(dead_code)]")?;
+    writeln!(w, "mod {JSON_STRING_MODULE} {{")?;
+    if no_std {
+        writeln!(w, "    use alloc::string::String;")?;
+    }
+    writeln!(w, "    use serde::de::DeserializeOwned;")?;
+    writeln!(
+        w,
+        "    use serde::{{Deserialize, Deserializer, Serialize, Serializer}};"
+    )?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        T: Serialize,")?;
+    writeln!(w, "        S: Serializer,")?;
+    writeln!(w, "    {{")?;
+    writeln!(
+        w,
+        "        let json = serde_json::to_string(value).map_err(serde::ser::Error::custom)?;"
+    )?;
+    writeln!(w, "        serializer.serialize_str(&json)")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        T: DeserializeOwned,")?;
+    writeln!(w, "        D: Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+    writeln!(w, "        let json = String::deserialize(deserializer)?;")?;
+    writeln!(
+        w,
+        "        serde_json::from_str(&json).map_err(serde::de::Error::custom)"
+    )?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(w, "    pub mod option {{")?;
+    if no_std {
+        writeln!(w, "        use alloc::string::String;")?;
+    }
+    writeln!(w, "        use serde::de::DeserializeOwned;")?;
+    writeln!(
+        w,
+        "        use serde::{{Deserialize, Deserializer, Serialize, Serializer}};"
+    )?;
+    writeln!(w)?;
+    writeln!(w, "        pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>")?;
+    writeln!(w, "        where")?;
+    writeln!(w, "            T: Serialize,")?;
+    writeln!(w, "            S: Serializer,")?;
+    writeln!(w, "        {{")?;
+    writeln!(w, "            match value {{")?;
+    writeln!(
+        w,
+        "                Some(value) => super::serialize(value, serializer),"
+    )?;
+    writeln!(w, "                None => serializer.serialize_none(),")?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>"
+    )?;
+    writeln!(w, "        where")?;
+    writeln!(w, "            T: DeserializeOwned,")?;
+    writeln!(w, "            D: Deserializer<'de>,")?;
+    writeln!(w, "        {{")?;
+    writeln!(
+        w,
+        "            match Option::<String>::deserialize(deserializer)? {{"
+    )?;
+    writeln!(
+        w,
+        "                Some(json) => serde_json::from_str(&json).map(Some).map_err(serde::de::Error::custom),"
+    )?;
+    writeln!(w, "                None => Ok(None),")?;
+    writeln!(w, "            }}")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes an `impl` block with one constructor function per variant of the provided enum.
+fn gen_variant_constructors(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    name: &str,
+    e: &EnumDef,
+) -> io::Result<()> {
+    let boxed = ctx.boxed_variants(e);
+    writeln!(w, "impl {name} {{")?;
+    for (i, variant) in e.variants.values().enumerate() {
+        if i != 0 {
+            writeln!(w)?;
         }
-        TypeKind::Enum(e) => {
-            writeln!(w, "#[derive(Serialize, Deserialize)]")?;
-            if e.copy {
-                writeln!(w, "#[derive(Copy, PartialEq, Eq, Hash)]")?;
-            }
-            for global_derive in &ctx.config.generation.global_derives {
-                writeln!(w, "#[derive({global_derive})]")?;
-            }
-            if let Some(derives) = ctx.config.generation.derives.get(&*ty.path) {
-                for derive in derives {
-                    writeln!(w, "#[derive({derive})]")?;
-                }
-            }
-            match &e.tag {
-                EnumTag::Normal => (),
-                EnumTag::Tagged(tag) => {
-                    writeln!(w, "#[serde(tag = \"{}\")]", tag)?;
-                }
-                EnumTag::Untagged => {
-                    writeln!(w, "#[serde(untagged)]")?;
-                }
-            }
-            writeln!(w, "pub enum {} {{", ty.name)?;
-            for variant in e.variants.values() {
-                if ctx.config.debug_path {
-                    writeln!(w, "    // {}", variant.path)?;
-                }
-                if let Some(doc) = &variant.documentation {
-                    writeln!(w, "    /// {}", doc)?;
-                }
-                if let Some(name_in_json) = &variant.name_in_json {
-                    if name_in_json != &variant.name {
-                        writeln!(w, "    #[serde(rename = \"{}\")]", name_in_json)?;
-                    }
-                }
-                if let Some(inner) = &variant.ty {
-                    writeln!(
-                        w,
-                        "    {}({}),",
-                        variant.name,
-                        ctx.type_ref_name(inner, true)
-                    )?;
+        let fn_name = format!("new_{}", snake_case(&variant.name));
+        writeln!(w, "    /// Creates a new [`{name}::{}`].", variant.name)?;
+        match &variant.ty {
+            Some(inner) => {
+                writeln!(w, "    #[inline]")?;
+                writeln!(
+                    w,
+                    "    pub fn {fn_name}(value: {}) -> Self {{",
+                    ctx.type_ref_name(inner, true),
+                )?;
+                if boxed.contains(&variant.path) {
+                    writeln!(w, "        Self::{}(Box::new(value))", variant.name)?;
                 } else {
-                    writeln!(w, "    {},", variant.name)?;
+                    writeln!(w, "        Self::{}(value)", variant.name)?;
                 }
             }
-            writeln!(w, "}}")?;
+            None => {
+                writeln!(w, "    #[inline]")?;
+                writeln!(w, "    pub const fn {fn_name}() -> Self {{")?;
+                writeln!(w, "        Self::{}", variant.name)?;
+            }
         }
+        writeln!(w, "    }}")?;
     }
-    writeln!(w)?;
+    writeln!(w, "}}")?;
 
     Ok(())
 }
@@ -195,27 +4762,34 @@ fn gen_method(
     ctx: &mut Ctx,
     method: &crate::parse::Method,
 ) -> io::Result<()> {
-    let std_mod = if ctx.config.generation.use_core {
-        "core"
-    } else {
-        "std"
-    };
+    let vis = ctx.vis();
+    let std_mod = ctx.std_mod();
 
-    let ident_base = if let Some(ref prefix) = ctx.config.generation.method_name_prefix {
-        method.name.strip_prefix(prefix).unwrap_or(&method.name)
-    } else {
-        &method.name
-    };
+    let ident_base = ctx.method_ident_base(method);
 
     if ctx.config.generation.method_name_constants {
         writeln!(w, "/// `{}`", method.name)?;
         writeln!(
             w,
-            "pub const {}: &str = \"{}\";",
+            "{vis} const {}: &str = \"{}\";",
             ident_base.to_case(Case::ScreamingSnake),
             method.name
         )?;
         writeln!(w)?;
+
+        for alias in ctx.method_aliases(method) {
+            let alias_base = match ctx.config.generation.method_name_prefix {
+                Some(ref prefix) => alias.strip_prefix(prefix).unwrap_or(alias),
+                None => alias,
+            };
+            writeln!(w, "/// `{alias}`, an alias of `{}`", method.name)?;
+            writeln!(
+                w,
+                "{vis} const {}: &str = \"{alias}\";",
+                alias_base.to_case(Case::ScreamingSnake),
+            )?;
+            writeln!(w)?;
+        }
     }
 
     if ctx.config.generation.result_types {
@@ -223,13 +4797,13 @@ fn gen_method(
         ident.push_str("Result");
         if let Some(ref result) = method.result {
             if let Some(ref doc) = result.documentation {
-                writeln!(w, "/// {doc}")?;
+                gen_doc(w, "", doc)?;
                 writeln!(w, "///")?;
             }
             writeln!(w, "/// Result type of `{}`.", method.name)?;
             writeln!(
                 w,
-                "pub type {} = {};",
+                "{vis} type {} = {};",
                 ident,
                 ctx.type_ref_name(&result.ty, true)
             )?;
@@ -240,22 +4814,31 @@ fn gen_method(
                 "/// Result type of `{}`. This method does not return anything.",
                 method.name
             )?;
-            writeln!(w, "pub type {} = ();", ident_base.to_case(Case::Pascal))?;
+            writeln!(w, "{vis} type {} = ();", ident_base.to_case(Case::Pascal))?;
             writeln!(w)?;
         }
     }
 
+    if ctx.config.generation.outcome_types && method.result.is_some() {
+        gen_outcome_type(w, ctx, method, ident_base)?;
+    }
+
+    if ctx.config.generation.method_errors && !method.errors.is_empty() {
+        gen_method_error_type(w, ctx, method, ident_base)?;
+    }
+
     if ctx.config.generation.param_types {
         let mut ident = ident_base.to_case(Case::Pascal);
         ident.push_str("Params");
 
         writeln!(w, "/// Parameters of the `{}` method.", method.name)?;
         writeln!(w, "#[derive(Debug, Clone)]")?;
-        writeln!(w, "pub struct {} {{", ident)?;
+        writeln!(w, "{vis} struct {} {{", ident)?;
         for param in &method.params {
             if let Some(ref doc) = param.documentation {
-                writeln!(w, "    /// {doc}")?;
+                gen_doc(w, "    ", doc)?;
             }
+            gen_param_examples(w, param)?;
             let param_ident = ctx.type_ref_name(&param.ty, param.required);
             writeln!(w, "    pub {}: {},", param.name, param_ident)?;
         }
@@ -263,7 +4846,7 @@ fn gen_method(
         writeln!(w)?;
 
         writeln!(w, "impl Serialize for {ident} {{")?;
-        writeln!(w, "        #[allow(unused_mut)]")?;
+        writeln!(w, "    #[allow(unused_mut)]")?;
         writeln!(
             w,
             "    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>"
@@ -272,23 +4855,29 @@ fn gen_method(
         writeln!(w, "        S: serde::Serializer,")?;
         writeln!(w, "    {{")?;
 
+        let values = method
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| gen_param_serialize_wrapper(w, ctx, param, i))
+            .collect::<io::Result<Vec<_>>>()?;
         if matches!(
             method.param_structure,
             ParamStructure::ByName | ParamStructure::Either
         ) {
             writeln!(w, "        let mut map = serializer.serialize_map(None)?;")?;
-            for param in &method.params {
+            for (param, value) in method.params.iter().zip(&values) {
                 writeln!(
                     w,
-                    "        map.serialize_entry(\"{}\", &self.{})?;",
-                    param.name_in_json, param.name
+                    "        map.serialize_entry(\"{}\", {value})?;",
+                    param.name_in_json,
                 )?;
             }
             writeln!(w, "        map.end()")?;
         } else {
             writeln!(w, "        let mut seq = serializer.serialize_seq(None)?;")?;
-            for param in &method.params {
-                writeln!(w, "        seq.serialize_element(&self.{})?;", param.name)?;
+            for value in &values {
+                writeln!(w, "        seq.serialize_element({value})?;")?;
             }
             writeln!(w, "        seq.end()")?;
         }
@@ -297,132 +4886,359 @@ fn gen_method(
         writeln!(w, "}}")?;
         writeln!(w)?;
 
-        writeln!(w, "impl<'de> Deserialize<'de> for {ident} {{")?;
-        writeln!(
-            w,
-            "    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>"
-        )?;
-        writeln!(w, "    where")?;
-        writeln!(w, "        D: serde::Deserializer<'de>,")?;
-        writeln!(w, "    {{")?;
+        if ctx.config.generation.params_into_values
+            && matches!(
+                method.param_structure,
+                ParamStructure::ByPosition | ParamStructure::Either
+            )
+        {
+            gen_params_into_values(w, ctx, method, &ident)?;
+        }
+
+        if ctx.config.generation.params_from_tuple
+            && matches!(
+                method.param_structure,
+                ParamStructure::ByPosition | ParamStructure::Either
+            )
+            && (1..=MAX_TUPLE_ARITY).contains(&method.params.len())
+        {
+            gen_params_from_tuple(w, ctx, method, &ident)?;
+        }
+
+        if ctx.config.generation.params_from_value {
+            gen_params_from_value(w, method, &ident)?;
+        }
+
+        if ctx.config.generation.param_macros {
+            gen_params_macro(w, ctx, method, ident_base, &ident)?;
+        }
 
-        writeln!(w, "        struct Visitor;")?;
+        // The deserialization logic can get fairly long for methods with many parameters.
+        // Generate it separately to know whether it would trip `clippy::too_many_lines`.
+        let mut de_impl = Vec::new();
+        gen_params_deserialize(&mut de_impl, ctx, method, &ident, std_mod)?;
+        if count_lines(&de_impl) > TOO_MANY_LINES_THRESHOLD {
+            writeln!(w, "#[allow(clippy::too_many_lines)]")?;
+        }
+        w.write_all(&de_impl)?;
         writeln!(w)?;
+
+        if let Some(cursor) = ctx.pagination_cursor(method) {
+            gen_method_stream(w, ctx, method, ident_base, cursor)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the functions building the parameters of linked methods from the results of the
+/// methods defining the links.
+fn gen_link_helpers(w: &mut dyn io::Write, ctx: &Ctx) -> io::Result<()> {
+    let mut generated = BTreeSet::new();
+
+    for method in &ctx.file.methods {
+        for link in &method.links {
+            let Some((target, exprs)) = ctx.link_params(method, link) else {
+                continue;
+            };
+            let words: String = link
+                .name
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+                .collect();
+            let words = match words.trim() {
+                "" => ctx.method_ident_base(method),
+                words => words,
+            };
+            let fn_name = format!("from_{}", words.to_case(Case::Snake));
+            let params = format!(
+                "{}Params",
+                ctx.method_ident_base(target).to_case(Case::Pascal)
+            );
+            // Two links may end up with the same name once converted to snake case.
+            if !generated.insert((params.clone(), fn_name.clone())) {
+                continue;
+            }
+            let result = ctx.type_ref_name(&method.result.as_ref().unwrap().ty, true);
+
+            writeln!(w, "impl {params} {{")?;
+            if let Some(ref doc) = link.documentation {
+                gen_doc(w, "    ", doc)?;
+                writeln!(w, "    ///")?;
+            }
+            writeln!(
+                w,
+                "    /// Builds the parameters of `{}` from the result of `{}`, following the `{}` link.",
+                target.name, method.name, link.name
+            )?;
+            writeln!(w, "    #[allow(unused_variables)]")?;
+            writeln!(w, "    pub fn {fn_name}(result: &{result}) -> Self {{")?;
+            writeln!(w, "        Self {{")?;
+            for (name, expr) in exprs {
+                writeln!(w, "            {name}: {expr},")?;
+            }
+            writeln!(w, "        }}")?;
+            writeln!(w, "    }}")?;
+            writeln!(w, "}}")?;
+            writeln!(w)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the `*Stream` type fetching the pages of results of the provided paginated method.
+///
+/// `cursor` is the name of the field holding the cursor of the next page in the result of the
+/// method, and of the parameter receiving it.
+fn gen_method_stream(
+    w: &mut dyn io::Write,
+    ctx: &Ctx,
+    method: &crate::parse::Method,
+    ident_base: &str,
+    (next_cursor, cursor_param): (&str, &str),
+) -> io::Result<()> {
+    let vis = ctx.vis();
+    let params = format!("{}Params", ident_base.to_case(Case::Pascal));
+    let stream = format!("{}Stream", ident_base.to_case(Case::Pascal));
+    let result = ctx.type_ref_name(&method.result.as_ref().unwrap().ty, true);
+
+    writeln!(w, "impl {params} {{")?;
+    writeln!(
+        w,
+        "    /// Returns a stream over the pages of results of `{}`, starting from these parameters.",
+        method.name
+    )?;
+    writeln!(
+        w,
+        "    pub fn into_stream<C: Client>(self, client: &C) -> {stream}<'_, C> {{"
+    )?;
+    writeln!(w, "        {stream} {{")?;
+    writeln!(w, "            client,")?;
+    writeln!(w, "            params: Some(self),")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "/// A stream over the pages of results of `{}`.",
+        method.name
+    )?;
+    writeln!(w, "{vis} struct {stream}<'a, C> {{")?;
+    writeln!(w, "    client: &'a C,")?;
+    writeln!(
+        w,
+        "    /// The parameters of the next call, or `None` once the last page has been fetched."
+    )?;
+    writeln!(w, "    params: Option<{params}>,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl<C: Client> {stream}<'_, C> {{")?;
+    writeln!(w, "    /// Fetches the next page of results.")?;
+    writeln!(w, "    ///")?;
+    writeln!(
+        w,
+        "    /// Returns `None` once the last page has been fetched. When a call fails, the same page"
+    )?;
+    writeln!(
+        w,
+        "    /// is fetched again by the next call to this function."
+    )?;
+    writeln!(
+        w,
+        "    pub async fn next_page(&mut self) -> Option<Result<{result}, C::Error>> {{"
+    )?;
+    writeln!(w, "        let mut params = self.params.clone()?;")?;
+    writeln!(
+        w,
+        "        let page = match self.client.{}(params.clone()).await {{",
+        ident_base.to_case(Case::Snake)
+    )?;
+    writeln!(w, "            Ok(page) => page,")?;
+    writeln!(w, "            Err(err) => return Some(Err(err)),")?;
+    writeln!(w, "        }};")?;
+    writeln!(
+        w,
+        "        params.{cursor_param} = page.{next_cursor}.clone();"
+    )?;
+    writeln!(
+        w,
+        "        self.params = params.{cursor_param}.is_some().then_some(params);"
+    )?;
+    writeln!(w, "        Some(Ok(page))")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Writes the [`Deserialize`](serde::Deserialize) implementation of the parameters of the
+/// provided method.
+fn gen_params_deserialize(
+    w: &mut dyn io::Write,
+    ctx: &mut Ctx,
+    method: &crate::parse::Method,
+    ident: &str,
+    std_mod: &str,
+) -> io::Result<()> {
+    writeln!(w, "impl<'de> Deserialize<'de> for {ident} {{")?;
+    writeln!(
+        w,
+        "    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>"
+    )?;
+    writeln!(w, "    where")?;
+    writeln!(w, "        D: serde::Deserializer<'de>,")?;
+    writeln!(w, "    {{")?;
+
+    writeln!(w, "        struct Visitor;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "        impl<'de> serde::de::Visitor<'de> for Visitor {{"
+    )?;
+    writeln!(w, "            type Value = {ident};",)?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "            fn expecting(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
+    )?;
+    writeln!(
+        w,
+        "                write!(f, \"the parameters for `{}`\")",
+        method.name
+    )?;
+    writeln!(w, "            }}")?;
+    writeln!(w)?;
+
+    if matches!(
+        method.param_structure,
+        ParamStructure::ByPosition | ParamStructure::Either
+    ) {
+        writeln!(w, "            #[allow(unused_mut)]")?;
         writeln!(
             w,
-            "        impl<'de> serde::de::Visitor<'de> for Visitor {{"
+            "            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>"
         )?;
-        writeln!(w, "            type Value = {ident};",)?;
+        writeln!(w, "            where")?;
+        writeln!(w, "                A: serde::de::SeqAccess<'de>,")?;
+        writeln!(w, "            {{")?;
+        for (i, param) in method.params.iter().enumerate() {
+            let ty = ctx.type_ref_name(&param.ty, param.required);
+            let attrs = param.ty.attributes(ctx.config, ctx.file, param.required);
+            let element = if attrs.is_empty() {
+                "seq.next_element()?".to_owned()
+            } else {
+                // The serde attributes of the parameter are applied through a wrapper.
+                writeln!(w, "                #[derive(Deserialize)]")?;
+                writeln!(w, "                #[serde(transparent)]")?;
+                writeln!(
+                    w,
+                    "                struct Param{i}({} {ty});",
+                    attrs.join(" ")
+                )?;
+                format!("seq.next_element::<Param{i}>()?.map(|param| param.0)")
+            };
+            writeln!(
+                w,
+                "                let {}: {ty} = {element}.ok_or_else(|| serde::de::Error::invalid_length({}, &\"expected {} parameters\"))?;",
+                param.name, i + 1, method.params.len(),
+            )?;
+        }
         writeln!(w)?;
         writeln!(
             w,
-            "            fn expecting(&self, f: &mut {std_mod}::fmt::Formatter) -> {std_mod}::fmt::Result {{"
-        )?;
-        writeln!(
-            w,
-            "                write!(f, \"the parameters for `{}`\")",
-            method.name
+            "                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {{"
         )?;
+        writeln!(w, "                    return Err(serde::de::Error::invalid_length({}, &\"expected {} parameters\"));", method.params.len() + 1, method.params.len())?;
+        writeln!(w, "                }}")?;
+        writeln!(w)?;
+        writeln!(w, "                Ok({ident} {{")?;
+        for param in &method.params {
+            writeln!(w, "                    {},", param.name)?;
+        }
+        writeln!(w, "                }})")?;
         writeln!(w, "            }}")?;
         writeln!(w)?;
+    }
 
-        if matches!(
-            method.param_structure,
-            ParamStructure::ByPosition | ParamStructure::Either
-        ) {
-            writeln!(w, "            #[allow(unused_mut)]")?;
-            writeln!(
-                w,
-                "            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>"
-            )?;
-            writeln!(w, "            where")?;
-            writeln!(w, "                A: serde::de::SeqAccess<'de>,")?;
-            writeln!(w, "            {{")?;
-            for (i, param) in method.params.iter().enumerate() {
+    if matches!(
+        method.param_structure,
+        ParamStructure::ByName | ParamStructure::Either
+    ) {
+        writeln!(w, "            #[allow(unused_variables)]")?;
+        writeln!(
+            w,
+            "            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>"
+        )?;
+        writeln!(w, "            where")?;
+        writeln!(w, "                A: serde::de::MapAccess<'de>,")?;
+        writeln!(w, "            {{")?;
+        writeln!(w, "                #[derive(Deserialize)]")?;
+        writeln!(w, "                struct Helper {{")?;
+        for param in &method.params {
+            if !param.required {
+                writeln!(w, "                    #[serde(default)]")?;
+            }
+            if param.name != param.name_in_json {
                 writeln!(
                     w,
-                    "                let {}: {} = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length({}, &\"expected {} parameters\"))?;",
-                    param.name, ctx.type_ref_name(&param.ty, param.required), i + 1, method.params.len(),
+                    "                    #[serde(rename = \"{}\")]",
+                    param.name_in_json
                 )?;
             }
-            writeln!(w)?;
+            for attr in param.ty.attributes(ctx.config, ctx.file, param.required) {
+                writeln!(w, "                    {attr}")?;
+            }
             writeln!(
                 w,
-                "                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {{"
+                "                    {}: {},",
+                param.name,
+                ctx.type_ref_name(&param.ty, param.required)
             )?;
-            writeln!(w, "                    return Err(serde::de::Error::invalid_length({}, &\"expected {} parameters\"));", method.params.len() + 1, method.params.len())?;
-            writeln!(w, "                }}")?;
-            writeln!(w)?;
-            writeln!(w, "                Ok({ident} {{")?;
-            for param in &method.params {
-                writeln!(w, "                    {},", param.name)?;
-            }
-            writeln!(w, "                }})")?;
-            writeln!(w, "            }}")?;
-            writeln!(w)?;
         }
-
-        if matches!(
-            method.param_structure,
-            ParamStructure::ByName | ParamStructure::Either
-        ) {
-            writeln!(w, "            #[allow(unused_variables)]")?;
+        writeln!(w, "                }}")?;
+        writeln!(w)?;
+        writeln!(w, "                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;")?;
+        writeln!(w)?;
+        writeln!(w, "                Ok({ident} {{")?;
+        for param in &method.params {
             writeln!(
                 w,
-                "            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>"
+                "                    {}: helper.{},",
+                param.name, param.name
             )?;
-            writeln!(w, "            where")?;
-            writeln!(w, "                A: serde::de::MapAccess<'de>,")?;
-            writeln!(w, "            {{")?;
-            writeln!(w, "                #[derive(Deserialize)]")?;
-            writeln!(w, "                struct Helper {{")?;
-            for param in &method.params {
-                if !param.required {
-                    writeln!(w, "                        #[serde(default)]")?;
-                }
-                writeln!(
-                    w,
-                    "                    {}: {},",
-                    param.name,
-                    ctx.type_ref_name(&param.ty, param.required)
-                )?;
-            }
-            writeln!(w, "                }}")?;
-            writeln!(w)?;
-            writeln!(w, "                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;")?;
-            writeln!(w)?;
-            writeln!(w, "                Ok({ident} {{")?;
-            for param in &method.params {
-                writeln!(
-                    w,
-                    "                    {}: helper.{},",
-                    param.name, param.name
-                )?;
-            }
-            writeln!(w, "                }})")?;
-            writeln!(w, "            }}")?;
-            writeln!(w)?;
         }
-
-        writeln!(w, "        }}")?;
+        writeln!(w, "                }})")?;
+        writeln!(w, "            }}")?;
         writeln!(w)?;
+    }
 
-        match method.param_structure {
-            ParamStructure::ByName => {
-                writeln!(w, "        deserializer.deserialize_map(Visitor)")?;
-            }
-            ParamStructure::ByPosition => {
-                writeln!(w, "        deserializer.deserialize_seq(Visitor)")?;
-            }
-            ParamStructure::Either => {
-                writeln!(w, "        deserializer.deserialize_any(Visitor)")?;
-            }
-        }
+    writeln!(w, "        }}")?;
+    writeln!(w)?;
 
-        writeln!(w, "    }}")?;
-        writeln!(w, "}}")?;
-        writeln!(w)?;
+    match method.param_structure {
+        ParamStructure::ByName => {
+            writeln!(w, "        deserializer.deserialize_map(Visitor)")?;
+        }
+        ParamStructure::ByPosition => {
+            writeln!(w, "        deserializer.deserialize_seq(Visitor)")?;
+        }
+        ParamStructure::Either => {
+            let method = match ctx.config.generation.either_params {
+                crate::config::EitherParams::Any => "deserialize_any",
+                crate::config::EitherParams::ByPosition => "deserialize_seq",
+                crate::config::EitherParams::ByName => "deserialize_map",
+            };
+            writeln!(w, "        deserializer.{method}(Visitor)")?;
+        }
     }
 
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+
     Ok(())
 }
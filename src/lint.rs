@@ -0,0 +1,171 @@
+//! Checks the final model for patterns that are known to misbehave once generated.
+//!
+//! Unlike fixes, lints never modify the model. They only produce warnings that help the user
+//! spot issues before they cause errors at runtime.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::parse::{EnumDef, EnumTag, File, Path, StructDef, StructField, TypeKind, TypeRef};
+
+/// The maximum number of nested types visited when looking for the keys of a flattened type.
+const MAX_DEPTH: usize = 32;
+
+/// Returns a list of warnings about the provided [`File`].
+pub fn lint(file: &File) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    for ty in file.types.values() {
+        lint_constraints(&ty.path, &ty.constraints, &mut warnings);
+        if let TypeKind::Enum(e) = &ty.kind {
+            lint_enum_default(&ty.path, e, &mut warnings);
+        }
+        if let TypeKind::Struct(s) = &ty.kind {
+            for field in s.fields.values() {
+                lint_constraints(&field.path, &field.constraints, &mut warnings);
+                lint_array_default(file, field, &mut warnings);
+            }
+            lint_flatten(file, s, &mut warnings);
+        }
+    }
+
+    warnings
+}
+
+/// Reports the schema constraints that are ignored by the generated types.
+fn lint_constraints(path: &Path, constraints: &[String], warnings: &mut Vec<String>) {
+    for constraint in constraints {
+        warnings.push(format!(
+            "\
+            schema constraint can't be represented and will not be enforced\n\
+            - path = {path}\n\
+            - constraint = {constraint}\n\
+            "
+        ));
+    }
+}
+
+/// Checks that the default value of the provided enum matches one of its variants.
+fn lint_enum_default(path: &Path, e: &EnumDef, warnings: &mut Vec<String>) {
+    let Some(default) = &e.default else {
+        return;
+    };
+    if !e
+        .variants
+        .values()
+        .any(|v| v.name_in_json.as_ref() == Some(default))
+    {
+        warnings.push(format!(
+            "\
+            default value does not match any variant and will be ignored\n\
+            - path = {path}\n\
+            - default = {default}\n\
+            "
+        ));
+    }
+}
+
+/// Checks that the items of the default value of the provided array field can be written as
+/// literals.
+fn lint_array_default(file: &File, field: &StructField, warnings: &mut Vec<String>) {
+    let Some(Value::Array(items)) = &field.default else {
+        return;
+    };
+    if field.required || field.flatten {
+        return;
+    }
+    let TypeRef::Array(inner, _) = file.unalias(&field.ty) else {
+        return;
+    };
+    let inner = file.unalias(inner);
+    if let Some(item) = items.iter().find(|item| inner.literal(item).is_none()) {
+        warnings.push(format!(
+            "\
+            default value can't be generated and the field will default to an empty array\n\
+            - path = {}\n\
+            - item = {item}\n\
+            ",
+            field.path
+        ));
+    }
+}
+
+/// Checks that the flattened fields of the provided struct can be (de)serialized by `serde`.
+///
+/// Flattened fields must be objects, and their keys must not overlap with the other fields of
+/// the struct. When they do, only the first field receives the value during deserialization.
+fn lint_flatten(file: &File, s: &StructDef, warnings: &mut Vec<String>) {
+    let mut claimed: BTreeMap<&str, &Path> = s
+        .fields
+        .values()
+        .filter(|f| !f.flatten)
+        .map(|f| (f.name_in_json.as_str(), &f.path))
+        .collect();
+
+    for field in s.fields.values().filter(|f| f.flatten) {
+        let Some(keys) = object_keys(file, &field.ty, 0) else {
+            warnings.push(format!(
+                "\
+                flattened field is not an object and will fail to (de)serialize\n\
+                - path = {}\n\
+                ",
+                field.path
+            ));
+            continue;
+        };
+
+        for key in keys {
+            if let Some(other) = claimed.insert(key, &field.path) {
+                warnings.push(format!(
+                    "\
+                    flattened field shares the key `{key}` with another field, which will \
+                    prevent one of them from being deserialized\n\
+                    - path = {}\n\
+                    - other = {other}\n\
+                    ",
+                    field.path
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the keys of the JSON object represented by the provided type, or `None` if the type
+/// is not always an object.
+///
+/// The keys of enums are not known in advance, meaning that an empty list is returned for them.
+fn object_keys<'a>(file: &'a File, ty: &TypeRef, depth: usize) -> Option<Vec<&'a str>> {
+    let TypeRef::Ref(path) = ty else {
+        return None;
+    };
+    if depth > MAX_DEPTH {
+        return Some(Vec::new());
+    }
+
+    match &file.types.get(path)?.kind {
+        TypeKind::Struct(s) => {
+            let mut keys = Vec::new();
+            for field in s.fields.values() {
+                if field.flatten {
+                    keys.extend(object_keys(file, &field.ty, depth + 1).unwrap_or_default());
+                } else {
+                    keys.push(field.name_in_json.as_str());
+                }
+            }
+            Some(keys)
+        }
+        TypeKind::Enum(e) => {
+            let is_object = e.variants.values().all(|v| match (&e.tag, &v.ty) {
+                (EnumTag::Tagged(_), None) | (EnumTag::Normal, Some(_)) => true,
+                (EnumTag::Tagged(_) | EnumTag::Untagged, Some(ty)) => {
+                    object_keys(file, ty, depth + 1).is_some()
+                }
+                (EnumTag::Normal | EnumTag::Untagged, None) => false,
+            });
+            is_object.then(Vec::new)
+        }
+        TypeKind::Alias(a) => object_keys(file, &a.ty, depth + 1),
+        TypeKind::Tuple(_) => None,
+    }
+}
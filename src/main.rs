@@ -1,69 +1,192 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 mod command_line;
 mod config;
 mod fix;
 mod gen;
+mod lint;
 mod parse;
+mod ts;
 
 fn main() -> ExitCode {
     let cmd = command_line::from_env();
-    let config = match config::load(&cmd.config) {
-        Ok(config) => config,
+    let jobs = match cmd.jobs() {
+        Ok(jobs) => jobs,
         Err(err) => {
-            let _ = print_error(format_args!("`{}`: {}", cmd.config.display(), err));
+            let _ = print_error(format_args!("{}", err));
             return ExitCode::FAILURE;
         }
     };
-    let document = match load_document(&cmd.document) {
-        Ok(document) => document,
-        Err(err) => {
-            let _ = print_error(format_args!("`{}`: {}", cmd.document.display(), err));
-            return ExitCode::FAILURE;
+    if cmd.cargo_emit {
+        if let Some(batch) = &cmd.batch {
+            println!("cargo:rerun-if-changed={}", batch.display());
         }
-    };
-    let mut document = match parse::parse(&document) {
-        Ok(document) => document,
-        Err(errs) => {
-            for err in errs {
-                let _ = print_error(format_args!("`{}`: {}", err.path, err.message));
+        let mut inputs = jobs
+            .iter()
+            .flat_map(|job| [job.config.clone(), job.document.clone()])
+            .collect::<BTreeSet<_>>();
+        let documents = jobs
+            .iter()
+            .map(|job| (&job.document, job.document_pointer.as_deref()))
+            .collect::<BTreeSet<_>>();
+        for (document, pointer) in documents {
+            inputs.extend(external_ref_files(document, pointer));
+        }
+        for path in inputs {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+    }
+
+    // Jobs keep running after one of them fails, so that all errors are reported at once.
+    let mut documents = BTreeMap::new();
+    let mut status = ExitCode::SUCCESS;
+    for job in &jobs {
+        if !run_job(&cmd, job, &mut documents) {
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
+
+/// A document that has already been loaded and parsed, shared by the jobs using it.
+struct ParsedDocument {
+    /// The parsed document, before any fix is applied.
+    file: parse::File,
+    /// The hash of the file containing the document.
+    hash: u64,
+    /// The number of warnings emitted while loading the document.
+    warnings: usize,
+}
+
+/// Loads and parses the document of the provided job, unless it was already parsed for a
+/// previous job.
+///
+/// Errors and warnings are printed as they are found, and `None` is returned on error.
+fn parse_document<'a>(
+    cmd: &command_line::CommandLineArgs,
+    job: &command_line::Job,
+    documents: &'a mut BTreeMap<(PathBuf, Option<String>), ParsedDocument>,
+) -> Option<&'a ParsedDocument> {
+    let key = (job.document.clone(), job.document_pointer.clone());
+    if !documents.contains_key(&key) {
+        let (document, raw_document, hash, warnings) =
+            match load_document(&job.document, job.document_pointer.as_deref()) {
+                Ok(document) => document,
+                Err(err) => {
+                    let _ = print_error(format_args!("`{}`: {}", job.document.display(), err));
+                    return None;
+                }
+            };
+        let warning_count = warnings.len();
+        for warning in warnings {
+            let _ = print_warning(format_args!("`{}`: {}", job.document.display(), warning));
+        }
+        let file = match parse::parse(&document, &raw_document) {
+            Ok(file) => file,
+            Err(errs) => {
+                if cmd.error_json {
+                    print_errors_json(&errs);
+                }
+                for err in errs {
+                    let _ = print_error(format_args!(
+                        "`{}`: {}\n- pointer = #{}",
+                        err.path, err.kind, err.pointer
+                    ));
+                }
+                return None;
             }
-            return ExitCode::FAILURE;
+        };
+        documents.insert(
+            key.clone(),
+            ParsedDocument {
+                file,
+                hash,
+                warnings: warning_count,
+            },
+        );
+    }
+    documents.get(&key)
+}
+
+/// Runs the provided job, returning whether it succeeded.
+fn run_job(
+    cmd: &command_line::CommandLineArgs,
+    job: &command_line::Job,
+    documents: &mut BTreeMap<(PathBuf, Option<String>), ParsedDocument>,
+) -> bool {
+    let config = match config::load(&job.config) {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = print_error(format_args!("`{}`: {}", job.config.display(), err));
+            return false;
         }
     };
+    let Some(parsed) = parse_document(cmd, job, documents) else {
+        return false;
+    };
+    let mut document = parsed.file.clone();
+    let document_hash = parsed.hash;
+    let mut warning_count = parsed.warnings;
     match fix::fix(&mut document, &config) {
         Ok(_) => {}
         Err(errs) => {
             for err in errs {
                 let _ = print_error(format_args!("{}", err));
             }
-            return ExitCode::FAILURE;
+            return false;
+        }
+    }
+    if !job.only.is_empty() {
+        for warning in fix::retain_only(&mut document, &job.only) {
+            warning_count += 1;
+            let _ = print_warning(format_args!("{}", warning));
+        }
+    }
+    for warning in lint::lint(&document) {
+        warning_count += 1;
+        let _ = print_warning(format_args!("{}", warning));
+    }
+    if cmd.dry_run {
+        if let Err(err) = gen_output(&mut std::io::sink(), &document, &config, document_hash) {
+            let _ = print_error(format_args!("{}", err));
+            return false;
         }
+        print_counts(&document, warning_count);
+        return true;
     }
-    let mut output = match std::fs::File::create(&cmd.output) {
+    // The output path is required when not doing a dry run.
+    let output_path = job.output.as_deref().unwrap();
+    let mut output = match std::fs::File::create(output_path) {
         Ok(output) => std::io::BufWriter::new(output),
         Err(err) => {
-            let _ = print_error(format_args!("`{}`: {}", cmd.output.display(), err));
-            return ExitCode::FAILURE;
+            let _ = print_error(format_args!("`{}`: {}", output_path.display(), err));
+            return false;
         }
     };
-    match gen::gen(&mut output, &document, &config) {
+    match gen_output(&mut output, &document, &config, document_hash) {
         Ok(_) => {}
         Err(err) => {
             let _ = print_error(format_args!("{}", err));
-            return ExitCode::FAILURE;
+            return false;
         }
     }
     drop(output);
+    if let Some(path) = &job.typescript {
+        if let Err(err) = gen_typescript(path, &document) {
+            let _ = print_error(format_args!("`{}`: {}", path.display(), err));
+            return false;
+        }
+    }
     if config.run_rustfmt {
-        if let Err(err) = run_rustmft(&cmd.output) {
+        if let Err(err) = run_rustmft(output_path) {
             let _ = print_error(format_args!("{}", err));
-            return ExitCode::FAILURE;
+            return false;
         }
     }
-    ExitCode::SUCCESS
+    true
 }
 
 /// Print an error message to the standard error stream.
@@ -79,12 +202,119 @@ fn print_error(args: std::fmt::Arguments) -> std::io::Result<()> {
     Ok(())
 }
 
+/// Print a warning message to the standard error stream.
+fn print_warning(args: std::fmt::Arguments) -> std::io::Result<()> {
+    let stderr = std::io::stderr();
+    let mut stderr = stderr.lock();
+
+    stderr.write_all(b"\x1B[33mwarning\x1B[0m: ")?;
+    stderr.write_fmt(args)?;
+    stderr.write_all(b"\n")?;
+    stderr.flush()?;
+
+    Ok(())
+}
+
+/// Prints the number of types, aliases and methods of the provided file to the standard output,
+/// along with the number of warnings emitted while processing it.
+fn print_counts(document: &parse::File, warnings: usize) {
+    let aliases = document
+        .types
+        .values()
+        .filter(|ty| matches!(ty.kind, parse::TypeKind::Alias(_)))
+        .count();
+    println!(
+        "{} types ({aliases} aliases), {} methods, {warnings} warnings",
+        document.types.len(),
+        document.methods.len(),
+    );
+}
+
+/// Prints the provided parsing errors to the standard output as a JSON array.
+fn print_errors_json(errs: &[parse::ParsingError]) {
+    let errs: Vec<_> = errs
+        .iter()
+        .map(|err| {
+            serde_json::json!({
+                "path": &*err.path,
+                "pointer": err.pointer,
+                "kind": err.kind.code(),
+                "message": err.kind.to_string(),
+            })
+        })
+        .collect();
+    println!("{}", serde_json::Value::Array(errs));
+}
+
+/// Writes the generated Rust code, followed by its footer when `generation.footer` is enabled.
+fn gen_output(
+    w: &mut dyn Write,
+    document: &parse::File,
+    config: &config::Config,
+    document_hash: u64,
+) -> std::io::Result<()> {
+    gen::gen(w, document, config)?;
+    if config.generation.footer {
+        gen::gen_footer(w, document_hash, config.generation.footer_timestamp)?;
+    }
+    Ok(())
+}
+
+/// Returns the files targeted by the external references of the document at the provided path,
+/// relative to the current directory.
+///
+/// Like [`load_document`], only the value at `pointer` is considered when one is provided.
+/// Nothing is returned when the document can't be loaded, as the job using it reports the error.
+fn external_ref_files(document: &Path, pointer: Option<&str>) -> Vec<PathBuf> {
+    let Ok(bytes) = std::fs::read(document) else {
+        return Vec::new();
+    };
+    let Ok(mut raw) = serde_json::from_slice::<serde_json::Value>(&bytes) else {
+        return Vec::new();
+    };
+    if let Some(pointer) = pointer {
+        let Some(value) = raw.pointer_mut(pointer) else {
+            return Vec::new();
+        };
+        raw = value.take();
+    }
+    let dir = document.parent().unwrap_or(Path::new(""));
+    parse::external_ref_files(&raw)
+        .into_iter()
+        .map(|file| dir.join(file))
+        .collect()
+}
+
 /// Loads the document from the provided path.
-fn load_document(path: &Path) -> Result<open_rpc::OpenRpc, String> {
-    let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
-    let buf = std::io::BufReader::new(file);
-    let document = serde_json::from_reader(buf).map_err(|e| e.to_string())?;
-    Ok(document)
+///
+/// When `pointer` is provided, the document is the value it points to within the file.
+///
+/// The raw JSON representation of the document is returned alongside it, as well as a hash of
+/// the whole file and the warnings emitted while approximating its dynamic references.
+fn load_document(
+    path: &Path,
+    pointer: Option<&str>,
+) -> Result<(open_rpc::OpenRpc, serde_json::Value, u64, Vec<String>), String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    let mut hash = fix::Fnv::new();
+    hash.write(&bytes);
+    let mut raw: serde_json::Value = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+    if let Some(pointer) = pointer {
+        raw = raw
+            .pointer_mut(pointer)
+            .map(serde_json::Value::take)
+            .ok_or_else(|| format!("no value at `{pointer}`"))?;
+    }
+    let warnings = parse::approximate_dynamic_refs(&mut raw)?;
+    let document = serde::Deserialize::deserialize(&raw).map_err(|e| e.to_string())?;
+    Ok((document, raw, hash.finish(), warnings))
+}
+
+/// Generates the TypeScript declaration file at the provided path.
+fn gen_typescript(path: &Path, document: &parse::File) -> std::io::Result<()> {
+    let mut output = std::io::BufWriter::new(std::fs::File::create(path)?);
+    ts::gen(&mut output, document)?;
+    output.flush()
 }
 
 /// Runs `rustfmt` on the provided path.
@@ -0,0 +1,219 @@
+//! Approximates dynamic references (`$dynamicRef` and `$recursiveRef`) with plain references.
+//!
+//! Dynamic references are resolved against the *dynamic* scope of the schema being validated,
+//! which can't be known when generating types. Instead, they are resolved to the lexically
+//! nearest matching anchor, which is what they point to in the vast majority of documents.
+
+use serde_json::{Map, Value};
+
+/// An object that may be the target of a dynamic reference.
+struct Scope {
+    /// The JSON pointer of the object.
+    pointer: String,
+    /// Whether the object is marked with `"$recursiveAnchor": true`.
+    recursive_anchor: bool,
+    /// The value of the `$dynamicAnchor` keyword of the object, if any.
+    dynamic_anchor: Option<String>,
+    /// Whether the object is a schema defined in `components/schemas`.
+    component: bool,
+}
+
+/// A dynamic reference that has been resolved to a plain one.
+struct Resolved {
+    /// The JSON pointer of the object containing the dynamic reference.
+    pointer: String,
+    /// The keyword used by the dynamic reference.
+    keyword: &'static str,
+    /// The value of the `$ref` keyword replacing the dynamic reference.
+    target: String,
+}
+
+/// The keywords introducing dynamic references.
+const DYNAMIC_KEYWORDS: [&str; 2] = ["$dynamicRef", "$recursiveRef"];
+
+/// Replaces the dynamic references of the provided document with plain `$ref`s.
+///
+/// On success, a warning is returned for each reference that has been replaced.
+pub fn approximate_dynamic_refs(raw: &mut Value) -> Result<Vec<String>, String> {
+    let mut anchors = Vec::new();
+    collect_anchors(raw, &mut String::new(), &mut anchors);
+
+    let mut resolved = Vec::new();
+    resolve(
+        raw,
+        &mut String::new(),
+        &mut Vec::new(),
+        &anchors,
+        &mut resolved,
+    )?;
+
+    let mut warnings = Vec::new();
+    for r in resolved {
+        let Some(Value::Object(object)) = raw.pointer_mut(&r.pointer) else {
+            unreachable!("resolved a dynamic reference outside of an object");
+        };
+        object.remove(r.keyword);
+        object.insert("$ref".into(), Value::String(r.target.clone()));
+        warnings.push(format!(
+            "\
+            `{}` approximated by a plain reference to the nearest anchor\n\
+            - pointer = #{}\n\
+            - target = {}\n\
+            ",
+            r.keyword, r.pointer, r.target,
+        ));
+    }
+
+    Ok(warnings)
+}
+
+/// Collects the `$dynamicAnchor`s of the document, along with the pointer of the object
+/// defining them.
+fn collect_anchors(value: &Value, pointer: &mut String, anchors: &mut Vec<(String, String)>) {
+    for_each_child(value, pointer, |child, pointer| {
+        if let Some(anchor) = child.get("$dynamicAnchor").and_then(Value::as_str) {
+            anchors.push((anchor.to_owned(), pointer.clone()));
+        }
+        collect_anchors(child, pointer, anchors);
+    });
+}
+
+/// Resolves the dynamic references found in the provided value.
+///
+/// `scopes` contains the objects enclosing `value` that may be targeted by dynamic references.
+fn resolve(
+    value: &Value,
+    pointer: &mut String,
+    scopes: &mut Vec<Scope>,
+    anchors: &[(String, String)],
+    resolved: &mut Vec<Resolved>,
+) -> Result<(), String> {
+    let mut result = Ok(());
+
+    for_each_child(value, pointer, |child, pointer| {
+        if result.is_err() {
+            return;
+        }
+
+        let pushed = match child {
+            Value::Object(object) => {
+                let scope = scope_of(object, pointer);
+                let pushed = scope.is_some();
+                scopes.extend(scope);
+                if let Err(err) = resolve_object(object, pointer, scopes, anchors, resolved) {
+                    result = Err(err);
+                    return;
+                }
+                pushed
+            }
+            _ => false,
+        };
+
+        result = resolve(child, pointer, scopes, anchors, resolved);
+
+        if pushed {
+            scopes.pop();
+        }
+    });
+
+    result
+}
+
+/// Returns the [`Scope`] defined by the provided object, if it may be the target of a dynamic
+/// reference.
+fn scope_of(object: &Map<String, Value>, pointer: &str) -> Option<Scope> {
+    let recursive_anchor = object.get("$recursiveAnchor") == Some(&Value::Bool(true));
+    let dynamic_anchor = object
+        .get("$dynamicAnchor")
+        .and_then(Value::as_str)
+        .map(str::to_owned);
+    let component = pointer
+        .strip_prefix("/components/schemas/")
+        .is_some_and(|name| !name.contains('/'));
+
+    (recursive_anchor || dynamic_anchor.is_some() || component).then(|| Scope {
+        pointer: pointer.to_owned(),
+        recursive_anchor,
+        dynamic_anchor,
+        component,
+    })
+}
+
+/// Resolves the dynamic reference of the provided object, if it has one.
+fn resolve_object(
+    object: &Map<String, Value>,
+    pointer: &str,
+    scopes: &[Scope],
+    anchors: &[(String, String)],
+    resolved: &mut Vec<Resolved>,
+) -> Result<(), String> {
+    for keyword in DYNAMIC_KEYWORDS {
+        let Some(reference) = object.get(keyword) else {
+            continue;
+        };
+        let reference = reference
+            .as_str()
+            .ok_or_else(|| format!("`{keyword}` must be a string\n- pointer = #{pointer}\n"))?;
+
+        // Both keywords behave like `$ref` when they don't target an anchor.
+        let target = match reference.strip_prefix('#') {
+            Some("") if keyword == "$recursiveRef" => scopes
+                .iter()
+                .rev()
+                .find(|s| s.recursive_anchor)
+                .or_else(|| scopes.iter().rev().find(|s| s.component))
+                .map(|s| format!("#{}", s.pointer)),
+            Some(anchor) if !anchor.starts_with('/') && keyword == "$dynamicRef" => scopes
+                .iter()
+                .rev()
+                .find(|s| s.dynamic_anchor.as_deref() == Some(anchor))
+                .map(|s| &s.pointer)
+                .or_else(|| anchors.iter().find(|(a, _)| a == anchor).map(|(_, p)| p))
+                .map(|pointer| format!("#{pointer}")),
+            _ => Some(reference.to_owned()),
+        };
+
+        let target = target.ok_or_else(|| {
+            format!(
+                "\
+                can't resolve `{keyword}`: no matching anchor\n\
+                - pointer = #{pointer}\n\
+                - reference = {reference}\n\
+                "
+            )
+        })?;
+
+        resolved.push(Resolved {
+            pointer: pointer.to_owned(),
+            keyword,
+            target,
+        });
+    }
+
+    Ok(())
+}
+
+/// Calls the provided function for each child of the provided value, with `pointer` updated to
+/// point to that child.
+fn for_each_child(value: &Value, pointer: &mut String, mut f: impl FnMut(&Value, &mut String)) {
+    let len = pointer.len();
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object {
+                pointer.push('/');
+                pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                f(child, pointer);
+                pointer.truncate(len);
+            }
+        }
+        Value::Array(array) => {
+            for (i, child) in array.iter().enumerate() {
+                pointer.push('/');
+                pointer.push_str(&i.to_string());
+                f(child, pointer);
+                pointer.truncate(len);
+            }
+        }
+        _ => (),
+    }
+}
@@ -0,0 +1,34 @@
+//! Lists the files targeted by the external references (`$ref`s to other files) of a document.
+//!
+//! External references are not resolved by the parser, but the files they target are still
+//! inputs of the generation, which build scripts should track.
+
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+/// Returns the files targeted by the `$ref`s of the provided document, as written in it.
+///
+/// References to URLs are ignored, as they are not files that can be tracked.
+pub fn external_ref_files(raw: &Value) -> BTreeSet<String> {
+    let mut files = BTreeSet::new();
+    collect(raw, &mut files);
+    files
+}
+
+/// Collects the files targeted by the `$ref`s of the provided value and its children.
+fn collect(value: &Value, files: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get("$ref") {
+                let file = reference.split('#').next().unwrap_or_default();
+                if !file.is_empty() && !file.contains("://") {
+                    files.insert(file.to_owned());
+                }
+            }
+            object.values().for_each(|child| collect(child, files));
+        }
+        Value::Array(array) => array.iter().for_each(|child| collect(child, files)),
+        _ => (),
+    }
+}
@@ -6,8 +6,9 @@ use convert_case::{Case, Casing};
 use open_rpc as rpc;
 
 use super::{
-    AliasDef, EnumDef, EnumTag, EnumVariant, File, Method, MethodParameter, MethodResult,
-    ParsingError, Path, StructDef, StructField, TypeDef, TypeKind, TypeRef, TypeSource,
+    AliasDef, Bound, EnumDef, EnumTag, EnumVariant, ErrorDef, File, Link, LinkParam, Method,
+    MethodParameter, MethodResult, ParsingError, ParsingErrorKind, Path, StructDef, StructField,
+    TupleDef, TypeDef, TypeKind, TypeRef, TypeSource, ANONYMOUS_TYPE_NAME,
 };
 
 /// Some context required when parsing.
@@ -21,28 +22,43 @@ struct Ctx<'a> {
     /// `components/schemas` section, meaning that they do not have a canonical name.
     pub anonymous_types: BTreeMap<Path, TypeDef>,
 
+    /// The errors that methods may return, deduplicated by code.
+    pub error_defs: BTreeMap<Path, ErrorDef>,
+
+    /// The JSON pointer of the value currently being parsed within the raw document.
+    ///
+    /// Unlike `path`, this follows the actual structure of the JSON document, which makes it
+    /// possible to look up keywords that are not part of the [`rpc`] data model.
+    pub pointer: String,
+
     /// The document that is being parsed.
     pub doc: &'a rpc::OpenRpc,
 
+    /// The raw JSON representation of the document that is being parsed.
+    pub raw: &'a serde_json::Value,
+
     /// A list of errors that have been encountered during parsing.
     pub errors: Vec<ParsingError>,
 }
 
 impl<'a> Ctx<'a> {
     /// Creates a new [`Ctx`] instance.
-    pub fn new(doc: &'a rpc::OpenRpc) -> Self {
+    pub fn new(doc: &'a rpc::OpenRpc, raw: &'a serde_json::Value) -> Self {
         Self {
             path: String::from("#"),
             anonymous_types: BTreeMap::new(),
+            error_defs: BTreeMap::new(),
+            pointer: String::new(),
             doc,
+            raw,
             errors: Vec::new(),
         }
     }
 
-    /// Pushes a new path to the context.
+    /// Pushes a new path to the context, escaping it like a JSON pointer segment if needed.
     pub fn push_path(&mut self, path: &str) {
         self.path.push('/');
-        self.path.push_str(path);
+        self.path.push_str(&escape_segment(path));
     }
 
     /// Pops the last component of the current path.
@@ -51,6 +67,27 @@ impl<'a> Ctx<'a> {
         self.path.truncate(index);
     }
 
+    /// Pushes a new segment to the current JSON pointer, escaping it if needed.
+    pub fn push_pointer(&mut self, segment: &str) {
+        self.pointer.push('/');
+        self.pointer.push_str(&escape_segment(segment));
+    }
+
+    /// Pops the last segment of the current JSON pointer.
+    pub fn pop_pointer(&mut self) {
+        let index = self
+            .pointer
+            .rfind('/')
+            .expect("tried to pop the root pointer");
+        self.pointer.truncate(index);
+    }
+
+    /// Returns the keyword with the provided name in the raw JSON object currently being
+    /// parsed, if any.
+    pub fn raw_keyword(&self, name: &str) -> Option<&'a serde_json::Value> {
+        self.raw.pointer(&self.pointer)?.get(name)
+    }
+
     /// Returns the current path.
     #[inline]
     pub fn current_path(&self) -> Path {
@@ -66,54 +103,69 @@ impl<'a> Ctx<'a> {
     }
 
     /// Adds a new error to the context.
-    pub fn add_error(&mut self, message: impl Into<String>) {
+    pub fn add_error(&mut self, kind: ParsingErrorKind) {
         self.errors.push(ParsingError {
             path: self.current_path(),
-            message: message.into(),
+            pointer: self.pointer.clone(),
+            kind,
         });
     }
 }
 
 /// Parses a file from an OpenRPC document.
-pub fn parse(doc: &rpc::OpenRpc) -> Result<File, Vec<ParsingError>> {
+///
+/// `raw` must be the JSON representation from which `doc` has been deserialized. It is used to
+/// look up the keywords that are not part of the [`rpc`] data model.
+pub fn parse(doc: &rpc::OpenRpc, raw: &serde_json::Value) -> Result<File, Vec<ParsingError>> {
     let mut methods = Vec::new();
     let mut types = BTreeMap::new();
 
-    let mut ctx = Ctx::new(doc);
+    let mut ctx = Ctx::new(doc, raw);
 
     parse_methods(&mut ctx, &mut methods, &doc.methods);
 
     if let Some(ref components) = doc.components {
         ctx.push_path("components");
+        ctx.push_pointer("components");
         parse_schemas(&mut ctx, &mut types, &components.schemas);
+        ctx.pop_pointer();
         ctx.pop_path();
     }
 
     assert_eq!(ctx.path, "#");
+    assert_eq!(ctx.pointer, "");
     if !ctx.errors.is_empty() {
         return Err(ctx.errors);
     }
 
     types.append(&mut ctx.anonymous_types);
 
-    Ok(File { methods, types })
+    Ok(File {
+        methods,
+        types,
+        errors: ctx.error_defs,
+    })
 }
 
 /// Parse the methods specified in the OpenRPC document into a list of [`Method`]s.
 fn parse_methods(ctx: &mut Ctx, output: &mut Vec<Method>, methods: &[rpc::RefOr<rpc::Method>]) {
     ctx.push_path("methods");
+    ctx.push_pointer("methods");
 
-    for method in methods {
+    for (i, method) in methods.iter().enumerate() {
+        ctx.push_pointer(&i.to_string());
         match method {
             rpc::RefOr::Inline(method) => {
                 output.push(parse_method(ctx, method));
             }
             rpc::RefOr::Reference { .. } => {
-                ctx.add_error("externally defined methods are not supported");
+                ctx.add_error(ParsingErrorKind::ExternalMethod);
             }
         }
+        ctx.pop_pointer();
     }
 
+    ctx.pop_pointer();
     ctx.pop_path();
 }
 
@@ -127,11 +179,15 @@ fn parse_method(ctx: &mut Ctx, method: &rpc::Method) -> Method {
         .description
         .clone()
         .or_else(|| method.summary.clone());
+    ctx.push_pointer("result");
     let result = method
         .result
         .as_ref()
         .and_then(|cd| ref_or_content_descriptor(ctx, cd, parse_method_result));
+    ctx.pop_pointer();
     parse_params(ctx, &mut params, &method.params);
+    let errors = parse_errors(ctx, &method.errors);
+    let links = parse_links(ctx, &method.links);
     ctx.pop_path();
 
     Method {
@@ -140,6 +196,234 @@ fn parse_method(ctx: &mut Ctx, method: &rpc::Method) -> Method {
         params,
         result,
         param_structure: method.param_structure,
+        errors,
+        links,
+    }
+}
+
+/// Parses the links of a method.
+fn parse_links(ctx: &mut Ctx, links: &[rpc::RefOr<rpc::Link>]) -> Vec<Link> {
+    let mut output = Vec::new();
+
+    ctx.push_path("links");
+    ctx.push_pointer("links");
+    for (i, link) in links.iter().enumerate() {
+        ctx.push_pointer(&i.to_string());
+        let link = match link {
+            rpc::RefOr::Inline(link) => Some(link),
+            rpc::RefOr::Reference { reference } => {
+                let link = component_ref(reference, "links")
+                    .and_then(|(_, key)| ctx.doc.components.as_ref()?.links.get(&key));
+                if link.is_none() {
+                    ctx.add_error(ParsingErrorKind::UnresolvedRef(reference.clone()));
+                }
+                link
+            }
+        };
+        if let Some(link) = link {
+            output.extend(parse_link(link));
+        }
+        ctx.pop_pointer();
+    }
+    ctx.pop_pointer();
+    ctx.pop_path();
+
+    output
+}
+
+/// Parses a link, returning `None` if it does not name the method it links to.
+fn parse_link(link: &rpc::Link) -> Option<Link> {
+    let params = match &link.params {
+        Some(rpc::LinkParams::Constant(params)) => Some(
+            params
+                .iter()
+                .map(|(name, value)| (name.clone(), link_param(value)))
+                .collect(),
+        ),
+        Some(rpc::LinkParams::Dynamic(_)) => None,
+        None => Some(BTreeMap::new()),
+    };
+
+    Some(Link {
+        name: link.name.clone(),
+        documentation: link.description.clone().or_else(|| link.summary.clone()),
+        method: link.method.clone()?,
+        params,
+    })
+}
+
+/// Parses the value of a parameter of a link.
+///
+/// Strings starting with `$` are runtime expressions, of which only references to a field of
+/// the result (`$response.body#/field`) are supported.
+fn link_param(value: &serde_json::Value) -> LinkParam {
+    let Some(expr) = value.as_str().filter(|s| s.starts_with('$')) else {
+        return LinkParam::Constant(value.clone());
+    };
+    let field = expr
+        .strip_prefix("$response.body#")
+        .map(decode_fragment)
+        .filter(|pointer| pointer.len() > 1 && pointer.starts_with('/'))
+        .map(|pointer| {
+            pointer[1..]
+                .split('/')
+                .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+                .collect()
+        });
+    match field {
+        Some(field) => LinkParam::Field(field),
+        None => LinkParam::Unsupported,
+    }
+}
+
+/// Parses the errors of a method, returning their paths.
+fn parse_errors(ctx: &mut Ctx, errors: &[rpc::RefOr<rpc::Error>]) -> Vec<Path> {
+    let mut output = Vec::new();
+
+    ctx.push_path("errors");
+    ctx.push_pointer("errors");
+    for (i, error) in errors.iter().enumerate() {
+        ctx.push_path(&i.to_string());
+        ctx.push_pointer(&i.to_string());
+        let path = match error {
+            rpc::RefOr::Inline(error) => {
+                let path = ctx.current_path();
+                Some(register_error(ctx, path, None, error))
+            }
+            rpc::RefOr::Reference { reference } => match component_ref(reference, "errors")
+                .and_then(|(pointer, key)| {
+                    let error = ctx.doc.components.as_ref()?.errors.get(&key)?;
+                    Some((pointer, error, key))
+                }) {
+                Some((pointer, error, key)) => {
+                    // Shared errors are identified by their reference, ensuring that they are
+                    // only defined once.
+                    let path = format!("#{pointer}").into();
+                    Some(register_error(ctx, path, Some(&key), error))
+                }
+                None => {
+                    ctx.add_error(ParsingErrorKind::UnresolvedRef(reference.clone()));
+                    None
+                }
+            },
+        };
+        if let Some(path) = path {
+            if !output.contains(&path) {
+                output.push(path);
+            }
+        }
+        ctx.pop_pointer();
+        ctx.pop_path();
+    }
+    ctx.pop_pointer();
+    ctx.pop_path();
+
+    output
+}
+
+/// Registers an error to the context, returning the path under which it is available.
+///
+/// If an error with the same code has already been registered, its path is returned instead.
+fn register_error(ctx: &mut Ctx, path: Path, key: Option<&str>, error: &rpc::Error) -> Path {
+    if let Some(existing) = ctx.error_defs.values().find(|e| e.code == error.code) {
+        return existing.path.clone();
+    }
+
+    let mut name = error_name(key, error);
+    if ctx.error_defs.values().any(|e| e.name == name) {
+        name = format!("{name}{}", error.code.unsigned_abs());
+    }
+
+    ctx.error_defs.insert(
+        path.clone(),
+        ErrorDef {
+            path: path.clone(),
+            name,
+            code: error.code,
+            message: error.message.clone(),
+        },
+    );
+
+    path
+}
+
+/// Escapes `~` and `/` in the provided JSON pointer segment.
+fn escape_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Decodes the `%xx` sequences of the provided URI fragment, turning it into a JSON pointer.
+///
+/// The `~0` and `~1` escapes are kept as-is, as they are part of the JSON pointer syntax. The
+/// fragment is returned unchanged if the decoded bytes are not valid UTF-8.
+fn decode_fragment(fragment: &str) -> String {
+    let bytes = fragment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).unwrap_or_else(|_| fragment.to_owned())
+}
+
+/// Parses a reference to an entry of the provided section of `components`.
+///
+/// Returns the JSON pointer of the entry along with its unescaped key, or `None` if the
+/// reference is not of the form `#/components/{section}/{key}`.
+fn component_ref(reference: &str, section: &str) -> Option<(String, String)> {
+    let pointer = decode_fragment(reference.strip_prefix('#')?);
+    let key = pointer
+        .strip_prefix("/components/")?
+        .strip_prefix(section)?
+        .strip_prefix('/')
+        .filter(|key| !key.contains('/'))?
+        .replace("~1", "/")
+        .replace("~0", "~");
+    Some((pointer, key))
+}
+
+/// Computes the name of an error from its key in `components/errors`, or from its message.
+fn error_name(key: Option<&str>, error: &rpc::Error) -> String {
+    let words: String = key
+        .unwrap_or(&error.message)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+        .collect();
+    let name = words.to_case(Case::Pascal);
+
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("Code{}", error.code.unsigned_abs())
+    }
+}
+
+/// Converts the value of a string enum into a valid Rust variant name.
+fn variant_name(value: &str) -> String {
+    let words: String = value
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let name = words.to_case(Case::Pascal);
+
+    if name.starts_with(char::is_alphabetic) {
+        name
+    } else {
+        format!("V{name}")
     }
 }
 
@@ -152,10 +436,34 @@ fn ref_or_content_descriptor<R>(
 ) -> Option<R> {
     match cd {
         rpc::RefOr::Inline(cd) => Some(f(ctx, cd)),
-        rpc::RefOr::Reference { reference } => match ctx.doc.get_content_descriptor(reference) {
-            Some(cd) => Some(f(ctx, cd)),
+        rpc::RefOr::Reference { reference } => match component_ref(reference, "contentDescriptors")
+            .and_then(|(pointer, key)| {
+                let cd = ctx.doc.components.as_ref()?.content_descriptors.get(&key)?;
+                Some((pointer, cd))
+            }) {
+            Some((target, cd)) => {
+                // Shared content descriptors are parsed at their own location in the document.
+                // This ensures that the types they define are shared between all the methods
+                // referencing them, instead of being duplicated for each method.
+                let path = std::mem::replace(&mut ctx.path, format!("#{target}"));
+                let pointer = std::mem::replace(&mut ctx.pointer, target);
+                let ret = f(ctx, cd);
+                // Without a title, the type defined by the schema of the descriptor is named
+                // after the descriptor itself rather than being anonymous.
+                let schema_pointer = format!("{}/schema", ctx.pointer);
+                if let Some(ty) = ctx
+                    .anonymous_types
+                    .values_mut()
+                    .find(|ty| ty.pointer == schema_pointer && ty.name == ANONYMOUS_TYPE_NAME)
+                {
+                    ty.name = type_name(&cd.name);
+                }
+                ctx.pointer = pointer;
+                ctx.path = path;
+                Some(ret)
+            }
             None => {
-                ctx.add_error(format!("reference `{reference}` not found"));
+                ctx.add_error(ParsingErrorKind::UnresolvedRef(reference.clone()));
                 None
             }
         },
@@ -169,11 +477,15 @@ fn parse_params(
     params: &[rpc::RefOr<rpc::ContentDescriptor>],
 ) {
     ctx.push_path("params");
+    ctx.push_pointer("params");
 
-    for param in params {
+    for (i, param) in params.iter().enumerate() {
+        ctx.push_pointer(&i.to_string());
         ref_or_content_descriptor(ctx, param, |ctx, cd| output.push(parse_param(ctx, cd)));
+        ctx.pop_pointer();
     }
 
+    ctx.pop_pointer();
     ctx.pop_path();
 }
 
@@ -183,7 +495,13 @@ fn parse_param(ctx: &mut Ctx, param: &rpc::ContentDescriptor) -> MethodParameter
     let name_in_json = param.name.clone();
     let name = field_name(name_in_json.clone());
     let documentation = param.description.clone().or_else(|| param.summary.clone());
+    let mut examples = raw_examples(ctx);
+    ctx.push_pointer("schema");
+    if examples.is_empty() {
+        examples = raw_examples(ctx);
+    }
     let ty = parse_type_ref(ctx, TypeSource::Method, &param.schema);
+    ctx.pop_pointer();
     let required = param.required;
     ctx.pop_path();
 
@@ -191,15 +509,33 @@ fn parse_param(ctx: &mut Ctx, param: &rpc::ContentDescriptor) -> MethodParameter
         name,
         name_in_json,
         documentation,
+        examples,
         ty,
         required,
     }
 }
 
+/// Returns the values of the `examples` keyword of the object currently being parsed.
+fn raw_examples(ctx: &Ctx) -> Vec<serde_json::Value> {
+    ctx.raw_keyword("examples")
+        .and_then(|examples| examples.as_array())
+        .cloned()
+        .unwrap_or_default()
+}
+
 /// Parses a [`rpc::ContentDescriptor`] into a method result.
 fn parse_method_result(ctx: &mut Ctx, result: &rpc::ContentDescriptor) -> MethodResult {
     ctx.push_path("result");
-    let ty = parse_type_ref(ctx, TypeSource::Method, &result.schema);
+    ctx.push_pointer("schema");
+    let ty = match &result.schema.contents {
+        // `anyOf` schemas are usually parsed as a struct of optional flattened fields, but a
+        // method result is always exactly one of the members of the union.
+        rpc::SchemaContents::AnyOf { any_of } if any_of.len() > 1 => {
+            parse_union_type_ref(ctx, TypeSource::Method, &result.schema, any_of)
+        }
+        _ => parse_type_ref(ctx, TypeSource::Method, &result.schema),
+    };
+    ctx.pop_pointer();
     let documentation = result
         .description
         .clone()
@@ -216,15 +552,36 @@ fn parse_schemas(
     schemas: &BTreeMap<String, rpc::Schema>,
 ) {
     ctx.push_path("schemas");
+    ctx.push_pointer("schemas");
 
     for (name, schema) in schemas {
+        ctx.push_pointer(name);
         let ty = parse_type(ctx, Some(name), TypeSource::Declared, schema);
+        ctx.pop_pointer();
         output.insert(ty.path.clone(), ty);
     }
 
+    ctx.pop_pointer();
     ctx.pop_path();
 }
 
+/// Converts the provided name into the name of a Rust type.
+fn type_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .to_case(Case::Pascal);
+
+    if name.is_empty() {
+        ANONYMOUS_TYPE_NAME.into()
+    } else if name.starts_with(char::is_alphabetic) {
+        name
+    } else {
+        format!("T{name}")
+    }
+}
+
 /// Parses a type from a [`rpc::Schema`] into a [`TypeDef`].
 fn parse_type(
     ctx: &mut Ctx,
@@ -234,23 +591,72 @@ fn parse_type(
 ) -> TypeDef {
     ctx.push_path(name.unwrap_or("_anon"));
     let path = ctx.current_path();
-    let name = name
-        .or(schema.title.as_deref())
-        .unwrap_or("Anonymous")
-        .to_case(Case::Pascal);
+    let pointer = ctx.pointer.clone();
+    let name = type_name(
+        name.or(schema.title.as_deref())
+            .unwrap_or(ANONYMOUS_TYPE_NAME),
+    );
     let documentation = schema.description.clone();
     let kind = parse_type_kind(ctx, &schema.contents);
+    let constraints = if source == TypeSource::Declared {
+        unsupported_constraints(ctx)
+    } else {
+        Vec::new()
+    };
     ctx.pop_path();
 
     TypeDef {
         path,
+        pointer,
         name,
         documentation,
         source,
         kind,
+        constraints,
     }
 }
 
+/// Returns the constraints of the schema currently being parsed that can't be represented in
+/// Rust types.
+fn unsupported_constraints(ctx: &Ctx) -> Vec<String> {
+    ctx.raw_keyword("not")
+        .map(|not| format!("not: {not}"))
+        .into_iter()
+        .collect()
+}
+
+/// Returns the bounds declared by the schema currently being parsed.
+///
+/// Both the numeric form of `exclusiveMinimum` and `exclusiveMaximum` and their older boolean
+/// form, modifying `minimum` and `maximum`, are supported.
+fn parse_bounds(ctx: &Ctx) -> Vec<Bound> {
+    let number = |name| match ctx.raw_keyword(name) {
+        Some(serde_json::Value::Number(n)) => Some(n.clone()),
+        _ => None,
+    };
+    let flag = |name| ctx.raw_keyword(name).and_then(|v| v.as_bool()) == Some(true);
+    let count = |name| ctx.raw_keyword(name).and_then(|v| v.as_u64());
+
+    let mut bounds = Vec::new();
+    if let Some(n) = number("minimum") {
+        bounds.push(Bound::Minimum(n, flag("exclusiveMinimum")));
+    }
+    if let Some(n) = number("exclusiveMinimum") {
+        bounds.push(Bound::Minimum(n, true));
+    }
+    if let Some(n) = number("maximum") {
+        bounds.push(Bound::Maximum(n, flag("exclusiveMaximum")));
+    }
+    if let Some(n) = number("exclusiveMaximum") {
+        bounds.push(Bound::Maximum(n, true));
+    }
+    bounds.extend(count("minLength").map(Bound::MinLength));
+    bounds.extend(count("maxLength").map(Bound::MaxLength));
+    bounds.extend(count("minItems").map(Bound::MinItems));
+    bounds.extend(count("maxItems").map(Bound::MaxItems));
+    bounds
+}
+
 /// Parses a [`rpc::Schema`] into a [`TypeInfo`].
 fn parse_type_ref(ctx: &mut Ctx, source: TypeSource, schema: &rpc::Schema) -> TypeRef {
     let ty = parse_type(ctx, None, source, schema);
@@ -263,16 +669,87 @@ fn parse_type_ref(ctx: &mut Ctx, source: TypeSource, schema: &rpc::Schema) -> Ty
     }
 }
 
+/// Parses an `anyOf` schema into an untagged enum rather than a flattened struct.
+fn parse_union_type_ref(
+    ctx: &mut Ctx,
+    source: TypeSource,
+    schema: &rpc::Schema,
+    members: &[rpc::Schema],
+) -> TypeRef {
+    let preserve_order = preserves_order(ctx);
+    ctx.push_path("_anon");
+    let pointer = ctx.pointer.clone();
+    ctx.push_pointer("anyOf");
+    let path = ctx.current_path();
+    let name = type_name(schema.title.as_deref().unwrap_or(ANONYMOUS_TYPE_NAME));
+    let documentation = schema.description.clone();
+    let kind = parse_enum(ctx, members, preserve_order);
+    ctx.pop_pointer();
+    ctx.pop_path();
+
+    ctx.register_type(TypeDef {
+        path: path.clone(),
+        pointer,
+        name,
+        documentation,
+        source,
+        kind,
+        constraints: Vec::new(),
+    });
+
+    TypeRef::Ref(path)
+}
+
 /// Parses the provided [`rpc::SchemaContents`] into a [`TypeKind`].
 fn parse_type_kind(ctx: &mut Ctx, contents: &rpc::SchemaContents) -> TypeKind {
     match contents {
-        rpc::SchemaContents::Reference { reference } => TypeKind::Alias(AliasDef {
-            ty: TypeRef::Ref(Path::from(reference.as_str())),
-        }),
+        rpc::SchemaContents::Reference { reference } => {
+            let target = reference.strip_prefix('#').map(decode_fragment);
+            if target
+                .as_deref()
+                .is_some_and(|target| ctx.raw.pointer(target).is_none())
+            {
+                ctx.push_pointer("$ref");
+                ctx.add_error(ParsingErrorKind::UnresolvedRef(reference.clone()));
+                ctx.pop_pointer();
+            }
+            let path = match target {
+                Some(target) => Path::from(format!("#{target}")),
+                None => Path::from(reference.as_str()),
+            };
+            TypeKind::Alias(AliasDef {
+                ty: TypeRef::Ref(path),
+            })
+        }
         rpc::SchemaContents::Literal(literal) => literal_to_type_kind(ctx, literal),
-        rpc::SchemaContents::AllOf { all_of } => parse_flatten_struct(ctx, true, all_of),
-        rpc::SchemaContents::AnyOf { any_of } => parse_flatten_struct(ctx, false, any_of),
-        rpc::SchemaContents::OneOf { one_of } => parse_enum(ctx, one_of),
+        rpc::SchemaContents::AllOf { all_of } => {
+            ctx.push_pointer("allOf");
+            let kind = parse_flatten_struct(ctx, true, all_of);
+            ctx.pop_pointer();
+            kind
+        }
+        // A `null` member can't be flattened into a struct, so such unions are parsed as a
+        // nullable enum instead.
+        rpc::SchemaContents::AnyOf { any_of } if any_of.iter().any(is_null_schema) => {
+            let preserve_order = preserves_order(ctx);
+            ctx.push_pointer("anyOf");
+            let kind = parse_enum(ctx, any_of, preserve_order);
+            ctx.pop_pointer();
+            kind
+        }
+        rpc::SchemaContents::AnyOf { any_of } => {
+            ctx.push_pointer("anyOf");
+            let kind = parse_flatten_struct(ctx, false, any_of);
+            ctx.pop_pointer();
+            kind
+        }
+        rpc::SchemaContents::OneOf { one_of } => {
+            let preserve_order = preserves_order(ctx);
+            ctx.push_pointer("oneOf");
+            let kind = parse_enum(ctx, one_of, preserve_order);
+            ctx.pop_pointer();
+            kind
+        }
     }
 }
 
@@ -298,6 +775,13 @@ fn literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::Literal) -> TypeKind {
 }
 
 fn string_literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::StringLiteral) -> TypeKind {
+    // `const` is not part of the OpenRPC schema model, but is equivalent to a single-value
+    // `enum`.
+    if let Some(value) = ctx.raw_keyword("const").and_then(|v| v.as_str()) {
+        return TypeKind::Alias(AliasDef {
+            ty: TypeRef::Keyword(value.to_owned()),
+        });
+    }
     if let Some(ref e) = literal.enumeration {
         if e.len() == 1 {
             TypeKind::Alias(AliasDef {
@@ -307,9 +791,10 @@ fn string_literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::StringLiteral) -> T
             TypeKind::Enum(EnumDef {
                 variants: e
                     .iter()
-                    .map(|e| {
+                    .enumerate()
+                    .map(|(index, e)| {
                         ctx.push_path(e);
-                        let name = e.to_case(Case::Pascal);
+                        let name = variant_name(e);
                         let path = ctx.current_path();
                         let out = EnumVariant {
                             path: path.clone(),
@@ -317,6 +802,7 @@ fn string_literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::StringLiteral) -> T
                             name_in_json: Some(e.clone()),
                             documentation: None,
                             ty: None,
+                            index,
                         };
                         ctx.pop_path();
 
@@ -324,9 +810,19 @@ fn string_literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::StringLiteral) -> T
                     })
                     .collect(),
                 copy: true,
+                nullable: false,
+                preserve_order: false,
+                default: ctx
+                    .raw_keyword("default")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_owned),
                 tag: EnumTag::Normal,
             })
         }
+    } else if let Some(ty) = parse_content_schema(ctx) {
+        TypeKind::Alias(AliasDef {
+            ty: TypeRef::EncodedJson(Box::new(ty)),
+        })
     } else if literal.pattern.as_deref() == Some("^0x[a-fA-F0-9]+$") {
         TypeKind::Alias(AliasDef {
             ty: TypeRef::Integer {
@@ -340,6 +836,30 @@ fn string_literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::StringLiteral) -> T
     }
 }
 
+/// Parses the `contentSchema` of the string schema currently being parsed, if its content is
+/// JSON.
+fn parse_content_schema(ctx: &mut Ctx) -> Option<TypeRef> {
+    let media_type = ctx.raw_keyword("contentMediaType")?.as_str()?;
+    if media_type != "application/json" && !media_type.ends_with("+json") {
+        return None;
+    }
+    let raw = ctx.raw_keyword("contentSchema")?;
+
+    ctx.push_pointer("contentSchema");
+    ctx.push_path("content");
+    let ty = match serde_json::from_value::<rpc::Schema>(raw.clone()) {
+        Ok(schema) => Some(parse_type_ref(ctx, TypeSource::Anonymous, &schema)),
+        Err(err) => {
+            ctx.add_error(ParsingErrorKind::InvalidContentSchema(err.to_string()));
+            None
+        }
+    };
+    ctx.pop_path();
+    ctx.pop_pointer();
+
+    ty
+}
+
 /// Converts an arbitrary name to a valid Rust field name.
 fn field_name(name_in_json: String) -> String {
     if name_in_json == "type" {
@@ -355,14 +875,28 @@ fn field_name(name_in_json: String) -> String {
 fn object_literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::ObjectLiteral) -> TypeKind {
     let mut fields = BTreeMap::new();
 
+    ctx.push_pointer("properties");
     for (name, value) in &literal.properties {
         ctx.push_path(name);
+        ctx.push_pointer(name);
         let path = ctx.current_path();
         let documentation = value.description.clone();
         let ty = parse_type_ref(ctx, TypeSource::Anonymous, value);
         let required = literal.required.contains(name);
+        let read_only = ctx
+            .raw_keyword("readOnly")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let write_only = ctx
+            .raw_keyword("writeOnly")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let constraints = unsupported_constraints(ctx);
+        let bounds = parse_bounds(ctx);
+        let default = ctx.raw_keyword("default").cloned();
         let name_in_json = name.clone();
         let name = field_name(name_in_json.clone());
+        ctx.pop_pointer();
         ctx.pop_path();
 
         fields.insert(
@@ -374,10 +908,16 @@ fn object_literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::ObjectLiteral) -> T
                 documentation,
                 required,
                 flatten: false,
+                read_only,
+                write_only,
                 ty,
+                constraints,
+                bounds,
+                default,
             },
         );
     }
+    ctx.pop_pointer();
 
     TypeKind::Struct(StructDef {
         fields,
@@ -387,38 +927,106 @@ fn object_literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::ObjectLiteral) -> T
 
 /// Creates a new [`TypeRef`] for the provided array literal.
 fn array_literal_to_type_kind(ctx: &mut Ctx, literal: &rpc::ArrayLiteral) -> TypeKind {
-    if let Some(ref items) = literal.items {
+    if let Some(tuple) = parse_prefix_items(ctx) {
+        TypeKind::Tuple(tuple)
+    } else if let Some(ref items) = literal.items {
+        let max_items = ctx.raw_keyword("maxItems").and_then(|v| v.as_u64());
+        ctx.push_pointer("items");
+        let ty = parse_type_ref(ctx, TypeSource::Anonymous, items);
+        ctx.pop_pointer();
         TypeKind::Alias(AliasDef {
-            ty: TypeRef::Array(Box::new(parse_type_ref(ctx, TypeSource::Anonymous, items))),
+            ty: TypeRef::Array(Box::new(ty), max_items),
         })
     } else {
-        ctx.add_error("array literals without `.items` are not supported");
+        ctx.add_error(ParsingErrorKind::ArrayWithoutItems);
         TypeKind::Alias(AliasDef {
-            ty: TypeRef::Array(Box::new(TypeRef::Null)),
+            ty: TypeRef::Array(Box::new(TypeRef::Null), None),
         })
     }
 }
 
+/// Parses the `prefixItems` of the array schema currently being parsed, if any.
+fn parse_prefix_items(ctx: &mut Ctx) -> Option<TupleDef> {
+    let raw = ctx.raw_keyword("prefixItems")?.as_array()?;
+
+    let mut items = Vec::with_capacity(raw.len());
+    let mut names = Vec::with_capacity(raw.len());
+    let mut documentation = Vec::with_capacity(raw.len());
+    ctx.push_pointer("prefixItems");
+    for (i, raw) in raw.iter().enumerate() {
+        ctx.push_path(&format!("item{}", i));
+        ctx.push_pointer(&i.to_string());
+        match serde_json::from_value::<rpc::Schema>(raw.clone()) {
+            Ok(schema) => {
+                let ty = parse_type_ref(ctx, TypeSource::Anonymous, &schema);
+                let name = match (&schema.title, &ty) {
+                    (Some(title), _) => Some(title.as_str()),
+                    (None, TypeRef::Ref(_)) => Some(ty.name()),
+                    (None, _) => None,
+                };
+                names.push(item_name(i, name, &names));
+                documentation.push(schema.description.clone());
+                items.push(ty);
+            }
+            Err(err) => ctx.add_error(ParsingErrorKind::InvalidPrefixItem(err.to_string())),
+        }
+        ctx.pop_pointer();
+        ctx.pop_path();
+    }
+    ctx.pop_pointer();
+
+    Some(TupleDef {
+        items,
+        names,
+        documentation,
+    })
+}
+
+/// Converts the title of the item at index `i` of a tuple into a valid Rust identifier, falling
+/// back to `item{i}` when there is none or when it is already taken by a previous item.
+fn item_name(i: usize, title: Option<&str>, taken: &[String]) -> String {
+    let name = title.map(|title| {
+        let words: String = title
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+            .collect();
+        field_name(words.to_case(Case::Snake))
+    });
+
+    match name {
+        Some(name)
+            if name.starts_with(|c: char| c.is_ascii_alphabetic()) && !taken.contains(&name) =>
+        {
+            name
+        }
+        _ => format!("item{i}"),
+    }
+}
+
 /// Parses the provided list of schemas into a flatten struct.
 fn parse_flatten_struct(ctx: &mut Ctx, required: bool, schemas: &[rpc::Schema]) -> TypeKind {
     if schemas.len() == 1 {
-        return TypeKind::Alias(AliasDef {
-            ty: parse_type_ref(ctx, TypeSource::Anonymous, &schemas[0]),
-        });
+        ctx.push_pointer("0");
+        let ty = parse_type_ref(ctx, TypeSource::Anonymous, &schemas[0]);
+        ctx.pop_pointer();
+        return TypeKind::Alias(AliasDef { ty });
     }
 
     let mut fields = BTreeMap::new();
 
     for (i, schema) in schemas.iter().enumerate() {
         ctx.push_path(&format!("field{}", i));
+        ctx.push_pointer(&i.to_string());
         let path = ctx.current_path();
         let documentation = schema.description.clone();
         let ty = parse_type_ref(ctx, TypeSource::Anonymous, schema);
+        let constraints = unsupported_constraints(ctx);
         let name = match schema.title {
             Some(ref title) => field_name(title.to_case(Case::Snake)),
             None => field_name(ty.name().to_case(Case::Snake)),
         };
         let name_in_json = name.clone();
+        ctx.pop_pointer();
         ctx.pop_path();
 
         fields.insert(
@@ -430,7 +1038,12 @@ fn parse_flatten_struct(ctx: &mut Ctx, required: bool, schemas: &[rpc::Schema])
                 documentation,
                 required,
                 flatten: true,
+                read_only: false,
+                write_only: false,
                 ty,
+                constraints,
+                bounds: Vec::new(),
+                default: None,
             },
         );
     }
@@ -442,11 +1055,13 @@ fn parse_flatten_struct(ctx: &mut Ctx, required: bool, schemas: &[rpc::Schema])
 }
 
 /// Parses the provided list of schemas into an enum.
-fn parse_enum(ctx: &mut Ctx, schemas: &[rpc::Schema]) -> TypeKind {
+fn parse_enum(ctx: &mut Ctx, schemas: &[rpc::Schema], preserve_order: bool) -> TypeKind {
     let mut variants = BTreeMap::new();
+    let mut nullable = false;
 
     for (i, schema) in schemas.iter().enumerate() {
         ctx.push_path(&format!("variant{}", i));
+        ctx.push_pointer(&i.to_string());
         let path = ctx.current_path();
         let documentation = schema.description.clone();
         let ty = parse_type_ref(ctx, TypeSource::Anonymous, schema);
@@ -454,8 +1069,14 @@ fn parse_enum(ctx: &mut Ctx, schemas: &[rpc::Schema]) -> TypeKind {
             Some(ref title) => title.to_case(Case::Pascal),
             None => ty.name().to_case(Case::Pascal),
         };
+        ctx.pop_pointer();
         ctx.pop_path();
 
+        if matches!(ty, TypeRef::Null) {
+            nullable = true;
+            continue;
+        }
+
         variants.insert(
             path.clone(),
             EnumVariant {
@@ -464,6 +1085,7 @@ fn parse_enum(ctx: &mut Ctx, schemas: &[rpc::Schema]) -> TypeKind {
                 name,
                 documentation,
                 ty: Some(ty),
+                index: i,
             },
         );
     }
@@ -472,5 +1094,23 @@ fn parse_enum(ctx: &mut Ctx, schemas: &[rpc::Schema]) -> TypeKind {
         variants,
         tag: EnumTag::Untagged,
         copy: false,
+        nullable,
+        preserve_order,
+        default: None,
     })
 }
+
+/// Returns whether the provided schema only accepts `null`.
+fn is_null_schema(schema: &rpc::Schema) -> bool {
+    matches!(
+        schema.contents,
+        rpc::SchemaContents::Literal(rpc::Literal::Null)
+    )
+}
+
+/// Returns whether the schema currently being parsed uses the `x-preserve-order` extension.
+fn preserves_order(ctx: &Ctx) -> bool {
+    ctx.raw_keyword("x-preserve-order")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
@@ -1,13 +1,18 @@
 //! Defines the data model we want to target with our parser.
 
+mod dynamic_refs;
+mod external_refs;
 mod logic;
 
 use std::collections::BTreeMap;
 
+use convert_case::{Case, Casing};
 use open_rpc::ParamStructure;
 
 use crate::config::Config;
 
+pub use self::dynamic_refs::approximate_dynamic_refs;
+pub use self::external_refs::external_ref_files;
 pub use self::logic::parse;
 
 /// An error that occurred during parsing.
@@ -15,8 +20,52 @@ pub use self::logic::parse;
 pub struct ParsingError {
     /// The path at which the error occured.
     pub path: Path,
-    /// A message describing the error.
-    pub message: String,
+    /// The JSON pointer of the value at which the error occured within the document.
+    pub pointer: String,
+    /// The kind of the error.
+    pub kind: ParsingErrorKind,
+}
+
+/// The kind of a [`ParsingError`].
+#[derive(Debug, Clone)]
+pub enum ParsingErrorKind {
+    /// A reference does not point to anything within the document.
+    UnresolvedRef(String),
+    /// A method is defined through a reference, which is not supported.
+    ExternalMethod,
+    /// An array schema does not specify the type of its items.
+    ArrayWithoutItems,
+    /// The `contentSchema` of a string is not a valid schema.
+    InvalidContentSchema(String),
+    /// An item of the `prefixItems` of an array is not a valid schema.
+    InvalidPrefixItem(String),
+}
+
+impl ParsingErrorKind {
+    /// Returns a short machine-readable identifier for the kind of error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnresolvedRef(_) => "unresolved-ref",
+            Self::ExternalMethod => "external-method",
+            Self::ArrayWithoutItems => "array-without-items",
+            Self::InvalidContentSchema(_) => "invalid-content-schema",
+            Self::InvalidPrefixItem(_) => "invalid-prefix-item",
+        }
+    }
+}
+
+impl std::fmt::Display for ParsingErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnresolvedRef(reference) => write!(f, "reference `{reference}` not found"),
+            Self::ExternalMethod => f.write_str("externally defined methods are not supported"),
+            Self::ArrayWithoutItems => {
+                f.write_str("array literals without `.items` are not supported")
+            }
+            Self::InvalidContentSchema(err) => write!(f, "invalid `contentSchema`: {err}"),
+            Self::InvalidPrefixItem(err) => write!(f, "invalid `prefixItems` item: {err}"),
+        }
+    }
 }
 
 /// The output file we want to generate.
@@ -26,6 +75,26 @@ pub struct File {
     pub methods: Vec<Method>,
     /// The list of types defined in the OpenRPC document.
     pub types: BTreeMap<Path, TypeDef>,
+    /// The list of errors that methods may return.
+    ///
+    /// Errors sharing the same code are only included once.
+    pub errors: BTreeMap<Path, ErrorDef>,
+}
+
+impl File {
+    /// Returns the type ultimately referenced by the provided type, looking through aliases.
+    pub fn unalias<'a>(&'a self, mut ty: &'a TypeRef) -> &'a TypeRef {
+        for _ in 0..32 {
+            let TypeRef::Ref(path) = ty else {
+                break;
+            };
+            match self.types.get(path).map(|ty| &ty.kind) {
+                Some(TypeKind::Alias(a)) => ty = &a.ty,
+                _ => break,
+            }
+        }
+        ty
+    }
 }
 
 /// An OpenRPC method.
@@ -43,6 +112,52 @@ pub struct Method {
     ///
     /// If `None`, the method is intended to be used as a notification.
     pub result: Option<MethodResult>,
+    /// The paths of the errors that the method may return, in [`File::errors`].
+    pub errors: Vec<Path>,
+    /// The links describing how the result of the method may be used to call other methods.
+    pub links: Vec<Link>,
+}
+
+/// A link from the result of a method to the parameters of another method.
+#[derive(Debug, Clone)]
+pub struct Link {
+    /// The name of the link.
+    pub name: String,
+    /// Some documentation about the link.
+    pub documentation: Option<String>,
+    /// The name of the linked method.
+    pub method: String,
+    /// The values of the parameters of the linked method, by their name in JSON.
+    ///
+    /// `None` when the parameters are computed from a single runtime expression, which is not
+    /// supported.
+    pub params: Option<BTreeMap<String, LinkParam>>,
+}
+
+/// The value of a parameter of a [`Link`].
+#[derive(Debug, Clone)]
+pub enum LinkParam {
+    /// A constant value.
+    Constant(serde_json::Value),
+    /// A field of the result of the method, through a `$response.body#/...` expression.
+    ///
+    /// The path contains the name in JSON of the field at each level.
+    Field(Vec<String>),
+    /// A runtime expression that is not supported.
+    Unsupported,
+}
+
+/// An application-defined error that may be returned by methods.
+#[derive(Debug, Clone)]
+pub struct ErrorDef {
+    /// The path at which the error is defined.
+    pub path: Path,
+    /// The name of the error.
+    pub name: String,
+    /// The error code.
+    pub code: i64,
+    /// A short description of the error.
+    pub message: String,
 }
 
 /// A path to a resource defined in an OpenRPC document.
@@ -93,15 +208,24 @@ pub enum TypeRef {
     ///
     /// This usually translates to `f64` or `f32`.
     Number,
-    /// An array of elements.
+    /// An array of elements, along with the maximum number of elements it may hold, as
+    /// specified by the `maxItems` keyword.
     ///
     /// This usually translates to `Vec<T>` or `Box<[T]>` and does not require a type
     /// definition.
-    Array(Box<TypeRef>),
+    Array(Box<TypeRef>, Option<u64>),
     /// An empty value.
     ///
-    /// Usually translates to `()`.
+    /// Usually translates to `()` when required, and to `Option<()>` otherwise. Note that
+    /// `null` members of `oneOf` and `anyOf` schemas do not use this type, but make the
+    /// resulting enum [nullable](EnumDef::nullable) instead.
     Null,
+    /// A string containing the JSON representation of a value of the inner type, as described
+    /// by the `contentSchema` keyword.
+    ///
+    /// This translates to the inner type when `decode-json-strings` is enabled, and to a
+    /// regular string otherwise.
+    EncodedJson(Box<TypeRef>),
 }
 
 impl TypeRef {
@@ -115,30 +239,125 @@ impl TypeRef {
             TypeRef::Keyword(val) => val.as_str(),
             TypeRef::Integer { .. } => "integer",
             TypeRef::Number => "number",
-            TypeRef::Array(_) => "array",
+            TypeRef::Array(..) => "array",
             TypeRef::Null => "null",
+            TypeRef::EncodedJson(_) => "string",
+        }
+    }
+
+    /// Returns a Rust expression evaluating to the provided JSON value, if this is a primitive
+    /// type that the value is valid for.
+    pub fn literal(&self, value: &serde_json::Value) -> Option<String> {
+        use serde_json::Value;
+
+        match (self, value) {
+            (TypeRef::Boolean, Value::Bool(b)) => Some(b.to_string()),
+            (
+                TypeRef::Integer {
+                    format_as_hex: false,
+                },
+                Value::Number(n),
+            ) if !n.is_f64() => Some(n.to_string()),
+            (TypeRef::Number, Value::Number(n)) => n.as_f64().map(|n| format!("{n:?}")),
+            (TypeRef::String, Value::String(s)) => Some(format!("{s:?}.into()")),
+            _ => None,
         }
     }
 
     /// Returns the path of the referenced type, if any.
     pub fn inner_path(&self) -> Option<&Path> {
         match self {
-            TypeRef::Array(inner) => inner.inner_path(),
+            TypeRef::Array(inner, _) | TypeRef::EncodedJson(inner) => inner.inner_path(),
             TypeRef::Ref(path) => Some(path),
             _ => None,
         }
     }
 
+    /// Whether the type holds strings of the configured shared string type (such as
+    /// `Arc<str>`), possibly within arrays, which must be (de)serialized through the
+    /// [`SHARED_STR_MODULE`].
+    pub fn holds_shared_string(&self, config: &Config, file: &File) -> bool {
+        if !config.primitives.is_shared_string() {
+            return false;
+        }
+        match self {
+            TypeRef::String | TypeRef::Keyword(_) => true,
+            TypeRef::EncodedJson(_) => !config.generation.decode_json_strings,
+            TypeRef::Array(inner, _) => inner.holds_shared_string(config, file),
+            TypeRef::Ref(r) if !config.generation.type_serde_with.contains_key(&**r) => {
+                match file.types.get(r).map(|ty| &ty.kind) {
+                    Some(TypeKind::Alias(a)) => a.ty.holds_shared_string(config, file),
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
     /// A collection of attributes to add to the type.
-    pub fn attributes(&self, config: &Config, file: &File) -> Vec<String> {
+    ///
+    /// `required` indicates whether the type is wrapped in the optional template.
+    pub fn attributes(&self, config: &Config, file: &File, required: bool) -> Vec<String> {
         match self {
             TypeRef::Ref(r) => {
+                if let Some(module) = config.generation.type_serde_with.get(&**r) {
+                    return vec![if required {
+                        format!("#[serde(with = \"{module}\")]")
+                    } else {
+                        format!("#[serde(with = \"{module}::option\")]")
+                    }];
+                }
                 if let Some(ty) = file.types.get(r) {
                     if let TypeKind::Alias(a) = &ty.kind {
-                        return a.ty.attributes(config, file);
+                        return a.ty.attributes(config, file, required);
                     }
                 }
             }
+            TypeRef::ExternalRef(name) if config.generation.remote_types.contains_key(name) => {
+                let (shim, option_module) = remote_shim_names(name);
+                return vec![if required {
+                    format!("#[serde(with = \"{shim}\")]")
+                } else {
+                    format!("#[serde(with = \"{option_module}\")]")
+                }];
+            }
+            TypeRef::EncodedJson(_) if config.generation.decode_json_strings => {
+                return vec![if required {
+                    format!("#[serde(with = \"{JSON_STRING_MODULE}\")]")
+                } else {
+                    format!("#[serde(with = \"{JSON_STRING_MODULE}::option\")]")
+                }];
+            }
+            TypeRef::Boolean if config.generation.lenient_bool => {
+                return vec![if required {
+                    format!("#[serde(deserialize_with = \"{LENIENT_BOOL_MODULE}::deserialize\")]")
+                } else {
+                    format!(
+                        "#[serde(deserialize_with = \"{LENIENT_BOOL_MODULE}::option::deserialize\")]"
+                    )
+                }];
+            }
+            // Optional values and arrays of shared strings are handled by the module itself.
+            _ if self.holds_shared_string(config, file) => {
+                return vec![format!("#[serde(with = \"{SHARED_STR_MODULE}\")]")];
+            }
+            TypeRef::String if config.generation.max_collection_size.is_some() => {
+                return vec![if required {
+                    format!("#[serde(deserialize_with = \"{CAPPED_MODULE}::string\")]")
+                } else {
+                    format!("#[serde(deserialize_with = \"{CAPPED_MODULE}::option::string\")]")
+                }];
+            }
+            TypeRef::Array(_, max_items)
+                if config.generation.max_collection_size.is_some()
+                    && !(max_items.is_some() && config.generation.bounded_vec) =>
+            {
+                return vec![if required {
+                    format!("#[serde(deserialize_with = \"{CAPPED_MODULE}::seq\")]")
+                } else {
+                    format!("#[serde(deserialize_with = \"{CAPPED_MODULE}::option::seq\")]")
+                }];
+            }
             TypeRef::Integer {
                 format_as_hex: true,
             } => {
@@ -152,8 +371,87 @@ impl TypeRef {
 
         Vec::new()
     }
+
+    /// The path of the function serializing the type in place of its `Serialize` implementation,
+    /// as set by the `with` attribute among its [`attributes`](Self::attributes), if any.
+    pub fn serialize_with(&self, config: &Config, file: &File, required: bool) -> Option<String> {
+        self.attributes(config, file, required)
+            .iter()
+            .find_map(|attr| {
+                let module = attr
+                    .strip_prefix("#[serde(with = \"")?
+                    .strip_suffix("\")]")?;
+                Some(format!("{module}::serialize"))
+            })
+    }
+}
+
+/// A bound on the value of a field, declared by its schema.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bound {
+    /// The value must be greater than or equal to the number, or strictly greater when the
+    /// bound is exclusive.
+    Minimum(serde_json::Number, bool),
+    /// The value must be less than or equal to the number, or strictly less when the bound is
+    /// exclusive.
+    Maximum(serde_json::Number, bool),
+    /// The string must have at least this many characters.
+    MinLength(u64),
+    /// The string must have at most this many characters.
+    MaxLength(u64),
+    /// The array must have at least this many items.
+    MinItems(u64),
+    /// The array must have at most this many items.
+    MaxItems(u64),
+}
+
+impl Bound {
+    /// Returns the JSON Schema keyword declaring the bound.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Minimum(_, false) => "minimum",
+            Self::Minimum(_, true) => "exclusiveMinimum",
+            Self::Maximum(_, false) => "maximum",
+            Self::Maximum(_, true) => "exclusiveMaximum",
+            Self::MinLength(_) => "minLength",
+            Self::MaxLength(_) => "maxLength",
+            Self::MinItems(_) => "minItems",
+            Self::MaxItems(_) => "maxItems",
+        }
+    }
+}
+
+/// The name given to types defined by inline schemas without a `title`.
+pub const ANONYMOUS_TYPE_NAME: &str = "Anonymous";
+
+/// The name of the module generated to (de)serialize shared string types.
+pub const SHARED_STR_MODULE: &str = "shared_str";
+
+/// Returns the name of the `remote` shim generated for the external type at the provided path,
+/// along with the name of the module (de)serializing optional values through it.
+pub fn remote_shim_names(path: &str) -> (String, String) {
+    let name = path.rsplit("::").next().unwrap_or(path);
+    let shim = format!("{name}Def");
+    let option_module = format!("{}_option", shim.to_case(Case::Snake));
+    (shim, option_module)
 }
 
+/// The name of the module generated to (de)serialize the vendor extensions of objects.
+pub const EXTENSIONS_MODULE: &str = "vendor_extensions";
+
+/// The name of the module generated to leniently deserialize booleans.
+pub const LENIENT_BOOL_MODULE: &str = "lenient_bool";
+
+/// The name of the module generated to (de)serialize values encoded as JSON strings.
+pub const JSON_STRING_MODULE: &str = "json_string";
+
+/// The name of the module generated to deserialize strings and arrays of bounded length.
+pub const CAPPED_MODULE: &str = "capped";
+
+/// The name of the module generated to deserialize strings without their surrounding
+/// whitespace.
+pub const TRIMMED_STRING_MODULE: &str = "trimmed_string";
+
 /// The result of an OpenRPC method.
 #[derive(Debug, Clone)]
 pub struct MethodResult {
@@ -172,6 +470,9 @@ pub struct MethodParameter {
     pub name_in_json: String,
     /// Some documentation about the parameter.
     pub documentation: Option<String>,
+    /// Example values of the parameter, taken from the `examples` keyword of its content
+    /// descriptor or of its schema.
+    pub examples: Vec<serde_json::Value>,
     /// The type of the parameter.
     pub ty: TypeRef,
     /// Whether the parameter is required.
@@ -185,6 +486,8 @@ pub struct MethodParameter {
 pub struct TypeDef {
     /// The path at which the type is defined.
     pub path: Path,
+    /// The JSON pointer of the schema defining the type within the document.
+    pub pointer: String,
     /// The name of the type.
     pub name: String,
     /// Some documentation associated with the type.
@@ -193,6 +496,10 @@ pub struct TypeDef {
     pub source: TypeSource,
     /// The kind of the type.
     pub kind: TypeKind,
+    /// The constraints of the schema that can't be represented by the type, such as `not`.
+    ///
+    /// Those are ignored for typing purposes.
+    pub constraints: Vec<String>,
 }
 
 /// The kind of a type.
@@ -204,6 +511,8 @@ pub enum TypeKind {
     Enum(EnumDef),
     /// A newtype.
     Alias(AliasDef),
+    /// A tuple struct.
+    Tuple(TupleDef),
 }
 
 /// A struct definition.
@@ -236,8 +545,28 @@ pub struct StructField {
     ///
     /// This usually means adding the `#[serde(flatten)]` attribute to the field.
     pub flatten: bool,
+    /// Whether the field is marked as `readOnly` in the OpenRPC document.
+    ///
+    /// Such fields are only ever sent by the server.
+    pub read_only: bool,
+    /// Whether the field is marked as `writeOnly` in the OpenRPC document.
+    ///
+    /// Such fields are only ever sent by the client.
+    pub write_only: bool,
     /// The type of the field.
     pub ty: TypeRef,
+    /// The constraints of the field's schema that can't be represented by its type, such as
+    /// `not`.
+    ///
+    /// Those are ignored for typing purposes.
+    pub constraints: Vec<String>,
+    /// The bounds on the value of the field declared by its own schema, such as `minimum` or
+    /// `maxLength`.
+    ///
+    /// Those are only checked by the generated `validate` methods.
+    pub bounds: Vec<Bound>,
+    /// The value of the `default` keyword of the field's schema, if any.
+    pub default: Option<serde_json::Value>,
     /// The original name of the field, eventually required to rename the field
     /// with `#[serde(rename = "...")]`.`
     pub name_in_json: String,
@@ -253,6 +582,20 @@ pub struct EnumDef {
     /// Whether the enumeration is a "simple" enum that should implement
     /// [`Copy`], [`PartialEq`], [`Eq`] and [`Hash`].
     pub copy: bool,
+    /// Whether the enum may also be `null`.
+    ///
+    /// References to nullable enums are wrapped in the optional template, rather than having
+    /// the enum include a variant for the `null` value.
+    pub nullable: bool,
+    /// Whether the variants of an untagged enum must be generated in the order in which they
+    /// are defined, as requested by the `x-preserve-order` extension.
+    ///
+    /// Otherwise, the most specific variants are generated first, as `serde` picks the first
+    /// variant that successfully deserializes.
+    pub preserve_order: bool,
+    /// The JSON representation of the default value of the enum, as specified by the `default`
+    /// keyword of string enums.
+    pub default: Option<String>,
 }
 
 /// Describes how an enum is represented in JSON.
@@ -285,6 +628,11 @@ pub struct EnumVariant {
     pub documentation: Option<String>,
     /// The type associated with the variant, if any.
     pub ty: Option<TypeRef>,
+    /// The index of the variant among the members of the schema defining the enum.
+    ///
+    /// Variants are stored by path, which does not preserve the order of the document (e.g.
+    /// `variant10` comes before `variant2`).
+    pub index: usize,
 }
 
 /// An alias definition.
@@ -293,3 +641,17 @@ pub struct AliasDef {
     /// The aliased type.
     pub ty: TypeRef,
 }
+
+/// A tuple struct definition, represented in JSON as an array with a fixed number of items.
+///
+/// This is created from array schemas using the `prefixItems` keyword.
+#[derive(Debug, Clone)]
+pub struct TupleDef {
+    /// The types of the items of the tuple, in order.
+    pub items: Vec<TypeRef>,
+    /// The names of the items of the tuple, derived from their `title` or from the type they
+    /// reference, and falling back to `item{i}`.
+    pub names: Vec<String>,
+    /// The documentation of the items of the tuple.
+    pub documentation: Vec<Option<String>>,
+}
@@ -0,0 +1,188 @@
+//! Generates a TypeScript declaration file mirroring the generated Rust types.
+
+use std::borrow::Cow;
+use std::io;
+
+use crate::parse::{EnumTag, File, TypeDef, TypeKind, TypeRef};
+
+/// Generates a TypeScript declaration file from the provided [`File`].
+///
+/// Only the types are generated; methods are ignored.
+pub fn gen(w: &mut dyn io::Write, file: &File) -> io::Result<()> {
+    writeln!(
+        w,
+        "\
+        //\n\
+        // This file was automatically generated by openrpc-gen.\n\
+        //\n\
+        // Do not edit it manually and instead edit either the source OpenRPC document,\n\
+        // the configuration file, or open an issue or pull request on the openrpc-gen\n\
+        // GitHub repository.\n\
+        // \n\
+        //     https://github.com/nils-mathieu/openrpc-gen\n\
+        //\n\
+        "
+    )?;
+
+    for ty in file.types.values() {
+        gen_type(w, file, ty)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the TypeScript type referenced by the provided [`TypeRef`].
+fn type_ref_name<'a>(file: &'a File, r: &'a TypeRef) -> Cow<'a, str> {
+    match r {
+        TypeRef::Array(inner, _) => Cow::Owned(format!("Array<{}>", type_ref_name(file, inner))),
+        TypeRef::Boolean => Cow::Borrowed("boolean"),
+        TypeRef::Integer {
+            format_as_hex: true,
+        } => Cow::Borrowed("string"),
+        TypeRef::Integer {
+            format_as_hex: false,
+        }
+        | TypeRef::Number => Cow::Borrowed("number"),
+        TypeRef::Null => Cow::Borrowed("null"),
+        TypeRef::String | TypeRef::EncodedJson(_) => Cow::Borrowed("string"),
+        TypeRef::Keyword(val) => Cow::Owned(string_literal(val)),
+        TypeRef::Ref(path) => match file.types.get(path) {
+            Some(ty) => Cow::Borrowed(&ty.name),
+            None => Cow::Owned(format!("unknown /* {path} */")),
+        },
+        TypeRef::ExternalRef(name) => Cow::Owned(format!("unknown /* {name} */")),
+    }
+}
+
+/// Returns a TypeScript string literal type for the provided value.
+fn string_literal(val: &str) -> String {
+    serde_json::to_string(val).unwrap()
+}
+
+/// Returns the provided property name, quoted if it is not a valid identifier.
+fn property_name(name: &str) -> Cow<'_, str> {
+    let is_ident = name.chars().enumerate().all(|(i, c)| {
+        c == '_' || c == '$' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+    });
+
+    if is_ident && !name.is_empty() {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(string_literal(name))
+    }
+}
+
+/// Writes the provided documentation as a TSDoc comment.
+fn write_doc(w: &mut dyn io::Write, indent: &str, doc: &Option<String>) -> io::Result<()> {
+    if let Some(doc) = doc {
+        writeln!(w, "{indent}/** {} */", doc.replace("*/", "*\\/"))?;
+    }
+    Ok(())
+}
+
+/// Writes the provided type.
+fn gen_type(w: &mut dyn io::Write, file: &File, ty: &TypeDef) -> io::Result<()> {
+    write_doc(w, "", &ty.documentation)?;
+    match &ty.kind {
+        TypeKind::Alias(alias) => {
+            writeln!(
+                w,
+                "export type {} = {};",
+                ty.name,
+                type_ref_name(file, &alias.ty)
+            )?;
+        }
+        TypeKind::Tuple(t) => {
+            let items = t
+                .items
+                .iter()
+                .map(|item| type_ref_name(file, item))
+                .collect::<Vec<_>>();
+            writeln!(w, "export type {} = [{}];", ty.name, items.join(", "))?;
+        }
+        TypeKind::Struct(s) => {
+            // Flattened fields cannot be expressed in an interface. They are merged with an
+            // intersection type instead.
+            let flattened = s
+                .fields
+                .values()
+                .filter(|f| f.flatten)
+                .map(|f| {
+                    let name = type_ref_name(file, &f.ty);
+                    if f.required {
+                        name.into_owned()
+                    } else {
+                        format!("Partial<{name}>")
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            if flattened.is_empty() {
+                writeln!(w, "export interface {} {{", ty.name)?;
+            } else {
+                writeln!(w, "export type {} = {{", ty.name)?;
+            }
+            for (tag, value) in &s.tags {
+                writeln!(w, "  {}: {};", property_name(tag), string_literal(value))?;
+            }
+            for field in s.fields.values().filter(|f| !f.flatten) {
+                write_doc(w, "  ", &field.documentation)?;
+                writeln!(
+                    w,
+                    "  {}{}: {};",
+                    property_name(&field.name_in_json),
+                    if field.required { "" } else { "?" },
+                    type_ref_name(file, &field.ty),
+                )?;
+            }
+            if flattened.is_empty() {
+                writeln!(w, "}}")?;
+            } else {
+                writeln!(w, "}} & {};", flattened.join(" & "))?;
+            }
+        }
+        TypeKind::Enum(e) => {
+            let variants = e
+                .variants
+                .values()
+                .map(|v| {
+                    let name_in_json = v.name_in_json.as_deref().unwrap_or(&v.name);
+                    let inner = v.ty.as_ref().map(|ty| type_ref_name(file, ty));
+                    match (&e.tag, inner) {
+                        (EnumTag::Untagged, Some(inner)) => inner.into_owned(),
+                        (EnumTag::Untagged, None) => "null".into(),
+                        (EnumTag::Tagged(tag), Some(inner)) => format!(
+                            "({{ {}: {} }} & {})",
+                            property_name(tag),
+                            string_literal(name_in_json),
+                            inner,
+                        ),
+                        (EnumTag::Tagged(tag), None) => format!(
+                            "{{ {}: {} }}",
+                            property_name(tag),
+                            string_literal(name_in_json),
+                        ),
+                        (EnumTag::Normal, Some(inner)) => {
+                            format!("{{ {}: {} }}", property_name(name_in_json), inner)
+                        }
+                        (EnumTag::Normal, None) => string_literal(name_in_json),
+                    }
+                })
+                .chain(e.nullable.then(|| "null".to_owned()))
+                .collect::<Vec<_>>();
+
+            if variants.is_empty() {
+                writeln!(w, "export type {} = never;", ty.name)?;
+            } else {
+                writeln!(w, "export type {} =", ty.name)?;
+                for (i, variant) in variants.iter().enumerate() {
+                    let end = if i + 1 == variants.len() { ";" } else { "" };
+                    writeln!(w, "  | {variant}{end}")?;
+                }
+            }
+        }
+    }
+    writeln!(w)?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub number: BlockNumber,
+    pub transactions: Hashes,
+}
+
+/// The height of a block.
+///
+/// Alias for `i64`.
+pub type BlockNumber = i64;
+
+/// Alias for `Vec<String>`.
+pub type Hashes = Vec<String>;
+
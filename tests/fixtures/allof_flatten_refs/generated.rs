@@ -0,0 +1,27 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Base {
+    pub id: String,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transfer {
+    #[serde(flatten)]
+    pub base: Base,
+    pub amount: i64,
+    #[serde(default)]
+    pub memo: Option<String>,
+}
+
@@ -0,0 +1,52 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub header: Anon5c9329,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Anon5c9329 {
+    pub number: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Receipt {
+    pub header: Anon5c93292,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Anon5c93292 {
+    pub number: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transaction {
+    pub origin: AnonA2d556,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnonA2d556 {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Result type of `getBlock`.
+pub type GetBlockResult = Block;
+
+/// Result type of `getReceipt`.
+pub type GetReceiptResult = Receipt;
+
+/// Result type of `getTransaction`.
+pub type GetTransactionResult = Transaction;
+
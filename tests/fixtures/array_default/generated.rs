@@ -0,0 +1,35 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Point {
+    pub x: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Settings {
+    #[serde(default = "default_settings_levels")]
+    pub levels: Vec<i64>,
+    #[serde(default)]
+    pub points: Vec<Point>,
+    #[serde(default = "default_settings_tags")]
+    pub tags: Vec<String>,
+}
+
+fn default_settings_levels() -> Vec<i64> {
+    [1, 2, 3].into_iter().collect()
+}
+
+fn default_settings_tags() -> Vec<String> {
+    ["a".into()].into_iter().collect()
+}
+
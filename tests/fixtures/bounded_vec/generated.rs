@@ -0,0 +1,70 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+/// A `Vec` holding at most `N` items.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct BoundedVec<T, const N: usize>(Vec<T>);
+
+#[allow(dead_code)]
+impl<T, const N: usize> BoundedVec<T, N> {
+    /// Creates a new [`BoundedVec`], or gives the provided vector back if it holds more
+    /// than `N` items.
+    pub fn new(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        if vec.len() <= N {
+            Ok(Self(vec))
+        } else {
+            Err(vec)
+        }
+    }
+
+    /// Returns the inner vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T, const N: usize> std::ops::Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for BoundedVec<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        Self::new(vec).map_err(|vec| {
+            serde::de::Error::custom(format_args!(
+                "expected at most {N} items, found {}",
+                vec.len()
+            ))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Batch {
+    pub hashes: BoundedVec<String, 3>,
+    #[serde(default)]
+    pub notes: Option<Vec<String>>,
+    #[serde(default)]
+    pub tags: Option<BoundedVec<i64, 2>>,
+}
+
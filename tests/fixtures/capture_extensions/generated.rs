@@ -0,0 +1,66 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[allow(dead_code)]
+mod vendor_extensions {
+    use std::collections::HashMap;
+
+    use serde::de::{IgnoredAny, MapAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+    use serde_json::Value;
+
+    pub fn serialize<S>(value: &HashMap<String, Value>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<HashMap<String, Value>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Extensions;
+
+        impl<'de> Visitor<'de> for Extensions {
+            type Value = HashMap<String, Value>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut extensions = HashMap::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    if key.starts_with("x-") {
+                        extensions.insert(key, map.next_value()?);
+                    } else {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+                Ok(extensions)
+            }
+        }
+
+        deserializer.deserialize_map(Extensions)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub hash: String,
+    pub number: i64,
+    /// The vendor extensions (`x-` properties) of the object.
+    #[serde(flatten, with = "vendor_extensions")]
+    pub extensions: std::collections::HashMap<String, serde_json::Value>,
+}
+
@@ -0,0 +1,78 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockHeight {
+    pub height: i64,
+}
+
+impl BlockHeight {
+    /// Creates a new [`BlockHeight`] from its inner value.
+    #[inline]
+    pub const fn new(value: i64) -> Self {
+        Self { height: value }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[serde(transparent)]
+pub struct Enabled(pub bool);
+
+impl Enabled {
+    /// Creates a new [`Enabled`] from its inner value.
+    #[inline]
+    pub const fn new(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "(i64,)", into = "(i64,)")]
+pub struct Gas(pub i64);
+
+impl From<(i64,)> for Gas {
+    fn from((item,): (i64,)) -> Self {
+        Self(item)
+    }
+}
+
+impl From<Gas> for (i64,) {
+    fn from(value: Gas) -> Self {
+        (value.0,)
+    }
+}
+
+impl Gas {
+    /// Creates a new [`Gas`] from its inner value.
+    #[inline]
+    pub const fn new(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Name {
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Copy, PartialEq, Eq, Hash)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pending,
+    Accepted,
+}
+
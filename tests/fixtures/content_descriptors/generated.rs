@@ -0,0 +1,171 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub number: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Filter {
+    #[serde(default)]
+    pub finalized: Option<bool>,
+}
+
+/// The requested block.
+///
+/// Result type of `getBlock`.
+pub type GetBlockResult = Block;
+
+/// Parameters of the `getBlock` method.
+#[derive(Debug, Clone)]
+pub struct GetBlockParams {
+}
+
+impl Serialize for GetBlockParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBlockParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBlock`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(1, &"expected 0 parameters"));
+                }
+
+                Ok(GetBlockParams {
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBlockParams {
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// The requested block.
+///
+/// Result type of `getLatestBlock`.
+pub type GetLatestBlockResult = Block;
+
+/// Parameters of the `getLatestBlock` method.
+#[derive(Debug, Clone)]
+pub struct GetLatestBlockParams {
+    pub filter: Option<Filter>,
+}
+
+impl Serialize for GetLatestBlockParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("filter", &self.filter)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetLatestBlockParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetLatestBlockParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getLatestBlock`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let filter: Option<Filter> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 1 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &"expected 1 parameters"));
+                }
+
+                Ok(GetLatestBlockParams {
+                    filter,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    #[serde(default)]
+                    filter: Option<Filter>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetLatestBlockParams {
+                    filter: helper.filter,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
@@ -0,0 +1,146 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+/// Parameters of the `getBalance` method.
+#[derive(Debug, Clone)]
+pub struct GetBalanceParams {
+    pub address: String,
+    pub block: Option<i64>,
+}
+
+impl Serialize for GetBalanceParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("address", &self.address)?;
+        map.serialize_entry("block", &self.block)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBalanceParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBalanceParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBalance`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let address: String = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 2 parameters"))?;
+                let block: Option<i64> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 2 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(3, &"expected 2 parameters"));
+                }
+
+                Ok(GetBalanceParams {
+                    address,
+                    block,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    address: String,
+                    #[serde(default)]
+                    block: Option<i64>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBalanceParams {
+                    address: helper.address,
+                    block: helper.block,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_seq(Visitor)
+    }
+}
+
+/// Parameters of the `getAccount` method.
+#[derive(Debug, Clone)]
+pub struct GetAccountParams {
+    pub address: String,
+}
+
+impl Serialize for GetAccountParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("address", &self.address)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetAccountParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetAccountParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getAccount`")
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    address: String,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetAccountParams {
+                    address: helper.address,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_map(Visitor)
+    }
+}
+
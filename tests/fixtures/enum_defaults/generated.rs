@@ -0,0 +1,97 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+#[derive(Serialize, Deserialize)]
+#[derive(Copy, PartialEq, Eq, Hash)]
+#[derive(Default)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockTag {
+    Earliest,
+    #[default]
+    Latest,
+    Pending,
+}
+
+/// Parameters of the `getBlock` method.
+#[derive(Debug, Clone)]
+pub struct GetBlockParams {
+    pub tag: BlockTag,
+}
+
+impl Serialize for GetBlockParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("tag", &self.tag)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBlockParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBlock`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let tag: BlockTag = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 1 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &"expected 1 parameters"));
+                }
+
+                Ok(GetBlockParams {
+                    tag,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    tag: BlockTag,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBlockParams {
+                    tag: helper.tag,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
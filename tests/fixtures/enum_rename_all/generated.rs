@@ -0,0 +1,38 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize)]
+#[derive(Copy, PartialEq, Eq, Hash)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Status {
+    NotReceived,
+    Received,
+    Pending,
+    AcceptedOnL2,
+    AcceptedOnL1,
+    Rejected,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Copy, PartialEq, Eq, Hash)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Version {
+    Latest,
+    Stable,
+    #[serde(rename = "1")]
+    V1,
+}
+
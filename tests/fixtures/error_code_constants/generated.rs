@@ -0,0 +1,88 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+/// An error that may be returned by a method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// invalid block number
+    InvalidBlockNumber,
+    /// block not found
+    BlockNotFound,
+}
+
+impl Error {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::InvalidBlockNumber => -32602,
+            Self::BlockNotFound => 4001,
+        }
+    }
+
+    /// Returns the message of this error, as declared in the OpenRPC document.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::InvalidBlockNumber => "invalid block number",
+            Self::BlockNotFound => "block not found",
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code())?;
+        map.serialize_entry("message", self.message())?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            code: i64,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.code {
+            -32602 => Ok(Self::InvalidBlockNumber),
+            4001 => Ok(Self::BlockNotFound),
+            code => Err(serde::de::Error::custom(format_args!("unknown error code: {code}"))),
+        }
+    }
+}
+
+/// The codes of the errors that methods may return.
+pub mod error_codes {
+    /// Invalid JSON was received by the server.
+    pub const PARSE_ERROR: i64 = -32700;
+    /// The JSON sent is not a valid request object.
+    pub const INVALID_REQUEST: i64 = -32600;
+    /// The method does not exist or is not available.
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    /// Internal JSON-RPC error.
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// invalid block number
+    pub const INVALID_BLOCK_NUMBER: i64 = -32602;
+    /// block not found
+    pub const BLOCK_NOT_FOUND: i64 = 4001;
+}
+
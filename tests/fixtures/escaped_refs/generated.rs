@@ -0,0 +1,153 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockHeader {
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AB(pub i64, pub i64);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PkgBlock {
+    pub header: BlockHeader,
+    pub range: AB,
+}
+
+/// An error that may be returned by a method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// not found
+    RpcNotFound,
+}
+
+impl Error {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::RpcNotFound => 1,
+        }
+    }
+
+    /// Returns the message of this error, as declared in the OpenRPC document.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::RpcNotFound => "not found",
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code())?;
+        map.serialize_entry("message", self.message())?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            code: i64,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.code {
+            1 => Ok(Self::RpcNotFound),
+            code => Err(serde::de::Error::custom(format_args!("unknown error code: {code}"))),
+        }
+    }
+}
+
+/// Parameters of the `getBlock` method.
+#[derive(Debug, Clone)]
+pub struct GetBlockParams {
+    pub number: i64,
+}
+
+impl Serialize for GetBlockParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("number", &self.number)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBlockParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBlock`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let number: i64 = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 1 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &"expected 1 parameters"));
+                }
+
+                Ok(GetBlockParams {
+                    number,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    number: i64,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBlockParams {
+                    number: helper.number,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
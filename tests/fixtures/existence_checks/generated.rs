@@ -0,0 +1,43 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+pub type Hash = String;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Range(pub i64, pub i64);
+
+#[derive(Serialize, Deserialize)]
+#[derive(Copy, PartialEq, Eq, Hash)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pending,
+    Final,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockResult {
+    pub hash: Hash,
+    pub range: Range,
+    pub status: Status,
+}
+
+/// Checks that every type defined by the document exists.
+#[cfg(test)]
+const _: fn() = || {
+    let _: Option<Hash> = None;
+    let _: Option<Range> = None;
+    let _: Option<Status> = None;
+    let _: Option<BlockResult> = None;
+};
+
@@ -0,0 +1,60 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.x.to_bits() == other.x.to_bits()
+            && self.y.to_bits() == other.y.to_bits()
+    }
+}
+
+impl Eq for Point {}
+
+impl std::hash::Hash for Point {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.x.to_bits(), state);
+        std::hash::Hash::hash(&self.y.to_bits(), state);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Segment {
+    pub end: Point,
+    pub start: Point,
+    #[serde(default)]
+    pub weights: Option<Vec<f64>>,
+}
+
+impl PartialEq for Segment {
+    fn eq(&self, other: &Self) -> bool {
+        self.end == other.end
+            && self.start == other.start
+            && self.weights.as_ref().map(|v| v.iter().map(|v| v.to_bits()).collect::<Vec<_>>()) == other.weights.as_ref().map(|v| v.iter().map(|v| v.to_bits()).collect::<Vec<_>>())
+    }
+}
+
+impl Eq for Segment {}
+
+impl std::hash::Hash for Segment {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.end, state);
+        std::hash::Hash::hash(&self.start, state);
+        std::hash::Hash::hash(&self.weights.as_ref().map(|v| v.iter().map(|v| v.to_bits()).collect::<Vec<_>>()), state);
+    }
+}
+
@@ -0,0 +1,46 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Block {
+    pub block_hash: String,
+    pub block_number: i64,
+    #[serde(rename = "parent_hash")]
+    pub parent_hash: String,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+// Variants are tried in order: Transfer, Mint.
+#[serde(untagged)]
+pub enum Event {
+    Transfer(Transfer),
+    Mint(Mint),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transfer {
+    pub from_address: String,
+    pub to_address: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Mint {
+    pub mint_amount: i64,
+    pub token_id: String,
+}
+
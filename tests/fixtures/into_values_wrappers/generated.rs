@@ -0,0 +1,246 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeSeq;
+
+#[allow(dead_code)]
+mod json_string {
+    use serde::de::DeserializeOwned;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        let json = serde_json::to_string(value).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        let json = String::deserialize(deserializer)?;
+        serde_json::from_str(&json).map_err(serde::de::Error::custom)
+    }
+
+    pub mod option {
+        use serde::de::DeserializeOwned;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Serialize,
+            S: Serializer,
+        {
+            match value {
+                Some(value) => super::serialize(value, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            T: DeserializeOwned,
+            D: Deserializer<'de>,
+        {
+            match Option::<String>::deserialize(deserializer)? {
+                Some(json) => serde_json::from_str(&json).map(Some).map_err(serde::de::Error::custom),
+                None => Ok(None),
+            }
+        }
+    }
+}
+
+/// Mirrors [`crate::into_values_wrappers::Point`] to (de)serialize it.
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "crate::into_values_wrappers::Point")]
+pub struct PointDef {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[allow(dead_code)]
+mod point_def_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    struct Ref<'a>(&'a crate::into_values_wrappers::Point);
+
+    impl Serialize for Ref<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::PointDef::serialize(self.0, serializer)
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Owned(#[serde(with = "super::PointDef")] crate::into_values_wrappers::Point);
+
+    pub fn serialize<S>(value: &Option<crate::into_values_wrappers::Point>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.as_ref().map(Ref).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<crate::into_values_wrappers::Point>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Owned>::deserialize(deserializer)?.map(|v| v.0))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Payload {
+    pub amount: i64,
+}
+
+/// Parameters of the `move` method.
+#[derive(Debug, Clone)]
+pub struct MoveParams {
+    pub to: crate::into_values_wrappers::Point,
+    pub payload: Payload,
+    pub via: Option<crate::into_values_wrappers::Point>,
+    pub speed: Option<i64>,
+}
+
+impl Serialize for MoveParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        struct Param0<'a>(&'a crate::into_values_wrappers::Point);
+
+        impl Serialize for Param0<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                PointDef::serialize(self.0, serializer)
+            }
+        }
+
+        struct Param1<'a>(&'a Payload);
+
+        impl Serialize for Param1<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                json_string::serialize(self.0, serializer)
+            }
+        }
+
+        struct Param2<'a>(&'a Option<crate::into_values_wrappers::Point>);
+
+        impl Serialize for Param2<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                point_def_option::serialize(self.0, serializer)
+            }
+        }
+
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element(&Param0(&self.to))?;
+        seq.serialize_element(&Param1(&self.payload))?;
+        seq.serialize_element(&Param2(&self.via))?;
+        seq.serialize_element(&self.speed)?;
+        seq.end()
+    }
+}
+
+impl MoveParams {
+    /// Returns the parameters as a list of JSON values, in the order expected by `move`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if one of the parameters cannot be represented as JSON.
+    pub fn into_values(self) -> Vec<serde_json::Value> {
+        struct Param0<'a>(&'a crate::into_values_wrappers::Point);
+
+        impl Serialize for Param0<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                PointDef::serialize(self.0, serializer)
+            }
+        }
+
+        struct Param1<'a>(&'a Payload);
+
+        impl Serialize for Param1<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                json_string::serialize(self.0, serializer)
+            }
+        }
+
+        struct Param2<'a>(&'a Option<crate::into_values_wrappers::Point>);
+
+        impl Serialize for Param2<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                point_def_option::serialize(self.0, serializer)
+            }
+        }
+
+        vec![
+            serde_json::to_value(Param0(&self.to)).expect("failed to serialize `to`"),
+            serde_json::to_value(Param1(&self.payload)).expect("failed to serialize `payload`"),
+            serde_json::to_value(Param2(&self.via)).expect("failed to serialize `via`"),
+            serde_json::to_value(self.speed).expect("failed to serialize `speed`"),
+        ]
+    }
+}
+
+impl<'de> Deserialize<'de> for MoveParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = MoveParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `move`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                #[serde(transparent)]
+                struct Param0(#[serde(with = "PointDef")] crate::into_values_wrappers::Point);
+                let to: crate::into_values_wrappers::Point = seq.next_element::<Param0>()?.map(|param| param.0).ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 4 parameters"))?;
+                #[derive(Deserialize)]
+                #[serde(transparent)]
+                struct Param1(#[serde(with = "json_string")] Payload);
+                let payload: Payload = seq.next_element::<Param1>()?.map(|param| param.0).ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 4 parameters"))?;
+                #[derive(Deserialize)]
+                #[serde(transparent)]
+                struct Param2(#[serde(with = "point_def_option")] Option<crate::into_values_wrappers::Point>);
+                let via: Option<crate::into_values_wrappers::Point> = seq.next_element::<Param2>()?.map(|param| param.0).ok_or_else(|| serde::de::Error::invalid_length(3, &"expected 4 parameters"))?;
+                let speed: Option<i64> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(4, &"expected 4 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(5, &"expected 4 parameters"));
+                }
+
+                Ok(MoveParams {
+                    to,
+                    payload,
+                    via,
+                    speed,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_seq(Visitor)
+    }
+}
+
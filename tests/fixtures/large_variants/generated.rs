@@ -0,0 +1,73 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+// Variants are tried in order: Receipt, Block, Tick.
+#[serde(untagged)]
+pub enum Event {
+    Receipt(Receipt),
+    Block(Header),
+    Tick(i64),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    pub a: String,
+    pub b: String,
+    pub c: String,
+    pub d: String,
+    pub e: String,
+    pub f: String,
+    pub g: String,
+    pub h: String,
+    pub i: String,
+    pub j: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+// Variants are tried in order: Number, Name.
+#[serde(untagged)]
+pub enum Id {
+    Number(i64),
+    Name(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Receipt {
+    pub a1: String,
+    pub a2: String,
+    pub b1: String,
+    pub b2: String,
+    pub c1: String,
+    pub c2: String,
+    pub d1: String,
+    pub d2: String,
+    pub e1: String,
+    pub e2: String,
+    pub f1: String,
+    pub f2: String,
+    pub g1: String,
+    pub g2: String,
+    pub h1: String,
+    pub h2: String,
+    pub i1: String,
+    pub i2: String,
+    pub j1: String,
+    pub j2: String,
+    pub k1: String,
+}
+
@@ -0,0 +1,165 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+#[allow(dead_code)]
+mod lenient_bool {
+    use serde::de::{Error, Unexpected, Visitor};
+    use serde::Deserializer;
+
+    struct LenientBool;
+
+    impl<'de> Visitor<'de> for LenientBool {
+        type Value = bool;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a boolean, \"true\", \"false\", 0 or 1")
+        }
+
+        fn visit_bool<E: Error>(self, v: bool) -> Result<bool, E> {
+            Ok(v)
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<bool, E> {
+            match v {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+            }
+        }
+
+        fn visit_u64<E: Error>(self, v: u64) -> Result<bool, E> {
+            match v {
+                0 => Ok(false),
+                1 => Ok(true),
+                _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+            }
+        }
+
+        fn visit_i64<E: Error>(self, v: i64) -> Result<bool, E> {
+            match v {
+                0 => Ok(false),
+                1 => Ok(true),
+                _ => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+            }
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<bool>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(LenientBool).map(T::from)
+    }
+
+    pub mod option {
+        use serde::{Deserialize, Deserializer};
+
+        #[derive(Deserialize)]
+        struct Lenient(#[serde(deserialize_with = "super::deserialize")] bool);
+
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            T: From<bool>,
+            D: Deserializer<'de>,
+        {
+            Ok(Option::<Lenient>::deserialize(deserializer)?.map(|b| T::from(b.0)))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    #[serde(deserialize_with = "lenient_bool::deserialize")]
+    pub finalized: bool,
+    #[serde(default)]
+    #[serde(deserialize_with = "lenient_bool::option::deserialize")]
+    pub pending: Option<bool>,
+}
+
+/// Parameters of the `getBlock` method.
+#[derive(Debug, Clone)]
+pub struct GetBlockParams {
+    pub full: Option<bool>,
+}
+
+impl Serialize for GetBlockParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("full", &self.full)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBlockParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBlock`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                #[serde(transparent)]
+                struct Param0(#[serde(deserialize_with = "lenient_bool::option::deserialize")] Option<bool>);
+                let full: Option<bool> = seq.next_element::<Param0>()?.map(|param| param.0).ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 1 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &"expected 1 parameters"));
+                }
+
+                Ok(GetBlockParams {
+                    full,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    #[serde(default)]
+                    #[serde(deserialize_with = "lenient_bool::option::deserialize")]
+                    full: Option<bool>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBlockParams {
+                    full: helper.full,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
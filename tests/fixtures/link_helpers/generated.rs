@@ -0,0 +1,208 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub header: Header,
+    pub number: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    #[serde(rename = "parentHash")]
+    pub parenthash: String,
+}
+
+/// Parameters of the `getBlock` method.
+#[derive(Debug, Clone)]
+pub struct GetBlockParams {
+    pub hash: String,
+}
+
+impl Serialize for GetBlockParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("hash", &self.hash)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBlockParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBlock`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let hash: String = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 1 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &"expected 1 parameters"));
+                }
+
+                Ok(GetBlockParams {
+                    hash,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    hash: String,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBlockParams {
+                    hash: helper.hash,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// Parameters of the `getBlockByNumber` method.
+#[derive(Debug, Clone)]
+pub struct GetBlockByNumberParams {
+    pub number: i64,
+    pub full: Option<bool>,
+}
+
+impl Serialize for GetBlockByNumberParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("number", &self.number)?;
+        map.serialize_entry("full", &self.full)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockByNumberParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBlockByNumberParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBlockByNumber`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let number: i64 = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 2 parameters"))?;
+                let full: Option<bool> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 2 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(3, &"expected 2 parameters"));
+                }
+
+                Ok(GetBlockByNumberParams {
+                    number,
+                    full,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    number: i64,
+                    #[serde(default)]
+                    full: Option<bool>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBlockByNumberParams {
+                    number: helper.number,
+                    full: helper.full,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+impl GetBlockParams {
+    /// The parent of the block.
+    ///
+    /// Builds the parameters of `getBlock` from the result of `getBlock`, following the `parent` link.
+    #[allow(unused_variables)]
+    pub fn from_parent(result: &Block) -> Self {
+        Self {
+            hash: result.header.parenthash.clone(),
+        }
+    }
+}
+
+impl GetBlockByNumberParams {
+    /// Builds the parameters of `getBlockByNumber` from the result of `getBlock`, following the `by number` link.
+    #[allow(unused_variables)]
+    pub fn from_by_number(result: &Block) -> Self {
+        Self {
+            number: result.number,
+            full: Some(true),
+        }
+    }
+}
+
+impl GetBlockByNumberParams {
+    /// Builds the parameters of `getBlockByNumber` from the result of `getBlock`, following the `genesis` link.
+    #[allow(unused_variables)]
+    pub fn from_genesis(result: &Block) -> Self {
+        Self {
+            number: 0,
+            full: None,
+        }
+    }
+}
+
@@ -0,0 +1,180 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+/// `eth_blockNumber`
+pub const ETH_BLOCK_NUMBER: &str = "eth_blockNumber";
+
+/// `blockNumber`, an alias of `eth_blockNumber`
+pub const BLOCK_NUMBER: &str = "blockNumber";
+
+/// Parameters of the `eth_blockNumber` method.
+#[derive(Debug, Clone)]
+pub struct EthBlockNumberParams {
+}
+
+impl Serialize for EthBlockNumberParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for EthBlockNumberParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = EthBlockNumberParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `eth_blockNumber`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(1, &"expected 0 parameters"));
+                }
+
+                Ok(EthBlockNumberParams {
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(EthBlockNumberParams {
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// `eth_chainId`
+pub const ETH_CHAIN_ID: &str = "eth_chainId";
+
+/// Parameters of the `eth_chainId` method.
+#[derive(Debug, Clone)]
+pub struct EthChainIdParams {
+}
+
+impl Serialize for EthChainIdParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for EthChainIdParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = EthChainIdParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `eth_chainId`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(1, &"expected 0 parameters"));
+                }
+
+                Ok(EthChainIdParams {
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(EthChainIdParams {
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// A method of the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// `eth_blockNumber`
+    EthBlockNumber,
+    /// `eth_chainId`
+    EthChainId,
+}
+
+impl Method {
+    /// Returns the method with the provided name, which may be an alias.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "eth_blockNumber" | "blockNumber" => Some(Self::EthBlockNumber),
+            "eth_chainId" => Some(Self::EthChainId),
+            _ => None,
+        }
+    }
+
+    /// Returns the canonical name of the method.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::EthBlockNumber => "eth_blockNumber",
+            Self::EthChainId => "eth_chainId",
+        }
+    }
+}
+
@@ -0,0 +1,154 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+/// An error that may be returned by a method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// block not found
+    BlockNotFound,
+    /// not listening
+    NotListening,
+    /// unauthorized
+    Unauthorized,
+}
+
+impl Error {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::BlockNotFound => 1,
+            Self::NotListening => 2,
+            Self::Unauthorized => 3,
+        }
+    }
+
+    /// Returns the message of this error, as declared in the OpenRPC document.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::BlockNotFound => "block not found",
+            Self::NotListening => "not listening",
+            Self::Unauthorized => "unauthorized",
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code())?;
+        map.serialize_entry("message", self.message())?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            code: i64,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.code {
+            1 => Ok(Self::BlockNotFound),
+            2 => Ok(Self::NotListening),
+            3 => Ok(Self::Unauthorized),
+            code => Err(serde::de::Error::custom(format_args!("unknown error code: {code}"))),
+        }
+    }
+}
+
+/// An error that may be returned by the `getBlock` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GetBlockError {
+    /// block not found
+    BlockNotFound,
+    /// unauthorized
+    Unauthorized,
+}
+
+impl GetBlockError {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::BlockNotFound => 1,
+            Self::Unauthorized => 3,
+        }
+    }
+}
+
+impl From<GetBlockError> for Error {
+    fn from(error: GetBlockError) -> Self {
+        match error {
+            GetBlockError::BlockNotFound => Self::BlockNotFound,
+            GetBlockError::Unauthorized => Self::Unauthorized,
+        }
+    }
+}
+
+impl TryFrom<Error> for GetBlockError {
+    type Error = Error;
+
+    /// Returns the provided error back if the method does not declare it.
+    fn try_from(error: Error) -> Result<Self, Error> {
+        match error {
+            Error::BlockNotFound => Ok(Self::BlockNotFound),
+            Error::Unauthorized => Ok(Self::Unauthorized),
+            error => Err(error),
+        }
+    }
+}
+
+/// An error that may be returned by the `getPeers` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GetPeersError {
+    /// not listening
+    NotListening,
+}
+
+impl GetPeersError {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::NotListening => 2,
+        }
+    }
+}
+
+impl From<GetPeersError> for Error {
+    fn from(error: GetPeersError) -> Self {
+        match error {
+            GetPeersError::NotListening => Self::NotListening,
+        }
+    }
+}
+
+impl TryFrom<Error> for GetPeersError {
+    type Error = Error;
+
+    /// Returns the provided error back if the method does not declare it.
+    fn try_from(error: Error) -> Result<Self, Error> {
+        match error {
+            Error::NotListening => Ok(Self::NotListening),
+            error => Err(error),
+        }
+    }
+}
+
@@ -0,0 +1,217 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+/// Parameters of the `getBalance` method.
+#[derive(Debug, Clone)]
+pub struct GetBalanceParams {
+    pub address: String,
+}
+
+impl Serialize for GetBalanceParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("address", &self.address)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBalanceParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBalanceParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBalance`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let address: String = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 1 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &"expected 1 parameters"));
+                }
+
+                Ok(GetBalanceParams {
+                    address,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    address: String,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBalanceParams {
+                    address: helper.address,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// Parameters of the `notify` method.
+#[derive(Debug, Clone)]
+pub struct NotifyParams {
+}
+
+impl Serialize for NotifyParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for NotifyParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = NotifyParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `notify`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(1, &"expected 0 parameters"));
+                }
+
+                Ok(NotifyParams {
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(NotifyParams {
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// A method of the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// `getBalance`
+    GetBalance,
+    /// `notify`
+    Notify,
+}
+
+impl Method {
+    /// Returns the method with the provided name, which may be an alias.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "getBalance" => Some(Self::GetBalance),
+            "notify" => Some(Self::Notify),
+            _ => None,
+        }
+    }
+
+    /// Returns the canonical name of the method.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::GetBalance => "getBalance",
+            Self::Notify => "notify",
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A method of the API, tied to its parameter and result types.
+///
+/// This trait is sealed and implemented by one unit type per method.
+pub trait RpcMethod: sealed::Sealed {
+    /// The variant of [`Method`] describing the method.
+    const METHOD: Method;
+    /// The parameters of the method.
+    type Params;
+    /// The result of the method.
+    type Result;
+}
+
+/// The `getBalance` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GetBalanceMethod;
+
+impl sealed::Sealed for GetBalanceMethod {}
+
+impl RpcMethod for GetBalanceMethod {
+    const METHOD: Method = Method::GetBalance;
+    type Params = GetBalanceParams;
+    type Result = i64;
+}
+
+/// The `notify` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NotifyMethod;
+
+impl sealed::Sealed for NotifyMethod {}
+
+impl RpcMethod for NotifyMethod {
+    const METHOD: Method = Method::Notify;
+    type Params = NotifyParams;
+    type Result = ();
+}
+
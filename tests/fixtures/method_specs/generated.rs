@@ -0,0 +1,76 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub hash: String,
+    pub number: i64,
+}
+
+/// The structure in which the parameters of a method are passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamStructure {
+    /// Parameters are passed as an object.
+    ByName,
+    /// Parameters are passed as an array.
+    ByPosition,
+    /// Parameters may be passed either way.
+    Either,
+}
+
+/// Describes a method of the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MethodSpec {
+    /// The canonical name of the method.
+    pub name: &'static str,
+    /// The other names under which the method is available.
+    pub aliases: &'static [&'static str],
+    /// The structure in which the parameters are passed.
+    pub param_structure: ParamStructure,
+    /// The number of parameters that must be provided.
+    pub required_params: usize,
+    /// The total number of parameters.
+    pub params: usize,
+    /// Whether the method is a notification, which does not return a result.
+    pub notification: bool,
+}
+
+/// Returns the specification of every method of the API.
+pub fn method_specs() -> &'static [MethodSpec] {
+    &[
+        MethodSpec {
+            name: "getBlock",
+            aliases: &[],
+            param_structure: ParamStructure::ByName,
+            required_params: 1,
+            params: 1,
+            notification: false,
+        },
+        MethodSpec {
+            name: "getBalance",
+            aliases: &[],
+            param_structure: ParamStructure::ByPosition,
+            required_params: 1,
+            params: 2,
+            notification: false,
+        },
+        MethodSpec {
+            name: "notify",
+            aliases: &[],
+            param_structure: ParamStructure::Either,
+            required_params: 0,
+            params: 0,
+            notification: true,
+        },
+    ]
+}
+
@@ -0,0 +1,129 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub hash: crate::module_path::generated::Hash,
+    pub parents: Vec<crate::module_path::generated::Hash>,
+}
+
+pub type Hash = String;
+
+/// Parameters of the `getBlock` method.
+#[derive(Debug, Clone)]
+pub struct GetBlockParams {
+    pub hash: crate::module_path::generated::Hash,
+    pub full: Option<bool>,
+}
+
+impl Serialize for GetBlockParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("hash", &self.hash)?;
+        map.serialize_entry("full", &self.full)?;
+        map.end()
+    }
+}
+
+/// Builds a [`GetBlockParams`] from `key: value` pairs.
+///
+/// Omitted optional parameters are set to their default value.
+#[allow(unused_macros)]
+macro_rules! get_block_params {
+    (@munch { $($acc:tt)* } [full $f0:tt] full : $value:expr $(, $($rest:tt)*)?) => {
+        crate::module_path::generated::get_block_params!(@munch { $($acc)* full: From::from($value), } [full !] $($($rest)*)?)
+    };
+    (@munch { $($acc:tt)* } $state:tt $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        crate::module_path::generated::get_block_params!(@munch { $($acc)* $key: $value, } $state $($($rest)*)?)
+    };
+    (@munch { $($acc:tt)* } $state:tt) => {
+        crate::module_path::generated::get_block_params!(@fill { $($acc)* } $state)
+    };
+    (@fill { $($acc:tt)* } [$param:ident _ $($rest:tt)*]) => {
+        crate::module_path::generated::get_block_params!(@fill { $($acc)* $param: Default::default(), } [$($rest)*])
+    };
+    (@fill { $($acc:tt)* } [$param:ident ! $($rest:tt)*]) => {
+        crate::module_path::generated::get_block_params!(@fill { $($acc)* } [$($rest)*])
+    };
+    (@fill { $($acc:tt)* } []) => {
+        crate::module_path::generated::GetBlockParams { $($acc)* }
+    };
+    ($($key:ident : $value:expr),* $(,)?) => {
+        crate::module_path::generated::get_block_params!(@munch {} [full _] $($key : $value),*)
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use get_block_params;
+
+impl<'de> Deserialize<'de> for GetBlockParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBlockParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBlock`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let hash: crate::module_path::generated::Hash = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 2 parameters"))?;
+                let full: Option<bool> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 2 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(3, &"expected 2 parameters"));
+                }
+
+                Ok(GetBlockParams {
+                    hash,
+                    full,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    hash: crate::module_path::generated::Hash,
+                    #[serde(default)]
+                    full: Option<bool>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBlockParams {
+                    hash: helper.hash,
+                    full: helper.full,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
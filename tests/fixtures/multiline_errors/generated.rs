@@ -0,0 +1,203 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+/// An error that may be returned by a method.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// block not found
+    ///
+    /// The block may have been pruned.
+    ///     Unknown,
+    BlockNotFound,
+    /// An error whose code is not declared in the OpenRPC document.
+    Unknown {
+        /// The code of the error.
+        code: i64,
+        /// The message of the error.
+        message: String,
+        /// Additional information about the error.
+        data: Option<serde_json::Value>,
+    },
+}
+
+impl Error {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::BlockNotFound => 1,
+            Self::Unknown { code, .. } => *code,
+        }
+    }
+
+    /// Returns the message of this error, as declared in the OpenRPC document.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::BlockNotFound => "block not found\n\nThe block may have been pruned.\n    Unknown,",
+            Self::Unknown { message, .. } => message,
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code())?;
+        map.serialize_entry("message", self.message())?;
+        if let Self::Unknown { data: Some(data), .. } = self {
+            map.serialize_entry("data", data)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            code: i64,
+            #[serde(default)]
+            message: String,
+            #[serde(default)]
+            data: Option<serde_json::Value>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.code {
+            1 => Ok(Self::BlockNotFound),
+            code => Ok(Self::Unknown {
+                code,
+                message: helper.message,
+                data: helper.data,
+            }),
+        }
+    }
+}
+
+/// The codes of the errors that methods may return.
+pub mod error_codes {
+    /// Invalid JSON was received by the server.
+    pub const PARSE_ERROR: i64 = -32700;
+    /// The JSON sent is not a valid request object.
+    pub const INVALID_REQUEST: i64 = -32600;
+    /// The method does not exist or is not available.
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    /// Invalid method parameters.
+    pub const INVALID_PARAMS: i64 = -32602;
+    /// Internal JSON-RPC error.
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// block not found
+    ///
+    /// The block may have been pruned.
+    ///     Unknown,
+    pub const BLOCK_NOT_FOUND: i64 = 1;
+}
+
+/// Result type of `getBlock`.
+pub type GetBlockResult = String;
+
+/// The outcome of a call to `getBlock`, deserialized from a JSON-RPC response.
+#[derive(Debug, Clone)]
+pub enum GetBlockOutcome {
+    /// The call succeeded.
+    Ok(GetBlockResult),
+    /// block not found
+    ///
+    /// The block may have been pruned.
+    ///     Unknown,
+    BlockNotFound,
+    /// An error that is not declared for this method.
+    Other(Error),
+}
+
+impl GetBlockOutcome {
+    /// Converts this outcome into a [`Result`], using the [`Error`] type for errors.
+    pub fn into_result(self) -> Result<GetBlockResult, Error> {
+        match self {
+            Self::Ok(result) => Ok(result),
+            Self::BlockNotFound => Err(Error::BlockNotFound),
+            Self::Other(error) => Err(error),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockOutcome {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            #[serde(default)]
+            result: serde_json::Value,
+            #[serde(default)]
+            error: Option<Error>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.error {
+            Some(Error::BlockNotFound) => Ok(Self::BlockNotFound),
+            Some(error) => Ok(Self::Other(error)),
+            None => serde_json::from_value(helper.result)
+                .map(Self::Ok)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// An error that may be returned by the `getBlock` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GetBlockError {
+    /// block not found
+    ///
+    /// The block may have been pruned.
+    ///     Unknown,
+    BlockNotFound,
+}
+
+impl GetBlockError {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::BlockNotFound => 1,
+        }
+    }
+}
+
+impl From<GetBlockError> for Error {
+    fn from(error: GetBlockError) -> Self {
+        match error {
+            GetBlockError::BlockNotFound => Self::BlockNotFound,
+        }
+    }
+}
+
+impl TryFrom<Error> for GetBlockError {
+    type Error = Error;
+
+    /// Returns the provided error back if the method does not declare it.
+    fn try_from(error: Error) -> Result<Self, Error> {
+        match error {
+            Error::BlockNotFound => Ok(Self::BlockNotFound),
+            error => Err(error),
+        }
+    }
+}
+
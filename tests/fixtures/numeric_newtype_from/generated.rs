@@ -0,0 +1,111 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[serde(transparent)]
+pub struct Balance(pub f64);
+
+impl From<f64> for Balance {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Balance> for f64 {
+    #[inline]
+    fn from(value: Balance) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockHeight {
+    pub height: u64,
+}
+
+impl From<u64> for BlockHeight {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self { height: value }
+    }
+}
+
+impl From<BlockHeight> for u64 {
+    #[inline]
+    fn from(value: BlockHeight) -> Self {
+        value.height
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "(u64,)", into = "(u64,)")]
+pub struct Gas(pub u64);
+
+impl From<(u64,)> for Gas {
+    fn from((item,): (u64,)) -> Self {
+        Self(item)
+    }
+}
+
+impl From<Gas> for (u64,) {
+    fn from(value: Gas) -> Self {
+        (value.0,)
+    }
+}
+
+impl From<u64> for Gas {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Gas> for u64 {
+    #[inline]
+    fn from(value: Gas) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Name {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Nonce {
+    pub value: u64,
+}
+
+impl From<u64> for Nonce {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<Nonce> for u64 {
+    #[inline]
+    fn from(value: Nonce) -> Self {
+        value.value
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timeout {
+    #[serde(default)]
+    pub millis: Option<u64>,
+}
+
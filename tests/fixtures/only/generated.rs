@@ -0,0 +1,124 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub header: Header,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Peer {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Receipt {
+    pub block: Block,
+}
+
+/// An error that may be returned by a method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// block not found
+    BlockNotFound,
+    /// not listening
+    NotListening,
+}
+
+impl Error {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::BlockNotFound => 1,
+            Self::NotListening => 2,
+        }
+    }
+
+    /// Returns the message of this error, as declared in the OpenRPC document.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::BlockNotFound => "block not found",
+            Self::NotListening => "not listening",
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code())?;
+        map.serialize_entry("message", self.message())?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            code: i64,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.code {
+            1 => Ok(Self::BlockNotFound),
+            2 => Ok(Self::NotListening),
+            code => Err(serde::de::Error::custom(format_args!("unknown error code: {code}"))),
+        }
+    }
+}
+
+/// A method of the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// `eth_getBlock`
+    EthGetBlock,
+    /// `eth_getBalance`
+    EthGetBalance,
+    /// `net_peers`
+    NetPeers,
+}
+
+impl Method {
+    /// Returns the method with the provided name, which may be an alias.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "eth_getBlock" => Some(Self::EthGetBlock),
+            "eth_getBalance" => Some(Self::EthGetBalance),
+            "net_peers" => Some(Self::NetPeers),
+            _ => None,
+        }
+    }
+
+    /// Returns the canonical name of the method.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::EthGetBlock => "eth_getBlock",
+            Self::EthGetBalance => "eth_getBalance",
+            Self::NetPeers => "net_peers",
+        }
+    }
+}
+
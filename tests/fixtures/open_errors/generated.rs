@@ -0,0 +1,90 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+/// An error that may be returned by a method.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Not found
+    NotFound,
+    /// An error whose code is not declared in the OpenRPC document.
+    Unknown {
+        /// The code of the error.
+        code: i64,
+        /// The message of the error.
+        message: String,
+        /// Additional information about the error.
+        data: Option<serde_json::Value>,
+    },
+}
+
+impl Error {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::NotFound => 404,
+            Self::Unknown { code, .. } => *code,
+        }
+    }
+
+    /// Returns the message of this error, as declared in the OpenRPC document.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::NotFound => "Not found",
+            Self::Unknown { message, .. } => message,
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code())?;
+        map.serialize_entry("message", self.message())?;
+        if let Self::Unknown { data: Some(data), .. } = self {
+            map.serialize_entry("data", data)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            code: i64,
+            #[serde(default)]
+            message: String,
+            #[serde(default)]
+            data: Option<serde_json::Value>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.code {
+            404 => Ok(Self::NotFound),
+            code => Ok(Self::Unknown {
+                code,
+                message: helper.message,
+                data: helper.data,
+            }),
+        }
+    }
+}
+
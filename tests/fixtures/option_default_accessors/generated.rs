@@ -0,0 +1,59 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Filter {
+    pub address: String,
+    #[serde(default)]
+    pub limit: Option<i64>,
+    #[serde(default)]
+    pub order: Option<String>,
+    #[serde(default)]
+    pub range: Option<Range>,
+    #[serde(default)]
+    pub topics: Option<Vec<String>>,
+    #[serde(default)]
+    pub verbose: Option<bool>,
+}
+
+impl Filter {
+    /// Returns `limit`, or its default value `10` when it is absent.
+    #[inline]
+    pub fn limit_or_default(&self) -> i64 {
+        self.limit.unwrap_or(10)
+    }
+
+    /// Returns `order`, or its default value `"asc"` when it is absent.
+    #[inline]
+    pub fn order_or_default(&self) -> String {
+        self.order.clone().unwrap_or_else(|| "asc".into())
+    }
+
+    /// Returns `topics`, or the default value of its type when it is absent.
+    #[inline]
+    pub fn topics_or_default(&self) -> Vec<String> {
+        self.topics.clone().unwrap_or_default()
+    }
+
+    /// Returns `verbose`, or the default value of its type when it is absent.
+    #[inline]
+    pub fn verbose_or_default(&self) -> bool {
+        self.verbose.unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Range {
+    pub from: i64,
+    pub to: i64,
+}
+
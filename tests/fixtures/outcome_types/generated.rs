@@ -0,0 +1,198 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub number: i64,
+}
+
+/// An error that may be returned by a method.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Not found
+    NotFound,
+    /// Busy
+    Busy,
+    /// An error whose code is not declared in the OpenRPC document.
+    Unknown {
+        /// The code of the error.
+        code: i64,
+        /// The message of the error.
+        message: String,
+        /// Additional information about the error.
+        data: Option<serde_json::Value>,
+    },
+}
+
+impl Error {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::NotFound => 404,
+            Self::Busy => 503,
+            Self::Unknown { code, .. } => *code,
+        }
+    }
+
+    /// Returns the message of this error, as declared in the OpenRPC document.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::NotFound => "Not found",
+            Self::Busy => "Busy",
+            Self::Unknown { message, .. } => message,
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code())?;
+        map.serialize_entry("message", self.message())?;
+        if let Self::Unknown { data: Some(data), .. } = self {
+            map.serialize_entry("data", data)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            code: i64,
+            #[serde(default)]
+            message: String,
+            #[serde(default)]
+            data: Option<serde_json::Value>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.code {
+            404 => Ok(Self::NotFound),
+            503 => Ok(Self::Busy),
+            code => Ok(Self::Unknown {
+                code,
+                message: helper.message,
+                data: helper.data,
+            }),
+        }
+    }
+}
+
+/// Result type of `getBlock`.
+pub type GetBlockResult = Block;
+
+/// The outcome of a call to `getBlock`, deserialized from a JSON-RPC response.
+#[derive(Debug, Clone)]
+pub enum GetBlockOutcome {
+    /// The call succeeded.
+    Ok(GetBlockResult),
+    /// Not found
+    NotFound,
+    /// An error that is not declared for this method.
+    Other(Error),
+}
+
+impl GetBlockOutcome {
+    /// Converts this outcome into a [`Result`], using the [`Error`] type for errors.
+    pub fn into_result(self) -> Result<GetBlockResult, Error> {
+        match self {
+            Self::Ok(result) => Ok(result),
+            Self::NotFound => Err(Error::NotFound),
+            Self::Other(error) => Err(error),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockOutcome {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            #[serde(default)]
+            result: serde_json::Value,
+            #[serde(default)]
+            error: Option<Error>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.error {
+            Some(Error::NotFound) => Ok(Self::NotFound),
+            Some(error) => Ok(Self::Other(error)),
+            None => serde_json::from_value(helper.result)
+                .map(Self::Ok)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// Result type of `sendTransaction`.
+pub type SendTransactionResult = String;
+
+/// The outcome of a call to `sendTransaction`, deserialized from a JSON-RPC response.
+#[derive(Debug, Clone)]
+pub enum SendTransactionOutcome {
+    /// The call succeeded.
+    Ok(SendTransactionResult),
+    /// Busy
+    Busy,
+    /// An error that is not declared for this method.
+    Other(Error),
+}
+
+impl SendTransactionOutcome {
+    /// Converts this outcome into a [`Result`], using the [`Error`] type for errors.
+    pub fn into_result(self) -> Result<SendTransactionResult, Error> {
+        match self {
+            Self::Ok(result) => Ok(result),
+            Self::Busy => Err(Error::Busy),
+            Self::Other(error) => Err(error),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SendTransactionOutcome {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            #[serde(default)]
+            result: serde_json::Value,
+            #[serde(default)]
+            error: Option<Error>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.error {
+            Some(Error::Busy) => Ok(Self::Busy),
+            Some(error) => Ok(Self::Other(error)),
+            None => serde_json::from_value(helper.result)
+                .map(Self::Ok)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
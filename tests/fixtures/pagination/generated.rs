@@ -0,0 +1,150 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockPage {
+    pub items: Vec<i64>,
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
+/// Parameters of the `listBlocks` method.
+#[derive(Debug, Clone)]
+pub struct ListBlocksParams {
+    pub limit: i64,
+    pub cursor: Option<String>,
+}
+
+impl Serialize for ListBlocksParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("limit", &self.limit)?;
+        map.serialize_entry("cursor", &self.cursor)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ListBlocksParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = ListBlocksParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `listBlocks`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let limit: i64 = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 2 parameters"))?;
+                let cursor: Option<String> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 2 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(3, &"expected 2 parameters"));
+                }
+
+                Ok(ListBlocksParams {
+                    limit,
+                    cursor,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    limit: i64,
+                    #[serde(default)]
+                    cursor: Option<String>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(ListBlocksParams {
+                    limit: helper.limit,
+                    cursor: helper.cursor,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+impl ListBlocksParams {
+    /// Returns a stream over the pages of results of `listBlocks`, starting from these parameters.
+    pub fn into_stream<C: Client>(self, client: &C) -> ListBlocksStream<'_, C> {
+        ListBlocksStream {
+            client,
+            params: Some(self),
+        }
+    }
+}
+
+/// A stream over the pages of results of `listBlocks`.
+pub struct ListBlocksStream<'a, C> {
+    client: &'a C,
+    /// The parameters of the next call, or `None` once the last page has been fetched.
+    params: Option<ListBlocksParams>,
+}
+
+impl<C: Client> ListBlocksStream<'_, C> {
+    /// Fetches the next page of results.
+    ///
+    /// Returns `None` once the last page has been fetched. When a call fails, the same page
+    /// is fetched again by the next call to this function.
+    pub async fn next_page(&mut self) -> Option<Result<BlockPage, C::Error>> {
+        let mut params = self.params.clone()?;
+        let page = match self.client.list_blocks(params.clone()).await {
+            Ok(page) => page,
+            Err(err) => return Some(Err(err)),
+        };
+        params.cursor = page.next_cursor.clone();
+        self.params = params.cursor.is_some().then_some(params);
+        Some(Ok(page))
+    }
+}
+
+/// A client able to call the methods of the API.
+#[allow(async_fn_in_trait)]
+pub trait Client {
+    /// The error returned when a call fails.
+    type Error;
+
+    /// Calls the method with the provided name and parameters, returning its result.
+    async fn call<P, R>(&self, method: &str, params: P) -> Result<R, Self::Error>
+    where
+        P: Serialize,
+        R: serde::de::DeserializeOwned;
+
+    /// Calls the `listBlocks` method.
+    async fn list_blocks(&self, params: ListBlocksParams) -> Result<BlockPage, Self::Error> {
+        self.call("listBlocks", params).await
+    }
+}
+
@@ -0,0 +1,183 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+use serde::ser::SerializeSeq;
+
+pub type Felt = i64;
+
+/// Parameters of the `transferByName` method.
+#[derive(Debug, Clone)]
+pub struct TransferByNameParams {
+    pub amount: Felt,
+    pub fee: Option<Felt>,
+    pub memo: Option<String>,
+}
+
+impl Serialize for TransferByNameParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        struct Param0<'a>(&'a Felt);
+
+        impl Serialize for Param0<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                hex::serialize(self.0, serializer)
+            }
+        }
+
+        struct Param1<'a>(&'a Option<Felt>);
+
+        impl Serialize for Param1<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                hex::option::serialize(self.0, serializer)
+            }
+        }
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("amount", &Param0(&self.amount))?;
+        map.serialize_entry("fee", &Param1(&self.fee))?;
+        map.serialize_entry("memo", &self.memo)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TransferByNameParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = TransferByNameParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `transferByName`")
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    #[serde(with = "hex")]
+                    amount: Felt,
+                    #[serde(default)]
+                    #[serde(with = "hex::option")]
+                    fee: Option<Felt>,
+                    #[serde(default)]
+                    memo: Option<String>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(TransferByNameParams {
+                    amount: helper.amount,
+                    fee: helper.fee,
+                    memo: helper.memo,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_map(Visitor)
+    }
+}
+
+/// Parameters of the `transferByPosition` method.
+#[derive(Debug, Clone)]
+pub struct TransferByPositionParams {
+    pub amount: Felt,
+    pub fee: Option<Felt>,
+    pub memo: Option<String>,
+}
+
+impl Serialize for TransferByPositionParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        struct Param0<'a>(&'a Felt);
+
+        impl Serialize for Param0<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                hex::serialize(self.0, serializer)
+            }
+        }
+
+        struct Param1<'a>(&'a Option<Felt>);
+
+        impl Serialize for Param1<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                hex::option::serialize(self.0, serializer)
+            }
+        }
+
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element(&Param0(&self.amount))?;
+        seq.serialize_element(&Param1(&self.fee))?;
+        seq.serialize_element(&self.memo)?;
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TransferByPositionParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = TransferByPositionParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `transferByPosition`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                #[serde(transparent)]
+                struct Param0(#[serde(with = "hex")] Felt);
+                let amount: Felt = seq.next_element::<Param0>()?.map(|param| param.0).ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 3 parameters"))?;
+                #[derive(Deserialize)]
+                #[serde(transparent)]
+                struct Param1(#[serde(with = "hex::option")] Option<Felt>);
+                let fee: Option<Felt> = seq.next_element::<Param1>()?.map(|param| param.0).ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 3 parameters"))?;
+                let memo: Option<String> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(3, &"expected 3 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(4, &"expected 3 parameters"));
+                }
+
+                Ok(TransferByPositionParams {
+                    amount,
+                    fee,
+                    memo,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_seq(Visitor)
+    }
+}
+
@@ -0,0 +1,228 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+/// Parameters of the `getBlock` method.
+#[derive(Debug, Clone)]
+pub struct GetBlockParams {
+    pub number: i64,
+    pub fulltransactions: Option<bool>,
+    pub tag: Option<String>,
+}
+
+impl Serialize for GetBlockParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("number", &self.number)?;
+        map.serialize_entry("fullTransactions", &self.fulltransactions)?;
+        map.serialize_entry("tag", &self.tag)?;
+        map.end()
+    }
+}
+
+/// Builds a [`GetBlockParams`] from `key: value` pairs.
+///
+/// Omitted optional parameters are set to their default value.
+///
+/// Both this macro and [`GetBlockParams`] must be in scope where it is used.
+#[allow(unused_macros)]
+macro_rules! get_block_params {
+    (@munch { $($acc:tt)* } [fulltransactions $f0:tt tag $f1:tt] fulltransactions : $value:expr $(, $($rest:tt)*)?) => {
+        get_block_params!(@munch { $($acc)* fulltransactions: From::from($value), } [fulltransactions ! tag $f1] $($($rest)*)?)
+    };
+    (@munch { $($acc:tt)* } [fulltransactions $f0:tt tag $f1:tt] tag : $value:expr $(, $($rest:tt)*)?) => {
+        get_block_params!(@munch { $($acc)* tag: From::from($value), } [fulltransactions $f0 tag !] $($($rest)*)?)
+    };
+    (@munch { $($acc:tt)* } $state:tt $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        get_block_params!(@munch { $($acc)* $key: $value, } $state $($($rest)*)?)
+    };
+    (@munch { $($acc:tt)* } $state:tt) => {
+        get_block_params!(@fill { $($acc)* } $state)
+    };
+    (@fill { $($acc:tt)* } [$param:ident _ $($rest:tt)*]) => {
+        get_block_params!(@fill { $($acc)* $param: Default::default(), } [$($rest)*])
+    };
+    (@fill { $($acc:tt)* } [$param:ident ! $($rest:tt)*]) => {
+        get_block_params!(@fill { $($acc)* } [$($rest)*])
+    };
+    (@fill { $($acc:tt)* } []) => {
+        GetBlockParams { $($acc)* }
+    };
+    ($($key:ident : $value:expr),* $(,)?) => {
+        get_block_params!(@munch {} [fulltransactions _ tag _] $($key : $value),*)
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use get_block_params;
+
+impl<'de> Deserialize<'de> for GetBlockParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBlockParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBlock`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let number: i64 = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 3 parameters"))?;
+                let fulltransactions: Option<bool> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 3 parameters"))?;
+                let tag: Option<String> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(3, &"expected 3 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(4, &"expected 3 parameters"));
+                }
+
+                Ok(GetBlockParams {
+                    number,
+                    fulltransactions,
+                    tag,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    number: i64,
+                    #[serde(default)]
+                    #[serde(rename = "fullTransactions")]
+                    fulltransactions: Option<bool>,
+                    #[serde(default)]
+                    tag: Option<String>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBlockParams {
+                    number: helper.number,
+                    fulltransactions: helper.fulltransactions,
+                    tag: helper.tag,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// Parameters of the `ping` method.
+#[derive(Debug, Clone)]
+pub struct PingParams {
+}
+
+impl Serialize for PingParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.end()
+    }
+}
+
+/// Builds a [`PingParams`] from `key: value` pairs.
+///
+/// Omitted optional parameters are set to their default value.
+///
+/// Both this macro and [`PingParams`] must be in scope where it is used.
+#[allow(unused_macros)]
+macro_rules! ping_params {
+    (@munch { $($acc:tt)* } $state:tt $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        ping_params!(@munch { $($acc)* $key: $value, } $state $($($rest)*)?)
+    };
+    (@munch { $($acc:tt)* } $state:tt) => {
+        ping_params!(@fill { $($acc)* } $state)
+    };
+    (@fill { $($acc:tt)* } [$param:ident _ $($rest:tt)*]) => {
+        ping_params!(@fill { $($acc)* $param: Default::default(), } [$($rest)*])
+    };
+    (@fill { $($acc:tt)* } [$param:ident ! $($rest:tt)*]) => {
+        ping_params!(@fill { $($acc)* } [$($rest)*])
+    };
+    (@fill { $($acc:tt)* } []) => {
+        PingParams { $($acc)* }
+    };
+    ($($key:ident : $value:expr),* $(,)?) => {
+        ping_params!(@munch {} [] $($key : $value),*)
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use ping_params;
+
+impl<'de> Deserialize<'de> for PingParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = PingParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `ping`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(1, &"expected 0 parameters"));
+                }
+
+                Ok(PingParams {
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(PingParams {
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
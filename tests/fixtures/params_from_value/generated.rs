@@ -0,0 +1,108 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+
+/// Parameters of the `getBlock` method.
+#[derive(Debug, Clone)]
+pub struct GetBlockParams {
+    pub blocknumber: i64,
+    pub includetxs: Option<bool>,
+}
+
+impl Serialize for GetBlockParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("blockNumber", &self.blocknumber)?;
+        map.serialize_entry("includeTxs", &self.includetxs)?;
+        map.end()
+    }
+}
+
+impl GetBlockParams {
+    /// Reads the parameters of `getBlock` from the `params` of a request.
+    ///
+    /// Arrays are read as positional parameters, and objects as named parameters.
+    pub fn from_rpc_params(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqAccessDeserializer, SeqDeserializer};
+
+        match value {
+            serde_json::Value::Array(items) => Self::deserialize(SeqAccessDeserializer::new(SeqDeserializer::new(items.into_iter()))),
+            serde_json::Value::Object(map) => Self::deserialize(MapAccessDeserializer::new(MapDeserializer::new(map.into_iter()))),
+            value => Self::deserialize(value),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GetBlockParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetBlockParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getBlock`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let blocknumber: i64 = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 2 parameters"))?;
+                let includetxs: Option<bool> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 2 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(3, &"expected 2 parameters"));
+                }
+
+                Ok(GetBlockParams {
+                    blocknumber,
+                    includetxs,
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    #[serde(rename = "blockNumber")]
+                    blocknumber: i64,
+                    #[serde(default)]
+                    #[serde(rename = "includeTxs")]
+                    includetxs: Option<bool>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetBlockParams {
+                    blocknumber: helper.blocknumber,
+                    includetxs: helper.includetxs,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
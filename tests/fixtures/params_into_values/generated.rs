@@ -0,0 +1,95 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeSeq;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Memo {
+    pub text: String,
+}
+
+/// Parameters of the `transfer` method.
+#[derive(Debug, Clone)]
+pub struct TransferParams {
+    pub to: String,
+    pub amount: i64,
+    pub memo: Option<Memo>,
+}
+
+impl Serialize for TransferParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element(&self.to)?;
+        seq.serialize_element(&self.amount)?;
+        seq.serialize_element(&self.memo)?;
+        seq.end()
+    }
+}
+
+impl TransferParams {
+    /// Returns the parameters as a list of JSON values, in the order expected by `transfer`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if one of the parameters cannot be represented as JSON.
+    pub fn into_values(self) -> Vec<serde_json::Value> {
+        vec![
+            serde_json::to_value(self.to).expect("failed to serialize `to`"),
+            serde_json::to_value(self.amount).expect("failed to serialize `amount`"),
+            serde_json::to_value(self.memo).expect("failed to serialize `memo`"),
+        ]
+    }
+}
+
+impl<'de> Deserialize<'de> for TransferParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = TransferParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `transfer`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let to: String = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 3 parameters"))?;
+                let amount: i64 = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 3 parameters"))?;
+                let memo: Option<Memo> = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(3, &"expected 3 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(4, &"expected 3 parameters"));
+                }
+
+                Ok(TransferParams {
+                    to,
+                    amount,
+                    memo,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_seq(Visitor)
+    }
+}
+
@@ -0,0 +1,59 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+/// Mirrors [`crate::remote_types::Point`] to (de)serialize it.
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "crate::remote_types::Point")]
+pub struct PointDef {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[allow(dead_code)]
+mod point_def_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    struct Ref<'a>(&'a crate::remote_types::Point);
+
+    impl Serialize for Ref<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::PointDef::serialize(self.0, serializer)
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Owned(#[serde(with = "super::PointDef")] crate::remote_types::Point);
+
+    pub fn serialize<S>(value: &Option<crate::remote_types::Point>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.as_ref().map(Ref).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<crate::remote_types::Point>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Owned>::deserialize(deserializer)?.map(|v| v.0))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Shape {
+    #[serde(default)]
+    #[serde(with = "point_def_option")]
+    pub anchor: Option<crate::remote_types::Point>,
+    #[serde(with = "PointDef")]
+    pub origin: crate::remote_types::Point,
+}
+
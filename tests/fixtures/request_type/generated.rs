@@ -0,0 +1,142 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+/// The identifier of a JSON-RPC request.
+///
+/// A `null` identifier is distinct from a missing one, which marks a notification.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Id {
+    /// A non-negative integer identifier.
+    Unsigned(u64),
+    /// A negative integer identifier.
+    Signed(i64),
+    /// A string identifier.
+    String(String),
+    /// The `null` identifier.
+    Null,
+}
+
+impl Serialize for Id {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unsigned(id) => serializer.serialize_u64(*id),
+            Self::Signed(id) => serializer.serialize_i64(*id),
+            Self::String(id) => serializer.serialize_str(id),
+            Self::Null => serializer.serialize_unit(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Id {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Id;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an integer, a string or null")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(Id::Unsigned(v))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+                match u64::try_from(v) {
+                    Ok(v) => Ok(Id::Unsigned(v)),
+                    Err(_) => Ok(Id::Signed(v)),
+                }
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Id::String(v.into()))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+                Ok(Id::String(v))
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E> {
+                Ok(Id::Null)
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E> {
+                Ok(Id::Null)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// A JSON-RPC request, or a notification when it has no identifier.
+#[derive(Debug, Clone)]
+pub struct Request<P> {
+    /// The identifier of the request, or `None` for a notification.
+    pub id: Option<Id>,
+    /// The name of the method to call.
+    pub method: String,
+    /// The parameters of the call.
+    pub params: P,
+}
+
+impl<P> Request<P> {
+    /// Creates a new request expecting a response.
+    pub fn new(id: Id, method: impl Into<String>, params: P) -> Self {
+        Self {
+            id: Some(id),
+            method: method.into(),
+            params,
+        }
+    }
+
+    /// Creates a new notification, which expects no response.
+    pub fn notification(method: impl Into<String>, params: P) -> Self {
+        Self {
+            id: None,
+            method: method.into(),
+            params,
+        }
+    }
+
+    /// Returns whether this call is a notification.
+    pub fn is_notification(&self) -> bool {
+        self.id.is_none()
+    }
+}
+
+impl<P: Serialize> Serialize for Request<P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let len = if self.id.is_some() { 4 } else { 3 };
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("jsonrpc", "2.0")?;
+        map.serialize_entry("method", &self.method)?;
+        map.serialize_entry("params", &self.params)?;
+        if let Some(id) = &self.id {
+            map.serialize_entry("id", id)?;
+        }
+        map.end()
+    }
+}
+
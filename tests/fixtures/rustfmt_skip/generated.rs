@@ -0,0 +1,23 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+#[rustfmt::skip]
+mod generated {
+    use serde::{Serialize, Deserialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Point {
+        pub x: i64,
+        pub y: i64,
+    }
+
+}
+
+pub use self::generated::*;
@@ -0,0 +1,35 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, crate::serde_path_overrides::vendored::serde::Serialize, crate::serde_path_overrides::vendored::serde::Deserialize)]
+#[serde(crate = "crate::serde_path_overrides::vendored::serde")]
+pub struct Block {
+    pub header: Header,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    pub hash: String,
+}
+
+#[derive(crate::serde_path_overrides::vendored::serde::Serialize, crate::serde_path_overrides::vendored::serde::Deserialize)]
+#[serde(crate = "crate::serde_path_overrides::vendored::serde")]
+#[derive(Copy, PartialEq, Eq, Hash)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pending,
+    Final,
+}
+
@@ -0,0 +1,77 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorDetails {
+    pub reason: String,
+}
+
+/// An error that may be returned by a method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// Not found
+    NotFound,
+    /// Internal error
+    InternalError,
+}
+
+impl Error {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::NotFound => 404,
+            Self::InternalError => 500,
+        }
+    }
+
+    /// Returns the message of this error, as declared in the OpenRPC document.
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::NotFound => "Not found",
+            Self::InternalError => "Internal error",
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code())?;
+        map.serialize_entry("message", self.message())?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            code: i64,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.code {
+            404 => Ok(Self::NotFound),
+            500 => Ok(Self::InternalError),
+            code => Err(serde::de::Error::custom(format_args!("unknown error code: {code}"))),
+        }
+    }
+}
+
@@ -0,0 +1,899 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+use serde::ser::SerializeMap;
+use serde::ser::SerializeSeq;
+
+#[allow(dead_code)]
+mod shared_str {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    /// A type holding shared strings, (de)serialized through this module.
+    pub trait Shared: Sized {
+        fn serialize_shared<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+        fn deserialize_shared<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+    }
+
+    impl Shared for std::sync::Arc<str> {
+        fn serialize_shared<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self)
+        }
+
+        fn deserialize_shared<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::capped::string::<String, D>(deserializer).map(Self::from)
+        }
+    }
+
+    impl<T: Shared> Shared for Option<T> {
+        fn serialize_shared<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                Some(value) => serializer.serialize_some(&Ser(value)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        fn deserialize_shared<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(Option::<De<T>>::deserialize(deserializer)?.map(|value| value.0))
+        }
+    }
+
+    impl<T: Shared> Shared for Vec<T> {
+        fn serialize_shared<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter().map(Ser))
+        }
+
+        fn deserialize_shared<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(super::capped::seq::<Vec<De<T>>, De<T>, D>(deserializer)?.into_iter().map(|item| item.0).collect())
+        }
+    }
+
+    impl<T: Shared, const N: usize> Shared for super::BoundedVec<T, N> {
+        fn serialize_shared<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter().map(Ser))
+        }
+
+        fn deserialize_shared<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let items = super::capped::seq::<Vec<De<T>>, De<T>, D>(deserializer)?.into_iter().map(|item| item.0).collect::<Vec<T>>();
+            Self::new(items).map_err(|items| {
+                serde::de::Error::custom(format_args!("expected at most {N} items, found {}", items.len()))
+            })
+        }
+    }
+
+    /// Serializes the referenced value through [`Shared`].
+    pub struct Ser<'a, T>(pub &'a T);
+
+    impl<T: Shared> Serialize for Ser<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize_shared(serializer)
+        }
+    }
+
+    /// Deserializes and serializes the wrapped value through [`Shared`].
+    pub struct De<T>(pub T);
+
+    impl<T: Shared> Serialize for De<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize_shared(serializer)
+        }
+    }
+
+    impl<'de, T: Shared> Deserialize<'de> for De<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            T::deserialize_shared(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<T: Shared, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_shared(serializer)
+    }
+
+    pub fn deserialize<'de, T: Shared, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_shared(deserializer)
+    }
+}
+
+#[allow(dead_code)]
+mod capped {
+    use std::marker::PhantomData;
+
+    use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+
+    /// The maximum number of items of arrays, and of bytes of strings.
+    pub const MAX_SIZE: usize = 8;
+
+    struct CappedString;
+
+    impl Visitor<'_> for CappedString {
+        type Value = String;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "a string of at most {MAX_SIZE} bytes")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<String, E> {
+            if v.len() > MAX_SIZE {
+                return Err(E::invalid_length(v.len(), &self));
+            }
+            Ok(v.into())
+        }
+    }
+
+    struct CappedSeq<T>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for CappedSeq<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "a sequence of at most {MAX_SIZE} items")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<T>, A::Error> {
+            let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(MAX_SIZE));
+            while let Some(item) = seq.next_element()? {
+                if vec.len() == MAX_SIZE {
+                    return Err(A::Error::invalid_length(MAX_SIZE + 1, &self));
+                }
+                vec.push(item);
+            }
+            Ok(vec)
+        }
+    }
+
+    pub fn string<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<String>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(CappedString).map(T::from)
+    }
+
+    pub fn seq<'de, C, T, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: FromIterator<T>,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_seq(CappedSeq(PhantomData))
+            .map(|vec| vec.into_iter().collect())
+    }
+
+    pub mod option {
+        use std::marker::PhantomData;
+
+        use serde::{Deserialize, Deserializer};
+
+        struct Capped<C, T>(C, PhantomData<T>);
+
+        impl<'de, C: FromIterator<T>, T: Deserialize<'de>> Deserialize<'de> for Capped<C, T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                super::seq(deserializer).map(|c| Capped(c, PhantomData))
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct CappedString(#[serde(deserialize_with = "super::string")] String);
+
+        pub fn string<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            T: From<String>,
+            D: Deserializer<'de>,
+        {
+            Ok(Option::<CappedString>::deserialize(deserializer)?.map(|s| T::from(s.0)))
+        }
+
+        pub fn seq<'de, C, T, D>(deserializer: D) -> Result<Option<C>, D::Error>
+        where
+            C: FromIterator<T>,
+            T: Deserialize<'de>,
+            D: Deserializer<'de>,
+        {
+            Ok(Option::<Capped<C, T>>::deserialize(deserializer)?.map(|c| c.0))
+        }
+    }
+}
+
+/// A `Vec` holding at most `N` items.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct BoundedVec<T, const N: usize>(Vec<T>);
+
+#[allow(dead_code)]
+impl<T, const N: usize> BoundedVec<T, N> {
+    /// Creates a new [`BoundedVec`], or gives the provided vector back if it holds more
+    /// than `N` items.
+    pub fn new(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        if vec.len() <= N {
+            Ok(Self(vec))
+        } else {
+            Err(vec)
+        }
+    }
+
+    /// Returns the inner vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T, const N: usize> std::ops::Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for BoundedVec<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        Self::new(vec).map_err(|vec| {
+            serde::de::Error::custom(format_args!(
+                "expected at most {N} items, found {}",
+                vec.len()
+            ))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "(shared_str::De<std::sync::Arc<str>>,)", into = "(shared_str::De<std::sync::Arc<str>>,)")]
+pub struct Handle(pub std::sync::Arc<str>);
+
+impl From<(shared_str::De<std::sync::Arc<str>>,)> for Handle {
+    fn from((item,): (shared_str::De<std::sync::Arc<str>>,)) -> Self {
+        Self(item.0)
+    }
+}
+
+impl From<Handle> for (shared_str::De<std::sync::Arc<str>>,) {
+    fn from(value: Handle) -> Self {
+        (shared_str::De(value.0),)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+// Variants are tried in order: Names, Name.
+#[serde(untagged)]
+pub enum Identity {
+    #[serde(with = "shared_str")]
+    Names(Vec<std::sync::Arc<str>>),
+    #[serde(with = "shared_str")]
+    Name(std::sync::Arc<str>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notice {
+    pub jsonrpc: JsonRpcVersion,
+    #[serde(with = "shared_str")]
+    pub text: std::sync::Arc<str>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    #[serde(default)]
+    #[serde(with = "shared_str")]
+    pub badges: Option<BoundedVec<std::sync::Arc<str>, 2>>,
+    #[serde(with = "shared_str")]
+    pub groups: Vec<Vec<std::sync::Arc<str>>>,
+    pub handle: Handle,
+    #[serde(with = "shared_str")]
+    pub kind: std::sync::Arc<str> /* user */,
+    #[serde(with = "shared_str")]
+    pub name: std::sync::Arc<str>,
+    #[serde(default)]
+    #[serde(with = "shared_str")]
+    pub nickname: Option<std::sync::Arc<str>>,
+}
+
+/// The `"2.0"` version of the JSON-RPC protocol.
+///
+/// This always serializes as `"2.0"` and fails to deserialize from any other value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct JsonRpcVersion;
+
+impl Serialize for JsonRpcVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str("2.0")
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonRpcVersion {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = JsonRpcVersion;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("\"2.0\"")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                if v == "2.0" {
+                    Ok(JsonRpcVersion)
+                } else {
+                    Err(E::invalid_value(serde::de::Unexpected::Str(v), &self))
+                }
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// An error that may be returned by a method.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// An error whose code is not declared in the OpenRPC document.
+    Unknown {
+        /// The code of the error.
+        code: i64,
+        /// The message of the error.
+        message: String,
+        /// Additional information about the error.
+        data: Option<serde_json::Value>,
+    },
+}
+
+impl Error {
+    /// Returns the code of this error.
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::Unknown { code, .. } => *code,
+        }
+    }
+
+    /// Returns the message of this error, as declared in the OpenRPC document.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Unknown { message, .. } => message,
+        }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code())?;
+        map.serialize_entry("message", self.message())?;
+        if let Self::Unknown { data: Some(data), .. } = self {
+            map.serialize_entry("data", data)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Error {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            code: i64,
+            #[serde(default)]
+            message: String,
+            #[serde(default)]
+            data: Option<serde_json::Value>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        Ok(Self::Unknown {
+            code: helper.code,
+            message: helper.message,
+            data: helper.data,
+        })
+    }
+}
+
+/// Result type of `tagUser`.
+pub type TagUserResult = std::sync::Arc<str>;
+
+/// The outcome of a call to `tagUser`, deserialized from a JSON-RPC response.
+#[derive(Debug, Clone)]
+pub enum TagUserOutcome {
+    /// The call succeeded.
+    Ok(TagUserResult),
+    /// An error that is not declared for this method.
+    Other(Error),
+}
+
+impl TagUserOutcome {
+    /// Converts this outcome into a [`Result`], using the [`Error`] type for errors.
+    pub fn into_result(self) -> Result<TagUserResult, Error> {
+        match self {
+            Self::Ok(result) => Ok(result),
+            Self::Other(error) => Err(error),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TagUserOutcome {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            #[serde(default)]
+            result: serde_json::Value,
+            #[serde(default)]
+            error: Option<Error>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.error {
+            Some(error) => Ok(Self::Other(error)),
+            None => serde_json::from_value::<shared_str::De<TagUserResult>>(helper.result)
+                .map(|result| Self::Ok(result.0))
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// Parameters of the `tagUser` method.
+#[derive(Debug, Clone)]
+pub struct TagUserParams {
+    pub user: User,
+    pub tags: Vec<std::sync::Arc<str>>,
+    pub note: Option<std::sync::Arc<str>>,
+}
+
+impl Serialize for TagUserParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        struct Param1<'a>(&'a Vec<std::sync::Arc<str>>);
+
+        impl Serialize for Param1<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                shared_str::serialize(self.0, serializer)
+            }
+        }
+
+        struct Param2<'a>(&'a Option<std::sync::Arc<str>>);
+
+        impl Serialize for Param2<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                shared_str::serialize(self.0, serializer)
+            }
+        }
+
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element(&self.user)?;
+        seq.serialize_element(&Param1(&self.tags))?;
+        seq.serialize_element(&Param2(&self.note))?;
+        seq.end()
+    }
+}
+
+impl TagUserParams {
+    /// Returns the parameters as a list of JSON values, in the order expected by `tagUser`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if one of the parameters cannot be represented as JSON.
+    pub fn into_values(self) -> Vec<serde_json::Value> {
+        struct Param1<'a>(&'a Vec<std::sync::Arc<str>>);
+
+        impl Serialize for Param1<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                shared_str::serialize(self.0, serializer)
+            }
+        }
+
+        struct Param2<'a>(&'a Option<std::sync::Arc<str>>);
+
+        impl Serialize for Param2<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                shared_str::serialize(self.0, serializer)
+            }
+        }
+
+        vec![
+            serde_json::to_value(self.user).expect("failed to serialize `user`"),
+            serde_json::to_value(Param1(&self.tags)).expect("failed to serialize `tags`"),
+            serde_json::to_value(Param2(&self.note)).expect("failed to serialize `note`"),
+        ]
+    }
+}
+
+impl<'de> Deserialize<'de> for TagUserParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = TagUserParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `tagUser`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let user: User = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(1, &"expected 3 parameters"))?;
+                #[derive(Deserialize)]
+                #[serde(transparent)]
+                struct Param1(#[serde(with = "shared_str")] Vec<std::sync::Arc<str>>);
+                let tags: Vec<std::sync::Arc<str>> = seq.next_element::<Param1>()?.map(|param| param.0).ok_or_else(|| serde::de::Error::invalid_length(2, &"expected 3 parameters"))?;
+                #[derive(Deserialize)]
+                #[serde(transparent)]
+                struct Param2(#[serde(with = "shared_str")] Option<std::sync::Arc<str>>);
+                let note: Option<std::sync::Arc<str>> = seq.next_element::<Param2>()?.map(|param| param.0).ok_or_else(|| serde::de::Error::invalid_length(3, &"expected 3 parameters"))?;
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(4, &"expected 3 parameters"));
+                }
+
+                Ok(TagUserParams {
+                    user,
+                    tags,
+                    note,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_seq(Visitor)
+    }
+}
+
+/// Result type of `renameUser`.
+pub type RenameUserResult = Vec<std::sync::Arc<str>>;
+
+/// The outcome of a call to `renameUser`, deserialized from a JSON-RPC response.
+#[derive(Debug, Clone)]
+pub enum RenameUserOutcome {
+    /// The call succeeded.
+    Ok(RenameUserResult),
+    /// An error that is not declared for this method.
+    Other(Error),
+}
+
+impl RenameUserOutcome {
+    /// Converts this outcome into a [`Result`], using the [`Error`] type for errors.
+    pub fn into_result(self) -> Result<RenameUserResult, Error> {
+        match self {
+            Self::Ok(result) => Ok(result),
+            Self::Other(error) => Err(error),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RenameUserOutcome {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            #[serde(default)]
+            result: serde_json::Value,
+            #[serde(default)]
+            error: Option<Error>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.error {
+            Some(error) => Ok(Self::Other(error)),
+            None => serde_json::from_value::<shared_str::De<RenameUserResult>>(helper.result)
+                .map(|result| Self::Ok(result.0))
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// Parameters of the `renameUser` method.
+#[derive(Debug, Clone)]
+pub struct RenameUserParams {
+    pub name: std::sync::Arc<str>,
+    pub aliases: Option<Vec<std::sync::Arc<str>>>,
+}
+
+impl Serialize for RenameUserParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        struct Param0<'a>(&'a std::sync::Arc<str>);
+
+        impl Serialize for Param0<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                shared_str::serialize(self.0, serializer)
+            }
+        }
+
+        struct Param1<'a>(&'a Option<Vec<std::sync::Arc<str>>>);
+
+        impl Serialize for Param1<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                shared_str::serialize(self.0, serializer)
+            }
+        }
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("name", &Param0(&self.name))?;
+        map.serialize_entry("aliases", &Param1(&self.aliases))?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RenameUserParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = RenameUserParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `renameUser`")
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                    #[serde(with = "shared_str")]
+                    name: std::sync::Arc<str>,
+                    #[serde(default)]
+                    #[serde(with = "shared_str")]
+                    aliases: Option<Vec<std::sync::Arc<str>>>,
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(RenameUserParams {
+                    name: helper.name,
+                    aliases: helper.aliases,
+                })
+            }
+
+        }
+
+        deserializer.deserialize_map(Visitor)
+    }
+}
+
+/// Result type of `getIdentity`.
+pub type GetIdentityResult = Identity;
+
+/// The outcome of a call to `getIdentity`, deserialized from a JSON-RPC response.
+#[derive(Debug, Clone)]
+pub enum GetIdentityOutcome {
+    /// The call succeeded.
+    Ok(GetIdentityResult),
+    /// An error that is not declared for this method.
+    Other(Error),
+}
+
+impl GetIdentityOutcome {
+    /// Converts this outcome into a [`Result`], using the [`Error`] type for errors.
+    pub fn into_result(self) -> Result<GetIdentityResult, Error> {
+        match self {
+            Self::Ok(result) => Ok(result),
+            Self::Other(error) => Err(error),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GetIdentityOutcome {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            #[serde(default)]
+            result: serde_json::Value,
+            #[serde(default)]
+            error: Option<Error>,
+        }
+
+        let helper = Helper::deserialize(deserializer)?;
+        match helper.error {
+            Some(error) => Ok(Self::Other(error)),
+            None => serde_json::from_value(helper.result)
+                .map(Self::Ok)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// Parameters of the `getIdentity` method.
+#[derive(Debug, Clone)]
+pub struct GetIdentityParams {
+}
+
+impl Serialize for GetIdentityParams {
+    #[allow(unused_mut)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.end()
+    }
+}
+
+impl GetIdentityParams {
+    /// Returns the parameters as a list of JSON values, in the order expected by `getIdentity`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if one of the parameters cannot be represented as JSON.
+    pub fn into_values(self) -> Vec<serde_json::Value> {
+        vec![
+        ]
+    }
+}
+
+impl<'de> Deserialize<'de> for GetIdentityParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = GetIdentityParams;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "the parameters for `getIdentity`")
+            }
+
+            #[allow(unused_mut)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(1, &"expected 0 parameters"));
+                }
+
+                Ok(GetIdentityParams {
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Helper {
+                }
+
+                let helper = Helper::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(GetIdentityParams {
+                })
+            }
+
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// A method of the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// `tagUser`
+    TagUser,
+    /// `renameUser`
+    RenameUser,
+    /// `getIdentity`
+    GetIdentity,
+}
+
+impl Method {
+    /// Returns the method with the provided name, which may be an alias.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "tagUser" => Some(Self::TagUser),
+            "renameUser" => Some(Self::RenameUser),
+            "getIdentity" => Some(Self::GetIdentity),
+            _ => None,
+        }
+    }
+
+    /// Returns the canonical name of the method.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::TagUser => "tagUser",
+            Self::RenameUser => "renameUser",
+            Self::GetIdentity => "getIdentity",
+        }
+    }
+}
+
+/// The result of a method of the API.
+#[derive(Debug, Clone)]
+pub enum Response {
+    /// The result of `tagUser`.
+    TagUser(std::sync::Arc<str>),
+    /// The result of `renameUser`.
+    RenameUser(Vec<std::sync::Arc<str>>),
+    /// The result of `getIdentity`.
+    GetIdentity(Identity),
+}
+
+impl Response {
+    /// Deserializes the result of the provided method.
+    pub fn deserialize_for<'de, D>(method: Method, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match method {
+            Method::TagUser => shared_str::deserialize(deserializer).map(Self::TagUser),
+            Method::RenameUser => shared_str::deserialize(deserializer).map(Self::RenameUser),
+            Method::GetIdentity => <Identity>::deserialize(deserializer).map(Self::GetIdentity),
+        }
+    }
+
+    /// Returns the method this is the result of.
+    pub const fn method(&self) -> Method {
+        match self {
+            Self::TagUser(_) => Method::TagUser,
+            Self::RenameUser(_) => Method::RenameUser,
+            Self::GetIdentity(_) => Method::GetIdentity,
+        }
+    }
+}
+
+impl Serialize for Response {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::TagUser(result) => shared_str::serialize(result, serializer),
+            Self::RenameUser(result) => shared_str::serialize(result, serializer),
+            Self::GetIdentity(result) => result.serialize(serializer),
+        }
+    }
+}
+
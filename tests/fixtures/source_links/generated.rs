@@ -0,0 +1,29 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+/// A block of the chain.
+///
+/// Generated from #/components/schemas/Block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub header: Header,
+}
+
+/// Generated from #/components/schemas/Block/properties/header
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    pub hash: String,
+}
+
+/// Generated from #/components/schemas/Hash
+pub type Hash = String;
+
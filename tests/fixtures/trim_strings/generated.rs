@@ -0,0 +1,56 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[allow(dead_code)]
+mod trimmed_string {
+    use serde::{Deserialize, Deserializer};
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<String>,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        if s.trim().len() == s.len() {
+            Ok(T::from(s))
+        } else {
+            Ok(T::from(s.trim().into()))
+        }
+    }
+
+    pub mod option {
+        use serde::{Deserialize, Deserializer};
+
+        #[derive(Deserialize)]
+        struct Trimmed(#[serde(deserialize_with = "super::deserialize")] String);
+
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            T: From<String>,
+            D: Deserializer<'de>,
+        {
+            Ok(Option::<Trimmed>::deserialize(deserializer)?.map(|s| T::from(s.0)))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Account {
+    pub address: String,
+    pub balance: i64,
+    #[serde(deserialize_with = "trimmed_string::deserialize")]
+    pub name: String,
+    #[serde(default)]
+    #[serde(deserialize_with = "trimmed_string::option::deserialize")]
+    pub nickname: Option<String>,
+}
+
@@ -0,0 +1,49 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+pub type Hash = String;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Proof(pub i64, pub Vec<String>, pub Hash, pub bool, pub String);
+
+impl Proof {
+    /// The proven value.
+    #[inline]
+    pub fn value(&self) -> &i64 {
+        &self.0
+    }
+
+    /// Returns the item at index 1 of the tuple.
+    #[inline]
+    pub fn merkle_proof(&self) -> &Vec<String> {
+        &self.1
+    }
+
+    /// Returns the item at index 2 of the tuple.
+    #[inline]
+    pub fn hash(&self) -> &Hash {
+        &self.2
+    }
+
+    /// Returns the item at index 3 of the tuple.
+    #[inline]
+    pub fn item3(&self) -> &bool {
+        &self.3
+    }
+
+    /// Returns the item at index 4 of the tuple.
+    #[inline]
+    pub fn item4(&self) -> &String {
+        &self.4
+    }
+}
+
@@ -0,0 +1,23 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+pub type Felt = i64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transfer {
+    #[serde(with = "hex")]
+    pub amount: Felt,
+    #[serde(default)]
+    #[serde(with = "hex::option")]
+    pub fee: Option<Felt>,
+}
+
@@ -0,0 +1,49 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Confirmed {
+    pub block: i64,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pending {
+    pub hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+// Variants are tried in order: Confirmed, Pending.
+#[serde(untagged)]
+pub enum T2NdStageReceiptV2 {
+    Confirmed(Confirmed),
+    Pending(Pending),
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+// Variants are tried in order: Confirmed, Pending.
+#[serde(untagged)]
+pub enum Anonymous {
+    Confirmed(Confirmed),
+    Pending(Pending),
+}
+
+/// Result type of `getTransaction`.
+pub type GetTransactionResult = Anonymous;
+
+/// Result type of `getReceipt`.
+pub type GetReceiptResult = T2NdStageReceiptV2;
+
@@ -0,0 +1,82 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Account {
+    pub age: i64,
+    #[serde(default)]
+    #[serde(rename = "display-name")]
+    pub display_name: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+}
+
+impl Account {
+    /// Checks the bounds declared by the schemas of the fields of [`Account`].
+    ///
+    /// All the violated bounds are returned, in the order of the fields.
+    pub fn validate(&self) -> Result<(), Vec<ConstraintViolation>> {
+        let mut violations = Vec::new();
+        {
+            let value = &self.age;
+            if *value < 18 {
+                violations.push(ConstraintViolation { field: "age", keyword: "minimum", limit: "18" });
+            }
+            if *value >= 150 {
+                violations.push(ConstraintViolation { field: "age", keyword: "exclusiveMaximum", limit: "150" });
+            }
+        }
+        if let Some(value) = &self.display_name {
+            if value.chars().count() > 4 {
+                violations.push(ConstraintViolation { field: "display-name", keyword: "maxLength", limit: "4" });
+            }
+        }
+        {
+            let value = &self.name;
+            if value.is_empty() {
+                violations.push(ConstraintViolation { field: "name", keyword: "minLength", limit: "1" });
+            }
+            if value.chars().count() > 8 {
+                violations.push(ConstraintViolation { field: "name", keyword: "maxLength", limit: "8" });
+            }
+        }
+        if let Some(value) = &self.tags {
+            if value.len() > 2 {
+                violations.push(ConstraintViolation { field: "tags", keyword: "maxItems", limit: "2" });
+            }
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// A bound declared by the schema of a field that its value violates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstraintViolation {
+    /// The name of the field in JSON.
+    pub field: &'static str,
+    /// The keyword declaring the bound, such as `maxLength`.
+    pub keyword: &'static str,
+    /// The value of the keyword.
+    pub limit: &'static str,
+}
+
+impl std::fmt::Display for ConstraintViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "`{}` violates `{}: {}`", self.field, self.keyword, self.limit)
+    }
+}
+
@@ -0,0 +1,54 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[serde(tag = "type")]
+#[serde(rename_all = "lowercase")]
+pub enum Event {
+    Receipt(Box<Receipt>),
+    Transfer(Transfer),
+}
+
+impl Event {
+    /// Creates a new [`Event::Receipt`].
+    #[inline]
+    pub fn new_receipt(value: Receipt) -> Self {
+        Self::Receipt(Box::new(value))
+    }
+
+    /// Creates a new [`Event::Transfer`].
+    #[inline]
+    pub fn new_transfer(value: Transfer) -> Self {
+        Self::Transfer(value)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Receipt {
+    pub a1: String,
+    pub b1: String,
+    pub c1: String,
+    pub d1: String,
+    pub e1: String,
+    pub f1: String,
+    pub g1: String,
+    pub h1: String,
+    pub i1: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transfer {
+    pub amount: i64,
+}
+
@@ -0,0 +1,183 @@
+//
+// This file was automatically generated by openrpc-gen.
+//
+// Do not edit it manually and instead edit either the source OpenRPC document,
+// the configuration file, or open an issue or pull request on the openrpc-gen
+// GitHub repository.
+// 
+//     https://github.com/nils-mathieu/openrpc-gen
+//
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+// Variants are tried in order: Name, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11.
+#[serde(untagged)]
+pub enum Pinned {
+    Name(String),
+    V1(V1),
+    V2(V2),
+    V3(V3),
+    V4(V4),
+    V5(V5),
+    V6(V6),
+    V7(V7),
+    V8(V8),
+    V9(V9),
+    V10(V10),
+    V11(V11),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V1 {
+    pub v1: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V10 {
+    pub v10: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V11 {
+    pub v11: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V2 {
+    pub v2: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V3 {
+    pub v3: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V4 {
+    pub v4: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V5 {
+    pub v5: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V6 {
+    pub v6: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V7 {
+    pub v7: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V8 {
+    pub v8: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V9 {
+    pub v9: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+// Variants are tried in order: Confirmed, Pending.
+#[serde(untagged)]
+pub enum Transaction {
+    Confirmed(Confirmed),
+    Pending(Pending),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pending {
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Confirmed {
+    pub block: i64,
+    pub hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone)]
+#[derive(Debug)]
+// Variants are tried in order: U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, Text.
+#[serde(untagged)]
+pub enum Unpinned {
+    U1(U1),
+    U2(U2),
+    U3(U3),
+    U4(U4),
+    U5(U5),
+    U6(U6),
+    U7(U7),
+    U8(U8),
+    U9(U9),
+    U10(U10),
+    U11(U11),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U1 {
+    pub v1: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U10 {
+    pub v10: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U11 {
+    pub v11: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U2 {
+    pub v2: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U3 {
+    pub v3: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U4 {
+    pub v4: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U5 {
+    pub v5: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U6 {
+    pub v6: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U7 {
+    pub v7: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U8 {
+    pub v8: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct U9 {
+    pub v9: i64,
+}
+
@@ -0,0 +1,44 @@
+//! `generation.alias-underlying-note` ends the documentation of type aliases with the name of
+//! the aliased type.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/alias_underlying_note/generated.rs");
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("alias_underlying_note");
+}
+
+#[test]
+fn note_follows_the_description() {
+    let generated = crate::generate("alias_underlying_note").code;
+    assert!(generated.contains(
+        "/// The height of a block.\n///\n/// Alias for `i64`.\npub type BlockNumber = i64;\n"
+    ));
+}
+
+#[test]
+fn undocumented_aliases_get_the_note_alone() {
+    let generated = crate::generate("alias_underlying_note").code;
+    assert!(
+        generated.contains("\n\n/// Alias for `Vec<String>`.\npub type Hashes = Vec<String>;\n")
+    );
+}
+
+#[test]
+fn structs_get_no_note() {
+    let generated = crate::generate("alias_underlying_note").code;
+    assert!(generated
+        .contains("\n\n#[derive(Debug, Clone, Serialize, Deserialize)]\npub struct Block {"));
+}
+
+#[test]
+fn source_links_come_after_the_note() {
+    let generated =
+        crate::generate_with("alias_underlying_note", "source_links.toml", &[]).unwrap();
+    assert!(generated.code.contains(
+        "/// Alias for `Vec<String>`.\n///\n/// Generated from #/components/schemas/Hashes\n"
+    ));
+}
@@ -0,0 +1,47 @@
+//! `generation.allof-flatten-refs` keeps the schemas referenced by `allOf` members as distinct
+//! types, flattened into the resulting struct.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/allof_flatten_refs/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("allof_flatten_refs");
+}
+
+#[test]
+fn base_is_flattened_next_to_the_inline_fields() {
+    let json = r#"{"id":"0x1","timestamp":10,"amount":5}"#;
+    let transfer: Transfer = serde_json::from_str(json).unwrap();
+    assert_eq!(transfer.base.id, "0x1");
+    assert_eq!(transfer.base.timestamp, 10);
+    assert_eq!(transfer.amount, 5);
+    assert_eq!(transfer.memo, None);
+}
+
+#[test]
+fn base_remains_usable_on_its_own() {
+    let base: Base = serde_json::from_str(r#"{"id":"0x2","timestamp":20}"#).unwrap();
+    let transfer = Transfer {
+        base,
+        amount: 1,
+        memo: Some("rent".into()),
+    };
+    assert_eq!(
+        serde_json::to_value(&transfer).unwrap(),
+        serde_json::json!({ "id": "0x2", "timestamp": 20, "amount": 1, "memo": "rent" })
+    );
+}
+
+#[test]
+fn fields_are_merged_by_default() {
+    let generated = crate::generate_with("allof_flatten_refs", "merged.toml", &[]).unwrap();
+    assert!(!generated.code.contains("pub struct Base"));
+    assert!(generated.code.contains(
+        "pub struct Transfer {\n    pub id: String,\n    pub timestamp: i64,\n    pub amount: i64,\n"
+    ));
+}
@@ -0,0 +1,63 @@
+//! With `anon-naming = "content-hash"`, inline schemas without a `title` are named after a hash
+//! of their structure.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/anon_naming/generated.rs");
+}
+
+use generated::*;
+
+/// Returns the names of the types generated from inline schemas, in order of appearance.
+fn anonymous_names(code: &str) -> Vec<&str> {
+    code.lines()
+        .filter_map(|line| line.strip_prefix("pub struct Anon"))
+        .map(|rest| &rest[..rest.find(' ').unwrap()])
+        .collect()
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("anon_naming");
+}
+
+#[test]
+fn names_are_short_hashes() {
+    let generated = crate::generate("anon_naming");
+    let names = anonymous_names(&generated.code);
+    assert_eq!(names.len(), 3);
+    for name in names {
+        let hash = &name[..6];
+        assert!(hash.starts_with(|c: char| c.is_ascii_digit() || c.is_ascii_uppercase()));
+        assert!(hash[1..]
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_lowercase()));
+        assert!(u32::from_str_radix(hash, 16).is_ok(), "{name}");
+    }
+}
+
+#[test]
+fn identical_structures_get_a_suffix() {
+    let block = Block {
+        header: Anon5c9329 { number: 1 },
+    };
+    let receipt = Receipt {
+        header: Anon5c93292 { number: 1 },
+    };
+    assert_eq!(
+        serde_json::to_value(&block).unwrap(),
+        serde_json::to_value(&receipt).unwrap(),
+    );
+}
+
+#[test]
+fn names_survive_reordering() {
+    let reordered = crate::generate_document("anon_naming", "reordered.json");
+    for field in [
+        "pub header: Anon5c9329,",
+        "pub header: Anon5c93292,",
+        "pub origin: AnonA2d556,",
+    ] {
+        assert!(reordered.code.contains(field), "{}", reordered.code);
+    }
+}
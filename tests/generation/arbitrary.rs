@@ -0,0 +1,85 @@
+//! `generation.arbitrary` derives `arbitrary::Arbitrary` for the generated types when the
+//! `arbitrary` feature is enabled.
+//!
+//! This crate declares an `arbitrary` feature, enabled by default, so that the derives are
+//! compiled and exercised here.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/arbitrary/generated.rs");
+}
+
+const DERIVE: &str = "#[cfg_attr(feature = \"arbitrary\", derive(arbitrary::Arbitrary))]\n";
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("arbitrary");
+}
+
+#[test]
+fn every_type_derives_arbitrary() {
+    let generated = crate::generate("arbitrary");
+    for item in [
+        "pub struct Block {",
+        "pub struct Range(",
+        "pub enum Status {",
+    ] {
+        let start = generated.code.find(item).unwrap();
+        let attrs = &generated.code[..start];
+        let attrs = &attrs[attrs.rfind("\n\n").unwrap()..];
+        assert!(attrs.contains(DERIVE), "{item}");
+    }
+}
+
+#[test]
+fn bounded_vecs_respect_their_bound() {
+    let generated = crate::generate("arbitrary");
+    assert!(generated.code.contains(
+        "#[cfg(feature = \"arbitrary\")]\n\
+         impl<'a, T: arbitrary::Arbitrary<'a>, const N: usize> arbitrary::Arbitrary<'a> \
+         for BoundedVec<T, N> {\n"
+    ));
+    assert!(generated
+        .code
+        .contains("        let len = u.int_in_range(0..=N)?;\n"));
+}
+
+#[test]
+fn nothing_is_derived_by_default() {
+    let generated = crate::generate_with("arbitrary", "disabled.toml", &[]).unwrap();
+    assert!(!generated.code.contains("arbitrary"));
+}
+
+/// Builds a value from each of several pseudo-random buffers.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_values<T: for<'a> arbitrary::Arbitrary<'a>>() -> Vec<T> {
+    (0..64u64)
+        .map(|seed| {
+            let bytes = (0..256u64)
+                .map(|i| (seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ i.wrapping_mul(31)) as u8)
+                .collect::<Vec<_>>();
+            T::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap()
+        })
+        .collect()
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_blocks_respect_the_bound_of_their_arrays() {
+    let blocks = arbitrary_values::<generated::Block>();
+    assert!(blocks.iter().all(|block| block.uncles.len() <= 2));
+    assert!(blocks.iter().any(|block| block.uncles.len() == 2));
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_values_are_valid_json_values() {
+    for block in arbitrary_values::<generated::Block>() {
+        let json = serde_json::to_value(&block).unwrap();
+        assert!(matches!(json["status"].as_str(), Some("pending" | "final")));
+        serde_json::from_value::<generated::Block>(json).unwrap();
+    }
+    let statuses = arbitrary_values::<generated::Status>();
+    assert!(statuses.contains(&generated::Status::Pending));
+    assert!(statuses.contains(&generated::Status::Final));
+}
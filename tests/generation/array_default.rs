@@ -0,0 +1,27 @@
+//! Array defaults of primitive items are generated, others fall back to an empty array.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/array_default/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("array_default");
+    assert!(generated.stderr.contains(
+        "default value can't be generated and the field will default to an empty array\n\
+        - path = #/components/schemas/Settings/points\n"
+    ));
+}
+
+#[test]
+fn absent_fields_use_the_defaults() {
+    let settings: Settings = serde_json::from_str("{}").unwrap();
+    assert_eq!(settings.levels, [1, 2, 3]);
+    assert_eq!(settings.tags, ["a"]);
+    assert!(settings.points.is_empty());
+    let settings: Settings = serde_json::from_str(r#"{"levels":[]}"#).unwrap();
+    assert!(settings.levels.is_empty());
+}
@@ -0,0 +1,54 @@
+//! `generation.async-style` chooses between native `async fn`s and the `async-trait` macro for
+//! the methods of the `Client` trait.
+//!
+//! The generated code is not compiled, as the `async-trait` style requires the `async-trait`
+//! crate. The native style is compiled by the `pagination` fixture.
+
+#[test]
+fn async_trait_style_uses_the_macro() {
+    let generated = crate::generate("async_style");
+    assert!(generated.code.contains(
+        "#[async_trait::async_trait]\n\
+         pub trait Client: Sync {\n"
+    ));
+    assert!(!generated.code.contains("async_fn_in_trait"));
+}
+
+#[test]
+fn async_trait_style_requires_send_parameters() {
+    let generated = crate::generate("async_style");
+    assert!(generated.code.contains(
+        "    async fn call<P, R>(&self, method: &str, params: P) -> Result<R, Self::Error>\n    \
+         where\n        \
+         P: Serialize + Send,\n        \
+         R: serde::de::DeserializeOwned;\n"
+    ));
+}
+
+#[test]
+fn async_trait_crate_is_configurable() {
+    let generated = crate::generate_with("async_style", "custom_crate.toml", &[]).unwrap();
+    assert!(generated
+        .code
+        .contains("#[::rpc::async_trait::async_trait]\npub trait Client: Sync {\n"));
+}
+
+#[test]
+fn native_style_uses_async_fn_in_traits() {
+    let generated = crate::generate_with("async_style", "native.toml", &[]).unwrap();
+    assert!(generated
+        .code
+        .contains("#[allow(async_fn_in_trait)]\npub trait Client {\n"));
+    assert!(generated.code.contains("        P: Serialize,\n"));
+    assert!(!generated.code.contains("async_trait]"));
+}
+
+#[test]
+fn methods_are_written_the_same_way_in_both_styles() {
+    const METHOD: &str = "    async fn eth_get_balance(&self, params: EthGetBalanceParams) \
+                          -> Result<i64, Self::Error> {\n        \
+                          self.call(\"eth_getBalance\", params).await\n    }\n";
+    assert!(crate::generate("async_style").code.contains(METHOD));
+    let native = crate::generate_with("async_style", "native.toml", &[]).unwrap();
+    assert!(native.code.contains(METHOD));
+}
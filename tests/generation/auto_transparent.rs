@@ -0,0 +1,32 @@
+//! Structs with a single required field serialize as that field when `auto-transparent` is on.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/auto_transparent/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("auto_transparent");
+    assert_eq!(generated.code.matches("#[serde(transparent)]").count(), 1);
+}
+
+#[test]
+fn one_field_struct_is_the_bare_value() {
+    let hash = Hash {
+        value: "0x1".into(),
+    };
+    assert_eq!(serde_json::to_string(&hash).unwrap(), r#""0x1""#);
+    let hash: Hash = serde_json::from_str(r#""0x2""#).unwrap();
+    assert_eq!(hash.value, "0x2");
+}
+
+#[test]
+fn optional_field_is_not_transparent() {
+    let label = Label {
+        text: Some("a".into()),
+    };
+    assert_eq!(serde_json::to_string(&label).unwrap(), r#"{"text":"a"}"#);
+}
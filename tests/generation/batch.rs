@@ -0,0 +1,106 @@
+//! `--batch` runs the jobs listed by a TOML manifest.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Copies the `batch` fixture to a fresh directory named `name` and writes the provided
+/// manifest next to it, returning that directory.
+fn setup(name: &str, manifest: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("nested")).unwrap();
+    let fixture = crate::fixture_dir("batch");
+    for file in ["openrpc.json", "config.toml", "nested/config.toml"] {
+        std::fs::copy(fixture.join(file), dir.join(file)).unwrap();
+    }
+    std::fs::write(dir.join("manifest.toml"), manifest).unwrap();
+    dir
+}
+
+/// Runs `openrpc-gen` on the manifest of the provided directory, from its parent directory so
+/// that paths only resolve when taken relative to the manifest.
+fn run_batch(dir: &Path, args: &[&str]) -> Output {
+    let manifest = Path::new(dir.file_name().unwrap()).join("manifest.toml");
+    Command::new(env!("CARGO_BIN_EXE_openrpc-gen"))
+        .current_dir(dir.parent().unwrap())
+        .arg("--batch")
+        .arg(manifest)
+        .args(args)
+        .output()
+        .expect("failed to run openrpc-gen")
+}
+
+const TWO_JOBS: &str = r#"
+[[job]]
+config = "config.toml"
+document = "openrpc.json"
+output = "first.rs"
+
+[[job]]
+config = "nested/config.toml"
+document = "openrpc.json"
+output = "nested/second.rs"
+"#;
+
+#[test]
+fn paths_are_relative_to_the_manifest() {
+    let dir = setup("batch-relative", TWO_JOBS);
+    let output = run_batch(&dir, &[]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+
+    let first = std::fs::read_to_string(dir.join("first.rs")).unwrap();
+    assert!(first.contains("pub value: i64,"));
+    let second = std::fs::read_to_string(dir.join("nested/second.rs")).unwrap();
+    assert!(second.contains("pub value: u32,"));
+}
+
+#[test]
+fn missing_output_is_reported() {
+    let manifest = r#"
+[[job]]
+config = "config.toml"
+document = "openrpc.json"
+output = "first.rs"
+
+[[job]]
+config = "nested/config.toml"
+document = "openrpc.json"
+"#;
+    let dir = setup("batch-missing-output", manifest);
+    let output = run_batch(&dir, &[]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("job 2 has no `output`"), "{stderr}");
+    // The manifest is rejected as a whole, before any job runs.
+    assert!(!dir.join("first.rs").exists());
+
+    // The output is not needed by a dry run.
+    let output = run_batch(&dir, &["--dry-run"]);
+    assert!(output.status.success());
+}
+
+#[test]
+fn documents_are_parsed_once() {
+    let dir = setup("batch-shared", TWO_JOBS);
+    let output = run_batch(&dir, &["--dry-run"]);
+    assert!(output.status.success());
+
+    // The document emits a single warning when loaded, which is printed once but counted by
+    // both jobs.
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(
+        stderr.matches("`$dynamicRef` approximated").count(),
+        1,
+        "{stderr}"
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout,
+        "1 types (0 aliases), 1 methods, 1 warnings\n\
+         1 types (0 aliases), 1 methods, 1 warnings\n",
+    );
+}
@@ -0,0 +1,60 @@
+//! `generation.bounded-vec` represents arrays with `maxItems` as `BoundedVec`s, which reject
+//! arrays holding more items than allowed when deserialized.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/bounded_vec/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("bounded_vec");
+}
+
+fn batch(hashes: serde_json::Value) -> Result<Batch, serde_json::Error> {
+    serde_json::from_value(json!({ "hashes": hashes }))
+}
+
+#[test]
+fn arrays_at_the_cap_are_accepted() {
+    let batch = batch(json!(["a", "b", "c"])).unwrap();
+    assert_eq!(&*batch.hashes, ["a", "b", "c"]);
+}
+
+#[test]
+fn arrays_past_the_cap_are_rejected() {
+    let err = batch(json!(["a", "b", "c", "d"])).err().unwrap();
+    assert!(err
+        .to_string()
+        .contains("expected at most 3 items, found 4"));
+}
+
+#[test]
+fn optional_arrays_are_capped() {
+    let ok = json!({ "hashes": [], "tags": [1, 2] });
+    assert_eq!(
+        &*serde_json::from_value::<Batch>(ok).unwrap().tags.unwrap(),
+        [1, 2]
+    );
+    let err = json!({ "hashes": [], "tags": [1, 2, 3] });
+    assert!(serde_json::from_value::<Batch>(err).is_err());
+}
+
+#[test]
+fn arrays_without_max_items_are_plain_vecs() {
+    let batch: Batch =
+        serde_json::from_value(json!({ "hashes": [], "notes": ["a", "b", "c", "d"] })).unwrap();
+    assert_eq!(batch.notes.unwrap().len(), 4);
+}
+
+#[test]
+fn new_checks_the_cap() {
+    assert!(BoundedVec::<i64, 2>::new(vec![1, 2]).is_ok());
+    assert_eq!(
+        BoundedVec::<i64, 2>::new(vec![1, 2, 3]).err(),
+        Some(vec![1, 2, 3])
+    );
+}
@@ -0,0 +1,37 @@
+//! `generation.capture-extensions` preserves the `x-` properties of objects.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/capture_extensions/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("capture_extensions");
+    assert!(generated
+        .code
+        .contains("    pub extensions: std::collections::HashMap<String, serde_json::Value>,\n"));
+}
+
+#[test]
+fn extensions_round_trip() {
+    let json = json!({ "hash": "0x1", "number": 2, "x-miner": "alice", "x-fee": 3 });
+    let block: Block = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(block.extensions.len(), 2);
+    assert_eq!(block.extensions["x-fee"], 3);
+    assert_eq!(serde_json::to_value(&block).unwrap(), json);
+}
+
+#[test]
+fn other_properties_are_ignored() {
+    let json = json!({ "hash": "0x1", "number": 2, "miner": "alice" });
+    let block: Block = serde_json::from_value(json).unwrap();
+    assert!(block.extensions.is_empty());
+    assert_eq!(
+        serde_json::to_value(&block).unwrap(),
+        json!({ "hash": "0x1", "number": 2 }),
+    );
+}
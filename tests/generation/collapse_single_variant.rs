@@ -0,0 +1,23 @@
+//! A single-member `oneOf` becomes a transparent newtype rather than an enum.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/collapse_single_variant/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("collapse_single_variant");
+    assert!(generated
+        .code
+        .contains("#[serde(transparent)]\npub struct Balance(pub i64);"));
+}
+
+#[test]
+fn newtype_is_transparent() {
+    let balance: Balance = serde_json::from_str("12").unwrap();
+    assert_eq!(balance.0, 12);
+    assert_eq!(serde_json::to_string(&balance).unwrap(), "12");
+}
@@ -0,0 +1,57 @@
+//! `generation.common-flatten` flattens the listed struct types into the structs referencing
+//! them.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/common_flatten/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("common_flatten");
+}
+
+#[test]
+fn referencing_structs_are_flat_on_the_wire() {
+    let json = r#"{"number":3,"id":"0x1","createdAt":100}"#;
+    let block: Block = serde_json::from_str(json).unwrap();
+    assert_eq!(block.number, 3);
+    assert_eq!(block.metadata.id, "0x1");
+    assert_eq!(block.metadata.createdat, 100);
+    assert_eq!(
+        serde_json::to_value(&block).unwrap(),
+        serde_json::json!({ "number": 3, "id": "0x1", "createdAt": 100 })
+    );
+}
+
+#[test]
+fn arrays_of_the_common_type_stay_nested() {
+    let json = r#"{"entries":[{"id":"0x1","createdAt":100}]}"#;
+    let history: History = serde_json::from_str(json).unwrap();
+    assert_eq!(history.entries[0].id, "0x1");
+}
+
+#[test]
+fn rejects_colliding_fields() {
+    let err = crate::generate_document_with("common_flatten", "collision.json", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't flatten common type: field already exists:\n\
+         - path = #/components/schemas/Metadata\n\
+         - field = #/components/schemas/Receipt/id\n"
+    ));
+}
+
+#[test]
+fn rejects_unknown_structs() {
+    let err = crate::generate_with("common_flatten", "missing.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't flatten common type: struct not found:\n\
+         - path = #/components/schemas/Missing\n"
+    ));
+}
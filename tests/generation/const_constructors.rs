@@ -0,0 +1,38 @@
+//! `generation.const-constructors` generates `const fn new` constructors for newtypes over
+//! `Copy` types.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/const_constructors/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("const_constructors");
+}
+
+const GENESIS: BlockHeight = BlockHeight::new(0);
+const TRANSFER_GAS: Gas = Gas::new(21_000);
+static ENABLED: Enabled = Enabled::new(true);
+
+#[test]
+fn newtypes_are_constructible_in_const_items() {
+    assert_eq!(GENESIS.height, 0);
+    assert_eq!(TRANSFER_GAS.0, 21_000);
+    assert!(ENABLED.0);
+}
+
+#[test]
+fn tables_of_newtypes_are_constructible() {
+    const CHECKPOINTS: [BlockHeight; 2] = [BlockHeight::new(100), BlockHeight::new(200)];
+    assert_eq!(CHECKPOINTS[1].height, 200);
+}
+
+#[test]
+fn other_types_get_no_constructor() {
+    let generated = crate::generate("const_constructors").code;
+    assert!(!generated.contains("impl Name {"));
+    assert!(!generated.contains("impl Status {"));
+}
@@ -0,0 +1,36 @@
+//! Methods referencing a shared content descriptor share its type and documentation, and that
+//! type is named after the descriptor.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/content_descriptors/generated.rs");
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("content_descriptors");
+    assert!(generated.code.contains("pub struct Block {"));
+    assert!(generated.code.contains("pub struct Filter {"));
+    assert!(!generated.code.contains("Anonymous"));
+    for method in ["GetBlock", "GetLatestBlock"] {
+        assert!(generated.code.contains(&format!(
+            "/// The requested block.\n///\n/// Result type of `{}`.\npub type {method}Result = Block;",
+            method[..1].to_lowercase() + &method[1..],
+        )));
+    }
+}
+
+#[test]
+fn result_types_are_shared() {
+    let block: generated::GetBlockResult = serde_json::from_str(r#"{"number":3}"#).unwrap();
+    let latest: generated::GetLatestBlockResult = block;
+    assert_eq!(latest.number, 3);
+}
+
+#[test]
+fn params_use_the_descriptor_type() {
+    let params: generated::GetLatestBlockParams =
+        serde_json::from_str(r#"{"filter":{"finalized":true}}"#).unwrap();
+    let filter: generated::Filter = params.filter.unwrap();
+    assert_eq!(filter.finalized, Some(true));
+}
@@ -0,0 +1,24 @@
+//! JSON-encoded strings with a `contentSchema` become the type of that schema.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/content_schema/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("content_schema");
+    assert!(generated.code.contains("pub struct Payload {"));
+}
+
+#[test]
+fn decodes_the_inner_json() {
+    let envelope: Envelope = serde_json::from_str(r#"{"payload":"{\"amount\":3}"}"#).unwrap();
+    assert_eq!(envelope.payload.amount, 3);
+    assert_eq!(
+        serde_json::to_string(&envelope).unwrap(),
+        r#"{"payload":"{\"amount\":3}"}"#
+    );
+}
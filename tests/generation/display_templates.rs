@@ -0,0 +1,67 @@
+//! `generation.display-templates` implements `Display` for the listed structs from templates
+//! naming their fields.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/display_templates/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("display_templates");
+}
+
+#[test]
+fn placeholders_name_fields_as_in_json() {
+    let block = Block {
+        id: "0xab".into(),
+        blockheight: 12,
+    };
+    assert_eq!(block.to_string(), "0xab@12");
+}
+
+#[test]
+fn templates_support_format_specs_and_escapes() {
+    let account = Account {
+        name: "alice".into(),
+        balance: 42,
+    };
+    assert_eq!(account.to_string(), "\"alice\" {    42}");
+}
+
+#[track_caller]
+fn assert_rejected(config: &str, message: &str) {
+    let err = crate::generate_with("display_templates", config, &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(message), "{err}");
+}
+
+#[test]
+fn rejects_unknown_fields() {
+    assert_rejected(
+        "unknown_field.toml",
+        "can't generate `Display`: field not found:\n\
+         - path = #/components/schemas/Block\n\
+         - field = height\n",
+    );
+}
+
+#[test]
+fn rejects_types_that_are_not_structs() {
+    assert_rejected(
+        "not_a_struct.toml",
+        "can't generate `Display`: struct not found:\n- path = #/components/schemas/Hash\n",
+    );
+}
+
+#[test]
+fn rejects_malformed_templates() {
+    assert_rejected(
+        "unclosed.toml",
+        "invalid template for `#/components/schemas/Block` in \
+         `generation.display-templates`: unclosed `{`",
+    );
+}
@@ -0,0 +1,26 @@
+//! `--document-pointer` selects an OpenRPC document nested in a larger JSON file.
+
+#[test]
+fn parses_nested_document() {
+    let generated = crate::generate_with(
+        "document_pointer",
+        "config.toml",
+        &["--document-pointer", "/deploy/api"],
+    )
+    .unwrap();
+    assert!(generated
+        .code
+        .contains("pub struct Point {\n    pub x: i64,\n}\n"));
+}
+
+#[test]
+fn missing_pointer_is_reported() {
+    let err = crate::generate_with(
+        "document_pointer",
+        "config.toml",
+        &["--document-pointer", "/deploy/missing"],
+    )
+    .err()
+    .unwrap();
+    assert!(err.contains("no value at `/deploy/missing`"));
+}
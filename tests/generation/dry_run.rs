@@ -0,0 +1,57 @@
+//! `--dry-run` generates the code without writing it, printing the number of generated items.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// Runs `openrpc-gen` in dry-run mode on the provided document of the `dry_run` fixture.
+fn dry_run(document: &str, args: &[&str]) -> Output {
+    let dir = crate::fixture_dir("dry_run");
+    Command::new(env!("CARGO_BIN_EXE_openrpc-gen"))
+        .arg("--config")
+        .arg(dir.join("config.toml"))
+        .arg("--document")
+        .arg(dir.join(document))
+        .arg("--dry-run")
+        .args(args)
+        .output()
+        .expect("failed to run openrpc-gen")
+}
+
+#[test]
+fn reports_counts() {
+    let output = dry_run("openrpc.json", &[]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2 types (1 aliases), 2 methods, 0 warnings\n"
+    );
+}
+
+#[test]
+fn counts_warnings() {
+    let output = dry_run("openrpc.json", &["--only", "get*", "--only", "missing"]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2 types (1 aliases), 1 methods, 1 warnings\n"
+    );
+}
+
+#[test]
+fn writes_nothing() {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("dry_run-output.rs");
+    let _ = std::fs::remove_file(&path);
+    let output = dry_run("openrpc.json", &["--output", path.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert!(!path.exists());
+}
+
+#[test]
+fn fails_on_invalid_documents() {
+    let output = dry_run("broken.json", &[]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("reference `#/components/schemas/Block` not found"));
+}
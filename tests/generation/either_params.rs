@@ -0,0 +1,60 @@
+//! `generation.either-params` pins how the parameters of methods accepting both structures are
+//! deserialized.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/either_params/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("either_params");
+}
+
+#[test]
+fn pinned_structure_is_deserialized() {
+    let params: GetBalanceParams = serde_json::from_str(r#"["0x1", 2]"#).unwrap();
+    assert_eq!(params.address, "0x1");
+    assert_eq!(params.block, Some(2));
+}
+
+#[test]
+fn other_structure_is_rejected() {
+    let err = serde_json::from_str::<GetBalanceParams>(r#"{"address":"0x1","block":2}"#)
+        .err()
+        .unwrap();
+    assert!(err
+        .to_string()
+        .contains("invalid type: map, expected the parameters for `getBalance`"));
+}
+
+#[test]
+fn methods_with_a_single_structure_are_unaffected() {
+    let generated = crate::generate_with("either_params", "by_name.toml", &[]).unwrap();
+    assert!(!generated
+        .code
+        .contains("deserializer.deserialize_any(Visitor)"));
+    assert!(!generated
+        .code
+        .contains("deserializer.deserialize_seq(Visitor)"));
+    assert_eq!(
+        generated
+            .code
+            .matches("deserializer.deserialize_map(Visitor)")
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn any_is_the_default() {
+    let generated = crate::generate_with("either_params", "any.toml", &[]).unwrap();
+    assert!(generated
+        .code
+        .contains("deserializer.deserialize_any(Visitor)"));
+    assert!(generated
+        .code
+        .contains("deserializer.deserialize_map(Visitor)"));
+}
@@ -0,0 +1,22 @@
+//! With `enum-defaults`, enums implement `Default` using the variant of the schema's `default`.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/enum_defaults/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("enum_defaults");
+}
+
+#[test]
+fn default_is_the_schema_default() {
+    assert_eq!(BlockTag::default(), BlockTag::Latest);
+    assert_eq!(
+        serde_json::to_string(&BlockTag::default()).unwrap(),
+        r#""latest""#
+    );
+}
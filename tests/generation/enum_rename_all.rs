@@ -0,0 +1,49 @@
+//! String enums whose values share a casing get a single `rename_all` attribute, and only the
+//! variants it doesn't cover are renamed.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/enum_rename_all/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("enum_rename_all");
+    assert_eq!(
+        generated
+            .code
+            .matches("#[serde(rename_all = \"SCREAMING_SNAKE_CASE\")]")
+            .count(),
+        1
+    );
+    assert_eq!(generated.code.matches("#[serde(rename = ").count(), 1);
+    assert!(generated
+        .code
+        .contains("    Stable,\n    #[serde(rename = \"1\")]\n    V1,\n"));
+}
+
+#[test]
+fn numeric_value_is_renamed() {
+    assert_eq!(serde_json::to_string(&Version::V1).unwrap(), r#""1""#);
+    assert_eq!(
+        serde_json::from_str::<Version>(r#""stable""#).unwrap(),
+        Version::Stable
+    );
+}
+
+#[test]
+fn round_trips_wire_values() {
+    for (status, json) in [
+        (Status::NotReceived, "\"NOT_RECEIVED\""),
+        (Status::Received, "\"RECEIVED\""),
+        (Status::Pending, "\"PENDING\""),
+        (Status::AcceptedOnL2, "\"ACCEPTED_ON_L2\""),
+        (Status::AcceptedOnL1, "\"ACCEPTED_ON_L1\""),
+        (Status::Rejected, "\"REJECTED\""),
+    ] {
+        assert_eq!(serde_json::to_string(&status).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Status>(json).unwrap(), status);
+    }
+}
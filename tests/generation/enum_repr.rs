@@ -0,0 +1,53 @@
+//! `generation.enum-repr` sets the representation of the listed enums.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/enum_repr/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("enum_repr");
+}
+
+#[test]
+fn enum_uses_the_representation() {
+    assert_eq!(std::mem::size_of::<Status>(), 1);
+    assert_eq!(Status::Pending as u8, 0);
+    assert_eq!(Status::Rejected as u8, 2);
+}
+
+#[test]
+fn representation_does_not_change_serialization() {
+    assert_eq!(
+        serde_json::to_string(&Status::Accepted).unwrap(),
+        "\"accepted\""
+    );
+}
+
+#[test]
+fn rejects_discriminants_that_do_not_fit() {
+    let err = crate::generate_with("enum_repr", "out_of_range.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't set the representation: discriminants don't fit:\n\
+         - path = #/components/schemas/Opcode\n\
+         - repr = i8\n\
+         - variants = 129\n"
+    ));
+    assert!(!err.contains("- path = #/components/schemas/Status\n"));
+}
+
+#[test]
+fn rejects_invalid_representations() {
+    let err = crate::generate_with("enum_repr", "invalid.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "invalid representation `byte` for `#/components/schemas/Status` in \
+         `generation.enum-repr`: expected `C` or a primitive integer type such as `u8`"
+    ));
+}
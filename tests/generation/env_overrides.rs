@@ -0,0 +1,85 @@
+//! The `OPENRPC_GEN_*` environment variables override the boolean options of the configuration.
+
+use std::process::Command;
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/env_overrides/generated.rs");
+}
+
+/// The variables documented in `--help` and in the README.
+const VARIABLES: &[&str] = &[
+    "OPENRPC_GEN_USE_CORE",
+    "OPENRPC_GEN_NO_STD",
+    "OPENRPC_GEN_METHOD_NAME_CONSTANTS",
+    "OPENRPC_GEN_RESULT_TYPES",
+    "OPENRPC_GEN_PARAM_TYPES",
+    "OPENRPC_GEN_ERROR_TYPES",
+    "OPENRPC_GEN_CLIENT_TRAIT",
+    "OPENRPC_GEN_TRACING",
+];
+
+fn generate_with_env(envs: &[(&str, &str)]) -> Result<crate::Generated, String> {
+    crate::generate_with_env("env_overrides", "config.toml", &[], envs)
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("env_overrides");
+}
+
+#[test]
+fn false_disables_an_option() {
+    let generated = generate_with_env(&[("OPENRPC_GEN_RESULT_TYPES", "false")]).unwrap();
+    assert!(!generated.code.contains("GetBlockResult"));
+}
+
+#[test]
+fn true_enables_an_option() {
+    let generated = generate_with_env(&[("OPENRPC_GEN_ERROR_TYPES", "true")]).unwrap();
+    assert!(generated.code.contains("pub enum Error {"));
+    assert!(generated.code.contains("pub type GetBlockResult = Block;"));
+}
+
+#[test]
+fn overridden_configuration_is_validated() {
+    let err = generate_with_env(&[("OPENRPC_GEN_CLIENT_TRAIT", "true")])
+        .err()
+        .unwrap();
+    assert!(err.contains("`generation.client-trait` requires `generation.param-types`"));
+}
+
+#[test]
+fn rejects_other_values() {
+    let err = generate_with_env(&[("OPENRPC_GEN_RESULT_TYPES", "yes")])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "invalid value \"yes\" for `OPENRPC_GEN_RESULT_TYPES`: expected `true` or `false`"
+    ));
+}
+
+#[test]
+fn variables_are_documented_in_help() {
+    let output = Command::new(env!("CARGO_BIN_EXE_openrpc-gen"))
+        .arg("--help")
+        .output()
+        .unwrap();
+    let help = String::from_utf8(output.stdout).unwrap();
+    assert!(help.contains("Environment variables:"));
+    for var in VARIABLES {
+        assert!(help.contains(var), "`{var}` is missing from `--help`");
+    }
+}
+
+#[test]
+fn variables_are_documented_in_readme() {
+    let readme =
+        std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md")).unwrap();
+    for var in VARIABLES {
+        assert!(
+            readme.contains(&format!("`{var}`")),
+            "`{var}` is missing from the README"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! `generation.eq-ignore-fields` implements `PartialEq` manually for the listed structs,
+//! ignoring the listed fields.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/eq_ignore_fields/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("eq_ignore_fields");
+}
+
+fn response(requestid: i64, timestamp: i64, result: &str) -> Response {
+    Response {
+        error: None,
+        requestid,
+        result: Some(result.into()),
+        timestamp,
+    }
+}
+
+#[test]
+fn ignored_fields_are_not_compared() {
+    assert_eq!(response(1, 100, "0x1"), response(2, 200, "0x1"));
+}
+
+#[test]
+fn other_fields_are_compared() {
+    assert_ne!(response(1, 100, "0x1"), response(1, 100, "0x2"));
+    let mut failed = response(1, 100, "0x1");
+    failed.error = Some("reverted".into());
+    assert_ne!(failed, response(1, 100, "0x1"));
+}
+
+#[test]
+fn structs_without_compared_fields_are_always_equal() {
+    assert_eq!(Heartbeat { timestamp: 1 }, Heartbeat { timestamp: 2 });
+}
+
+#[test]
+fn rejects_float_hash_structs() {
+    let err = crate::generate_with("eq_ignore_fields", "float_hash.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "`#/components/schemas/Response` can't be in both `generation.float-hash` and \
+         `generation.eq-ignore-fields`"
+    ));
+}
@@ -0,0 +1,35 @@
+//! `generation.error-code-constants` generates an `error_codes` module naming the standard
+//! JSON-RPC error codes and the codes of the declared errors.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/error_code_constants/generated.rs");
+}
+
+use generated::{error_codes, Error};
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("error_code_constants");
+}
+
+#[test]
+fn declared_codes_are_named_after_their_errors() {
+    assert_eq!(error_codes::BLOCK_NOT_FOUND, 4001);
+    assert_eq!(error_codes::INVALID_BLOCK_NUMBER, -32602);
+    assert_eq!(Error::BlockNotFound.code(), error_codes::BLOCK_NOT_FOUND);
+}
+
+#[test]
+fn standard_codes_are_always_included() {
+    assert_eq!(error_codes::PARSE_ERROR, -32700);
+    assert_eq!(error_codes::INVALID_REQUEST, -32600);
+    assert_eq!(error_codes::METHOD_NOT_FOUND, -32601);
+    assert_eq!(error_codes::INTERNAL_ERROR, -32603);
+}
+
+#[test]
+fn declared_errors_replace_standard_codes() {
+    let generated = crate::generate("error_code_constants");
+    assert!(!generated.code.contains("INVALID_PARAMS"));
+}
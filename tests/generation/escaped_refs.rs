@@ -0,0 +1,33 @@
+//! References are percent-decoded and their `~0` and `~1` escapes are unescaped before being
+//! looked up.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/escaped_refs/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("escaped_refs");
+    assert!(generated.stderr.is_empty(), "{}", generated.stderr);
+}
+
+#[test]
+fn escaped_schema_refs_resolve() {
+    // `pkg~1Block` refers to `pkg/Block`, `Block%20Header` to `Block Header` and `a~0b` to
+    // `a~b`.
+    let block: PkgBlock =
+        serde_json::from_str(r#"{"header":{"hash":"0x1"},"range":[1,2]}"#).unwrap();
+    let BlockHeader { hash } = block.header;
+    let AB(start, end) = block.range;
+    assert_eq!((hash.as_str(), start, end), ("0x1", 1, 2));
+}
+
+#[test]
+fn escaped_errors_and_content_descriptors_resolve() {
+    assert_eq!(Error::RpcNotFound.code(), 1);
+    let params = GetBlockParams { number: 1 };
+    assert_eq!(serde_json::to_value(params).unwrap()["number"], 1);
+}
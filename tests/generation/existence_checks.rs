@@ -0,0 +1,47 @@
+//! `generation.emit-existence-checks` names every generated type in a test-only constant.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/existence_checks/generated.rs");
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("existence_checks");
+}
+
+#[test]
+fn every_type_is_checked_once() {
+    let generated = crate::generate("existence_checks");
+    let mut defined = generated
+        .code
+        .lines()
+        .filter_map(|line| {
+            let rest = line
+                .strip_prefix("pub struct ")
+                .or_else(|| line.strip_prefix("pub enum "))
+                .or_else(|| line.strip_prefix("pub type "))?;
+            rest.split(|c: char| !c.is_alphanumeric()).next()
+        })
+        .collect::<Vec<_>>();
+    let mut checked = generated
+        .code
+        .lines()
+        .filter_map(|line| {
+            line.strip_prefix("    let _: Option<")?
+                .strip_suffix("> = None;")
+        })
+        .collect::<Vec<_>>();
+    defined.sort_unstable();
+    checked.sort_unstable();
+    assert_eq!(checked, ["BlockResult", "Hash", "Range", "Status"]);
+    assert_eq!(checked, defined);
+}
+
+#[test]
+fn checks_only_exist_in_tests() {
+    let generated = crate::generate("existence_checks");
+    assert!(generated
+        .code
+        .contains("#[cfg(test)]\nconst _: fn() = || {\n"));
+}
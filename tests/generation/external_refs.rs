@@ -0,0 +1,28 @@
+//! `--cargo-emit` lists the files targeted by external references along with the inputs.
+
+#[test]
+fn rerun_list_includes_external_ref_files() {
+    let generated =
+        crate::generate_with("external_refs", "config.toml", &["--cargo-emit"]).unwrap();
+    let dir = crate::fixture_dir("external_refs");
+    for file in ["config.toml", "openrpc.json", "types.json"] {
+        let line = format!("cargo:rerun-if-changed={}\n", dir.join(file).display());
+        assert!(generated.stdout.contains(&line), "{}", generated.stdout);
+    }
+}
+
+#[test]
+fn rerun_list_follows_the_document_pointer() {
+    let generated = crate::generate_document_with(
+        "external_refs",
+        "nested.json",
+        &["--cargo-emit", "--document-pointer", "/apis/felt"],
+    )
+    .unwrap();
+    let dir = crate::fixture_dir("external_refs");
+    let line = |file: &str| format!("cargo:rerun-if-changed={}\n", dir.join(file).display());
+    assert!(generated.stdout.contains(&line("nested.json")));
+    assert!(generated.stdout.contains(&line("types.json")));
+    // Only referenced outside of the selected document.
+    assert!(!generated.stdout.contains("unused.json"));
+}
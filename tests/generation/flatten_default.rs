@@ -0,0 +1,25 @@
+//! Flattened structs whose fields are all optional default when they are absent.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/flatten_default/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("flatten_default");
+    assert!(generated
+        .code
+        .contains("    #[serde(flatten)]\n    #[serde(default)]\n    pub paging: Paging,\n"));
+}
+
+#[test]
+fn omitted_base_is_defaulted() {
+    let query: Query = serde_json::from_str(r#"{"address":"0x1"}"#).unwrap();
+    assert_eq!(query.paging.limit, None);
+    assert_eq!(query.paging.cursor, None);
+    let query: Query = serde_json::from_str(r#"{"address":"0x1","limit":10}"#).unwrap();
+    assert_eq!(query.paging.limit, Some(10));
+}
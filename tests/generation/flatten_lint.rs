@@ -0,0 +1,25 @@
+//! Flattened fields sharing a key are reported, as only one of them gets the value.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/flatten_lint/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("flatten_lint");
+    assert!(generated.stderr.contains(
+        "flattened field shares the key `hash` with another field, which will prevent one of \
+        them from being deserialized\n\
+        - path = #/components/schemas/Block/field1\n\
+        - other = #/components/schemas/Block/field0\n"
+    ));
+}
+
+#[test]
+fn shared_key_fails_to_deserialize() {
+    let json = r#"{"hash":"0x1","number":1,"size":2}"#;
+    assert!(serde_json::from_str::<Block>(json).is_err());
+}
@@ -0,0 +1,38 @@
+//! Structs listed in `float-hash` compare and hash their floats by bit patterns.
+
+use std::collections::HashSet;
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/float_hash/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("float_hash");
+}
+
+#[test]
+fn equal_structs_are_one_set_entry() {
+    let segment = || Segment {
+        start: Point { x: 0.0, y: 1.5 },
+        end: Point { x: 2.0, y: -1.0 },
+        weights: Some(vec![0.5]),
+    };
+    let set = [segment(), segment()].into_iter().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 1);
+
+    let mut other = segment();
+    other.end.y = 3.0;
+    assert!(!set.contains(&other));
+}
+
+#[test]
+fn unlisted_nested_struct_is_reported() {
+    let err = crate::generate_with("float_hash", "unlisted.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains("- field = start\n- type = #/components/schemas/Point\n"));
+}
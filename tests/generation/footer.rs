@@ -0,0 +1,57 @@
+//! `generation.footer` ends the generated file with a comment recording the version of
+//! openrpc-gen and the hash of the input document.
+
+/// Returns the FNV-1a hash of the provided bytes.
+fn fnv(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[test]
+fn footer_records_the_version_and_document_hash() {
+    let document = std::fs::read(crate::fixture_dir("footer").join("openrpc.json")).unwrap();
+    let generated = crate::generate("footer").code;
+    assert!(generated.ends_with(&format!(
+        "    pub number: i64,\n}}\n\n\
+         //\n\
+         // Generated by openrpc-gen {}.\n\
+         // Document hash (FNV-1a): {:016x}\n\
+         //\n",
+        env!("CARGO_PKG_VERSION"),
+        fnv(&document),
+    )));
+}
+
+#[test]
+fn footer_is_deterministic_by_default() {
+    let generated = crate::generate("footer").code;
+    assert!(!generated.contains("// Generated at:"));
+    assert_eq!(generated, crate::generate("footer").code);
+}
+
+#[test]
+fn timestamp_is_opt_in() {
+    let generated = crate::generate_with("footer", "timestamp.toml", &[]).unwrap();
+    let line = generated
+        .code
+        .lines()
+        .find_map(|line| line.strip_prefix("// Generated at: "))
+        .unwrap();
+    let secs = line.strip_suffix(" (Unix time)").unwrap();
+    assert!(secs.parse::<u64>().unwrap() > 0);
+}
+
+#[test]
+fn timestamp_requires_the_footer() {
+    let err = crate::generate_with("footer", "timestamp_only.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains("`generation.footer-timestamp` requires `generation.footer`"));
+}
+
+#[test]
+fn footer_is_omitted_by_default() {
+    let generated = crate::generate("only").code;
+    assert!(!generated.contains("// Document hash"));
+}
@@ -0,0 +1,36 @@
+//! The `Id` type round-trips numeric, string and null identifiers.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/id_type/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("id_type");
+}
+
+#[track_caller]
+fn round_trip(json: &str, expected: Id) {
+    let id: Id = serde_json::from_str(json).unwrap();
+    assert_eq!(id, expected);
+    assert_eq!(serde_json::to_string(&id).unwrap(), json);
+}
+
+#[test]
+fn numeric_id() {
+    round_trip("42", Id::Unsigned(42));
+    round_trip("-1", Id::Signed(-1));
+}
+
+#[test]
+fn string_id() {
+    round_trip(r#""abc""#, Id::String("abc".into()));
+}
+
+#[test]
+fn null_id() {
+    round_trip("null", Id::Null);
+}
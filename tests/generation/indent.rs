@@ -0,0 +1,53 @@
+//! `generation.indent` changes the indentation of the generated code, but not the text of its
+//! documentation.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/indent/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("indent");
+    assert!(generated.code.contains(
+        "pub struct Block {\n  /// The hash of the block.\n  ///   Always hex-encoded.\n  pub hash: String,\n"
+    ));
+    assert!(generated
+        .code
+        .contains("  {\n    let mut map = serializer.serialize_map(None)?;\n"));
+}
+
+#[test]
+fn documentation_is_left_alone() {
+    let generated = crate::check_fixture("indent");
+    assert!(generated
+        .code
+        .contains("/// Read it with:\n///\n///     let block = client.get_block(number)?;\n"));
+    assert!(generated
+        .code
+        .contains("  /// The number of the block, such as:\n  ///\n  ///     0x1f\n  ///\n"));
+}
+
+#[test]
+fn tabs() {
+    let generated = crate::generate_with("indent", "tabs.toml", &[]).unwrap();
+    assert!(generated.code.contains(
+        "pub struct Block {\n\t/// The hash of the block.\n\t///   Always hex-encoded.\n\tpub hash: String,\n"
+    ));
+    assert!(generated
+        .code
+        .contains("\t{\n\t\tlet mut map = serializer.serialize_map(None)?;\n"));
+    assert!(generated
+        .code
+        .contains("\t/// The number of the block, such as:\n\t///\n\t///     0x1f\n\t///\n"));
+    assert!(!generated.code.contains("\n "));
+}
+
+#[test]
+fn params_round_trip() {
+    let params: GetBlockParams = serde_json::from_str(r#"{"number":31}"#).unwrap();
+    assert_eq!(params.number, 31);
+    assert_eq!(serde_json::to_string(&params).unwrap(), r#"{"number":31}"#);
+}
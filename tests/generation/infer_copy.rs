@@ -0,0 +1,32 @@
+//! With `infer-copy`, structs made only of `Copy` fields derive `Copy`.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/infer_copy/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("infer_copy");
+    assert!(generated
+        .code
+        .contains("#[derive(Copy)]\npub struct Point {"));
+    // `points` is a `Vec`, which is not `Copy`.
+    assert_eq!(generated.code.matches("#[derive(Copy)]").count(), 1);
+}
+
+#[test]
+fn primitive_struct_is_copy() {
+    let origin = Point {
+        visible: true,
+        x: 1,
+        y: 2.0,
+    };
+    let shape = Shape {
+        origin,
+        points: vec![origin, origin],
+    };
+    assert_eq!(origin.x + shape.origin.x, 2);
+}
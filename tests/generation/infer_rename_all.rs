@@ -0,0 +1,63 @@
+//! `generation.infer-rename-all` replaces the `rename` attributes of most fields of a struct
+//! with a `rename_all` rule, and `fixes.snake-case-fields` names fields in snake case.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/infer_rename_all/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("infer_rename_all");
+    assert!(generated
+        .code
+        .contains("#[serde(rename_all = \"camelCase\")]\npub struct Block {"));
+}
+
+#[test]
+fn fields_keep_their_json_names() {
+    let json = json!({
+        "blockHash": "0x1",
+        "blockNumber": 2,
+        "parent_hash": "0x0",
+        "timestamp": 3,
+    });
+    let block: Block = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(block.block_hash, "0x1");
+    assert_eq!(block.block_number, 2);
+    assert_eq!(block.parent_hash, "0x0");
+    assert_eq!(serde_json::to_value(&block).unwrap(), json);
+}
+
+#[test]
+fn field_names_are_left_alone_without_snake_case_fields() {
+    let generated = crate::generate_with("infer_rename_all", "lowercase.toml", &[]).unwrap();
+    assert!(generated.code.contains(
+        "pub struct Block {\n    #[serde(rename = \"blockHash\")]\n    pub blockhash: String,\n"
+    ));
+    assert!(!generated.code.contains("rename_all"));
+}
+
+#[test]
+fn rules_are_inferred_per_variant() {
+    let generated = crate::check_fixture("infer_rename_all");
+    assert!(generated
+        .code
+        .contains("#[serde(rename_all = \"camelCase\")]\npub struct Transfer {"));
+    assert!(generated
+        .code
+        .contains("#[serde(rename_all = \"PascalCase\")]\npub struct Mint {"));
+
+    let json = json!({ "fromAddress": "0x1", "toAddress": "0x2" });
+    let event: Event = serde_json::from_value(json.clone()).unwrap();
+    assert!(matches!(&event, Event::Transfer(t) if t.from_address == "0x1"));
+    assert_eq!(serde_json::to_value(&event).unwrap(), json);
+
+    let json = json!({ "TokenId": "0x3", "MintAmount": 4 });
+    let event: Event = serde_json::from_value(json.clone()).unwrap();
+    assert!(matches!(&event, Event::Mint(m) if m.token_id == "0x3" && m.mint_amount == 4));
+    assert_eq!(serde_json::to_value(&event).unwrap(), json);
+}
@@ -0,0 +1,54 @@
+//! `generation.params-into-values` represents each parameter the same way as the `Serialize`
+//! implementation of the parameters, including those (de)serialized through a module.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/into_values_wrappers/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+/// An external type, which implements neither `Serialize` nor `Deserialize`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("into_values_wrappers");
+}
+
+fn params() -> MoveParams {
+    MoveParams {
+        to: Point { x: 1, y: 2 },
+        payload: Payload { amount: 5 },
+        via: Some(Point { x: 3, y: 4 }),
+        speed: None,
+    }
+}
+
+#[test]
+fn values_use_the_serde_modules() {
+    assert_eq!(
+        params().into_values(),
+        [
+            json!({ "x": 1, "y": 2 }),
+            json!("{\"amount\":5}"),
+            json!({ "x": 3, "y": 4 }),
+            json!(null),
+        ]
+    );
+}
+
+#[test]
+fn values_match_the_serialized_params() {
+    let serialized = serde_json::to_value(params()).unwrap();
+    let values = params().into_values();
+    assert_eq!(
+        serialized.as_array().unwrap()[..],
+        values[..serialized.as_array().unwrap().len()]
+    );
+}
@@ -0,0 +1,40 @@
+//! Enums with a variant much larger than the others allow `clippy::large_enum_variant`, unless
+//! `generation.box-large-variants` boxes that variant.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/large_variants/generated.rs");
+}
+
+/// Returns whether the `large_enum_variant` lint is allowed on the provided enum.
+fn allows_large_variant(code: &str, name: &str) -> bool {
+    let end = code.find(&format!("pub enum {name} {{")).unwrap();
+    let start = code[..end].rfind("\n\n").unwrap();
+    code[start..end].contains("#[allow(clippy::large_enum_variant)]")
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("large_variants");
+    assert!(allows_large_variant(&generated.code, "Event"));
+    assert!(!allows_large_variant(&generated.code, "Id"));
+}
+
+#[test]
+fn boxed_variants_need_no_allow() {
+    let generated = crate::generate_with("large_variants", "boxed.toml", &[]).unwrap();
+    assert!(generated.code.contains("    Receipt(Box<Receipt>),\n"));
+    assert!(generated.code.contains("    Block(Box<Header>),\n"));
+    assert!(!allows_large_variant(&generated.code, "Event"));
+    assert!(!allows_large_variant(&generated.code, "Id"));
+}
+
+#[test]
+fn unboxed_large_variant_keeps_the_allow() {
+    // `Header` has a serde attribute and can't be boxed, so it remains large once `Receipt` is.
+    let generated = crate::generate_with("large_variants", "serde_with.toml", &[]).unwrap();
+    assert!(generated.code.contains("    Receipt(Box<Receipt>),\n"));
+    assert!(generated.code.contains("    Block(Header),\n"));
+    assert!(allows_large_variant(&generated.code, "Event"));
+    assert!(!allows_large_variant(&generated.code, "Id"));
+}
@@ -0,0 +1,58 @@
+//! `generation.lenient-bool` deserializes booleans from strings and integers too.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/lenient_bool/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("lenient_bool");
+}
+
+#[test]
+fn accepts_strings_and_integers() {
+    for (finalized, expected) in [
+        (json!(true), true),
+        (json!("true"), true),
+        (json!(1), true),
+        (json!(false), false),
+        (json!("false"), false),
+        (json!(0), false),
+    ] {
+        let block: Block = serde_json::from_value(json!({ "finalized": finalized })).unwrap();
+        assert_eq!(block.finalized, expected);
+        assert_eq!(block.pending, None);
+    }
+
+    let block: Block =
+        serde_json::from_value(json!({ "finalized": 0, "pending": "true" })).unwrap();
+    assert_eq!(block.pending, Some(true));
+}
+
+#[test]
+fn rejects_other_values() {
+    for finalized in [json!("yes"), json!(2), json!(-1), json!(null)] {
+        assert!(serde_json::from_value::<Block>(json!({ "finalized": finalized })).is_err());
+    }
+}
+
+#[test]
+fn serializes_booleans() {
+    let block: Block = serde_json::from_value(json!({ "finalized": "true" })).unwrap();
+    assert_eq!(
+        serde_json::to_value(&block).unwrap(),
+        json!({ "finalized": true, "pending": null }),
+    );
+}
+
+#[test]
+fn params_are_lenient() {
+    let params: GetBlockParams = serde_json::from_value(json!({ "full": 1 })).unwrap();
+    assert_eq!(params.full, Some(true));
+    let params: GetBlockParams = serde_json::from_value(json!(["false"])).unwrap();
+    assert_eq!(params.full, Some(false));
+}
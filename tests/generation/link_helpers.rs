@@ -0,0 +1,51 @@
+//! `generation.link-helpers` builds the parameters of linked methods from the results of the
+//! methods declaring the links.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/link_helpers/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("link_helpers");
+}
+
+fn block() -> Block {
+    Block {
+        number: 7,
+        header: Header {
+            parenthash: "0x6".into(),
+        },
+    }
+}
+
+#[test]
+fn field_references_read_the_result() {
+    let params = GetBlockParams::from_parent(&block());
+    assert_eq!(params.hash, "0x6");
+}
+
+#[test]
+fn constants_and_fields_can_be_mixed() {
+    let params = GetBlockByNumberParams::from_by_number(&block());
+    assert_eq!(params.number, 7);
+    assert_eq!(params.full, Some(true));
+}
+
+#[test]
+fn referenced_links_are_followed() {
+    let params = GetBlockByNumberParams::from_genesis(&block());
+    assert_eq!(params.number, 0);
+    assert_eq!(params.full, None);
+}
+
+#[test]
+fn unsupported_links_are_skipped() {
+    let generated = crate::generate("link_helpers");
+    // `same block` uses a request expression, and `any block` leaves `number` unset.
+    assert!(!generated.code.contains("from_same_block"));
+    assert!(!generated.code.contains("from_any_block"));
+}
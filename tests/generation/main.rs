@@ -0,0 +1,220 @@
+//! Runs `openrpc-gen` on the fixtures of `tests/fixtures` and exercises the generated code.
+//!
+//! Each fixture is a directory holding an `openrpc.json` document, a `config.toml`
+//! configuration and the `generated.rs` file expected from them, which the modules of this
+//! crate include to test its behavior. Setting `OPENRPC_GEN_BLESS=1` overwrites the expected
+//! files with the current output instead of comparing them.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Like in a `#![no_std]` crate, the `alloc` crate is declared at the root so that the code
+// generated with `no-std` can use it from any module.
+extern crate alloc;
+
+mod alias_underlying_note;
+mod allof_flatten_refs;
+mod anon_naming;
+mod arbitrary;
+mod array_default;
+mod async_style;
+mod auto_transparent;
+mod batch;
+mod bounded_vec;
+mod capture_extensions;
+mod collapse_single_variant;
+mod common_flatten;
+mod const_constructors;
+mod content_descriptors;
+mod content_schema;
+mod display_templates;
+mod document_pointer;
+mod dry_run;
+mod either_params;
+mod enum_defaults;
+mod enum_rename_all;
+mod enum_repr;
+mod env_overrides;
+mod eq_ignore_fields;
+mod error_code_constants;
+mod escaped_refs;
+mod existence_checks;
+mod external_refs;
+mod flatten_default;
+mod flatten_lint;
+mod float_hash;
+mod footer;
+mod id_type;
+mod indent;
+mod infer_copy;
+mod infer_rename_all;
+mod into_values_wrappers;
+mod large_variants;
+mod lenient_bool;
+mod link_helpers;
+mod method_aliases;
+mod method_errors;
+mod method_markers;
+mod method_specs;
+mod module_path;
+mod multiline_errors;
+mod no_std;
+mod not_schema;
+mod null_members;
+mod numeric_newtype_from;
+mod only;
+mod open_errors;
+mod option_default_accessors;
+mod outcome_types;
+mod pagination;
+mod param_attributes;
+mod param_examples;
+mod param_macros;
+mod params_from_tuple;
+mod params_from_value;
+mod params_into_values;
+mod prefix_items;
+mod read_only_params;
+mod recursive_ref;
+mod remote_types;
+mod request_type;
+mod rustfmt_skip;
+mod serde_path_overrides;
+mod shared_errors;
+mod shared_str;
+mod skip_if;
+mod source_links;
+mod split_access;
+mod str_partial_eq;
+mod tracing;
+mod trim_strings;
+mod tuple_accessors;
+mod type_serde_with;
+mod typescript;
+mod union_results;
+mod unresolved_ref;
+mod validate_method;
+mod value_tryfrom;
+mod variant_constructors;
+mod variant_eq;
+mod variant_order;
+mod visibility;
+mod wrapper_deref;
+
+/// The output of a successful run of `openrpc-gen`.
+pub struct Generated {
+    /// The generated Rust code.
+    pub code: String,
+    /// The standard output of the run.
+    pub stdout: String,
+    /// The warnings printed while generating it.
+    pub stderr: String,
+}
+
+/// Returns the directory of the provided fixture.
+pub fn fixture_dir(fixture: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(fixture)
+}
+
+/// Runs `openrpc-gen` on the document of the provided fixture with the given configuration
+/// file and extra arguments.
+///
+/// The error output is returned when the generation fails.
+pub fn generate_with(fixture: &str, config: &str, args: &[&str]) -> Result<Generated, String> {
+    generate_with_env(fixture, config, args, &[])
+}
+
+/// Like [`generate_with`], but sets the provided environment variables for the run.
+pub fn generate_with_env(
+    fixture: &str,
+    config: &str,
+    args: &[&str],
+    envs: &[(&str, &str)],
+) -> Result<Generated, String> {
+    run(fixture, "openrpc.json", config, args, envs)
+}
+
+/// Runs `openrpc-gen` on the provided document of the fixture, instead of its `openrpc.json`
+/// document, with its `config.toml` configuration.
+pub fn generate_document(fixture: &str, document: &str) -> Generated {
+    generate_document_with(fixture, document, &[]).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Like [`generate_document`], but passes the provided extra arguments and returns the error
+/// output when the generation fails.
+pub fn generate_document_with(
+    fixture: &str,
+    document: &str,
+    args: &[&str],
+) -> Result<Generated, String> {
+    run(fixture, document, "config.toml", args, &[])
+}
+
+/// Runs `openrpc-gen` on a document of the provided fixture with the given configuration file,
+/// extra arguments and environment variables.
+fn run(
+    fixture: &str,
+    document: &str,
+    config: &str,
+    args: &[&str],
+    envs: &[(&str, &str)],
+) -> Result<Generated, String> {
+    // Tests run in parallel, and may run the same fixture with different arguments or
+    // environment variables, so every run gets its own output file.
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    let dir = fixture_dir(fixture);
+    let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!(
+        "{fixture}-{}-{}-{}.rs",
+        document.trim_end_matches(".json"),
+        config.trim_end_matches(".toml"),
+        RUNS.fetch_add(1, Ordering::Relaxed),
+    ));
+    let result = Command::new(env!("CARGO_BIN_EXE_openrpc-gen"))
+        .arg("--config")
+        .arg(dir.join(config))
+        .arg("--document")
+        .arg(dir.join(document))
+        .arg("--output")
+        .arg(&output)
+        .args(args)
+        .envs(envs.iter().copied())
+        .output()
+        .expect("failed to run openrpc-gen");
+    let stderr = String::from_utf8(result.stderr).unwrap();
+    if !result.status.success() {
+        return Err(stderr);
+    }
+    Ok(Generated {
+        code: std::fs::read_to_string(&output).unwrap(),
+        stdout: String::from_utf8(result.stdout).unwrap(),
+        stderr,
+    })
+}
+
+/// Runs `openrpc-gen` on the document and configuration of the provided fixture.
+pub fn generate(fixture: &str) -> Generated {
+    generate_with(fixture, "config.toml", &[]).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Checks that the `generated.rs` file of the provided fixture matches the current output of
+/// `openrpc-gen`, returning that output.
+pub fn check_fixture(fixture: &str) -> Generated {
+    let generated = generate(fixture);
+    let expected = fixture_dir(fixture).join("generated.rs");
+    if std::env::var_os("OPENRPC_GEN_BLESS").is_some() {
+        std::fs::write(&expected, &generated.code).unwrap();
+    } else {
+        let contents = std::fs::read_to_string(&expected).unwrap_or_default();
+        assert!(
+            contents == generated.code,
+            "`{}` is out of date, run the tests with `OPENRPC_GEN_BLESS=1` to update it\n\n{}",
+            expected.display(),
+            generated.code,
+        );
+    }
+    generated
+}
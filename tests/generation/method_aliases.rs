@@ -0,0 +1,31 @@
+//! Method aliases get their own name constant and are accepted by `Method::from_name`.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/method_aliases/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("method_aliases");
+    assert!(generated
+        .code
+        .contains("pub const BLOCK_NUMBER: &str = \"blockNumber\";"));
+}
+
+#[test]
+fn aliases_route_to_the_same_handler() {
+    let dispatch = |name: &str| match Method::from_name(name)? {
+        Method::EthBlockNumber => Some("block number"),
+        Method::EthChainId => Some("chain id"),
+    };
+    assert_eq!(dispatch(ETH_BLOCK_NUMBER), Some("block number"));
+    assert_eq!(dispatch(BLOCK_NUMBER), Some("block number"));
+    assert_eq!(dispatch("chainId"), None);
+    assert_eq!(
+        Method::from_name(BLOCK_NUMBER).unwrap().name(),
+        "eth_blockNumber"
+    );
+}
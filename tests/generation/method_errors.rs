@@ -0,0 +1,55 @@
+//! `generation.method-errors` generates an error enum per method, convertible from and to the
+//! `Error` enum shared by all methods.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/method_errors/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("method_errors");
+}
+
+#[test]
+fn method_errors_convert_into_the_shared_enum() {
+    let error = Error::from(GetBlockError::Unauthorized);
+    assert_eq!(error, Error::Unauthorized);
+    assert_eq!(error.code(), GetBlockError::Unauthorized.code());
+    assert_eq!(Error::from(GetPeersError::NotListening).code(), 2);
+}
+
+#[test]
+fn shared_errors_convert_back_by_code() {
+    for error in [GetBlockError::BlockNotFound, GetBlockError::Unauthorized] {
+        assert_eq!(GetBlockError::try_from(Error::from(error)), Ok(error));
+    }
+}
+
+#[test]
+fn undeclared_errors_are_returned_back() {
+    assert_eq!(
+        GetBlockError::try_from(Error::NotListening),
+        Err(Error::NotListening)
+    );
+    assert_eq!(
+        GetPeersError::try_from(Error::BlockNotFound),
+        Err(Error::BlockNotFound)
+    );
+}
+
+#[test]
+fn methods_without_errors_get_no_enum() {
+    let generated = crate::generate("method_errors");
+    assert!(!generated.code.contains("PingError"));
+}
+
+#[test]
+fn requires_error_types() {
+    let err = crate::generate_with("method_errors", "no_error_types.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains("`generation.method-errors` requires `generation.error-types`"));
+}
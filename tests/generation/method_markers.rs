@@ -0,0 +1,59 @@
+//! `generation.method-markers` ties each method to its parameter and result types through a
+//! unit type implementing `RpcMethod`.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/method_markers/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("method_markers");
+}
+
+/// Builds the request of the method `M`, the way a generic client would.
+fn request<M: RpcMethod>(_: M, params: M::Params) -> serde_json::Value
+where
+    M::Params: serde::Serialize,
+{
+    json!({ "method": M::METHOD.name(), "params": params })
+}
+
+/// Reads the result of the method `M`, the way a generic client would.
+fn result<M: RpcMethod>(_: M, value: serde_json::Value) -> M::Result
+where
+    M::Result: serde::de::DeserializeOwned,
+{
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn markers_carry_their_types() {
+    let params = GetBalanceParams {
+        address: "0x1".into(),
+    };
+    assert_eq!(
+        request(GetBalanceMethod, params),
+        json!({ "method": "getBalance", "params": { "address": "0x1" } }),
+    );
+    let balance: i64 = result(GetBalanceMethod, json!(42));
+    assert_eq!(balance, 42);
+}
+
+#[test]
+fn markers_map_to_methods() {
+    assert_eq!(GetBalanceMethod::METHOD, Method::GetBalance);
+    assert_eq!(NotifyMethod::METHOD, Method::Notify);
+    let () = result(NotifyMethod, json!(null));
+}
+
+#[test]
+fn requires_the_method_enum() {
+    let err = crate::generate_with("method_markers", "no_method_enum.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains("`generation.method-markers` requires `generation.method-enum`"));
+}
@@ -0,0 +1,34 @@
+//! `method_specs()` lists every method along with the structure of its parameters.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/method_specs/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("method_specs");
+}
+
+#[test]
+fn registry_lists_each_method() {
+    let specs = method_specs();
+    let find = |name: &str| specs.iter().find(|spec| spec.name == name).unwrap();
+    assert_eq!(specs.len(), 3);
+    assert_eq!(find("getBlock").param_structure, ParamStructure::ByName);
+    assert_eq!(
+        find("getBalance").param_structure,
+        ParamStructure::ByPosition
+    );
+    assert_eq!(
+        (
+            find("getBalance").required_params,
+            find("getBalance").params
+        ),
+        (1, 2)
+    );
+    assert_eq!(find("notify").param_structure, ParamStructure::Either);
+    assert!(find("notify").notification);
+}
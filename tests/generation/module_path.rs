@@ -0,0 +1,41 @@
+//! `generation.module-path` qualifies the references to generated types with the path of the
+//! module they are generated in.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/module_path/generated.rs");
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("module_path");
+}
+
+#[test]
+fn internal_refs_are_qualified() {
+    let generated = crate::generate("module_path");
+    assert!(generated
+        .code
+        .contains("    pub hash: crate::module_path::generated::Hash,\n"));
+    assert!(generated
+        .code
+        .contains("    pub parents: Vec<crate::module_path::generated::Hash>,\n"));
+    // Definitions themselves are not qualified.
+    assert!(generated.code.contains("pub type Hash = String;"));
+    assert!(generated.code.contains("pub struct Block {"));
+}
+
+#[test]
+fn param_macros_are_usable_by_path() {
+    let params = crate::module_path::generated::get_block_params!(hash: "0x1".into());
+    assert_eq!(params.hash, "0x1");
+    assert_eq!(params.full, None);
+}
+
+#[test]
+fn rejects_invalid_paths() {
+    let err = crate::generate_with("module_path", "invalid.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains("invalid module `crate::generated::` in `generation.module-path`"));
+}
@@ -0,0 +1,55 @@
+//! Error messages spanning several lines are documented line by line.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/multiline_errors/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("multiline_errors");
+}
+
+#[test]
+fn error_variants_document_every_line() {
+    let generated = crate::generate("multiline_errors").code;
+    assert!(generated.contains(
+        "    /// block not found\n    ///\n    /// The block may have been pruned.\n    ///     Unknown,\n    BlockNotFound,\n"
+    ));
+}
+
+#[test]
+fn message_is_kept_whole() {
+    assert_eq!(
+        Error::BlockNotFound.message(),
+        "block not found\n\nThe block may have been pruned.\n    Unknown,"
+    );
+}
+
+#[test]
+fn outcome_variants_document_every_line() {
+    let generated = crate::generate("multiline_errors").code;
+    assert!(generated.contains(
+        "    Ok(GetBlockResult),\n    /// block not found\n    ///\n    /// The block may have been pruned.\n    ///     Unknown,\n    BlockNotFound,\n"
+    ));
+}
+
+#[test]
+fn method_error_variants_document_every_line() {
+    let generated = crate::generate("multiline_errors").code;
+    assert!(generated.contains(
+        "pub enum GetBlockError {\n    /// block not found\n    ///\n    /// The block may have been pruned.\n    ///     Unknown,\n    BlockNotFound,\n}\n"
+    ));
+    assert_eq!(GetBlockError::BlockNotFound.code(), 1);
+}
+
+#[test]
+fn error_code_constants_document_every_line() {
+    let generated = crate::generate("multiline_errors").code;
+    assert!(generated.contains(
+        "    /// block not found\n    ///\n    /// The block may have been pruned.\n    ///     Unknown,\n    pub const BLOCK_NOT_FOUND: i64 = 1;\n"
+    ));
+    assert_eq!(error_codes::BLOCK_NOT_FOUND, 1);
+}
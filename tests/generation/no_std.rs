@@ -0,0 +1,32 @@
+//! With `no-std`, the generated code only uses `core` and `alloc`.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/no_std/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("no_std");
+    assert!(generated
+        .code
+        .contains("use alloc::{string::String, vec, vec::Vec};"));
+    assert!(!generated.code.contains("std::"));
+}
+
+#[test]
+fn params_deserialize() {
+    let params: GetBalanceParams = serde_json::from_str(r#"["0x1",null]"#).unwrap();
+    assert_eq!((&*params.address, params.block), ("0x1", None));
+}
+
+#[test]
+fn extensions_are_captured() {
+    let json = serde_json::json!({ "hash": "0x1", "number": 2, "x-miner": "alice" });
+    let block: Block = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(block.extensions.len(), 1);
+    assert_eq!(block.extensions["x-miner"], "alice");
+    assert_eq!(serde_json::to_value(&block).unwrap(), json);
+}
@@ -0,0 +1,21 @@
+//! A `not` constraint falls back to the base type with a comment.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/not_schema/generated.rs");
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("not_schema");
+    assert!(generated
+        .code
+        .contains("// Unsupported constraint, not enforced: not: {\"enum\":[\"admin\",\"root\"]}\n    pub nick: String,"));
+    assert!(generated.stderr.contains("- constraint = not: "));
+}
+
+#[test]
+fn deserializes_excluded_values() {
+    let user: generated::User = serde_json::from_str(r#"{"nick":"admin"}"#).unwrap();
+    assert_eq!(user.nick, "admin");
+}
@@ -0,0 +1,48 @@
+//! `null` schemas map to the unit type, and `null` members of unions make the enum optional.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/null_members/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("null_members");
+    assert!(generated.code.contains("    pub unit: (),\n"));
+    assert!(generated.code.contains("    pub maybe_unit: Option<()>,\n"));
+    assert!(generated.code.contains("    pub count: Option<Count>,\n"));
+    assert!(generated.code.contains("    pub label: Option<Label>,\n"));
+    assert!(generated
+        .code
+        .contains("pub type GetShapeResult = Option<Shape>;"));
+    assert!(!generated.code.contains("Null("));
+}
+
+#[test]
+fn null_field_is_unit() {
+    let holder: Holder = serde_json::from_str(r#"{"unit":null,"count":null}"#).unwrap();
+    assert_eq!(holder.maybe_unit, None);
+    let holder: Holder =
+        serde_json::from_str(r#"{"unit":null,"maybe_unit":null,"count":3}"#).unwrap();
+    assert!(matches!(holder.count, Some(Count::Integer(3))));
+}
+
+#[test]
+fn any_of_param_is_optional() {
+    let params: SetHolderParams = serde_json::from_str(r#"[null]"#).unwrap();
+    assert!(params.label.is_none());
+    let params: SetHolderParams = serde_json::from_str(r#"{"label":"x"}"#).unwrap();
+    assert!(matches!(params.label, Some(Label::String(s)) if s == "x"));
+}
+
+#[test]
+fn one_of_result_is_option_of_remaining_variants() {
+    let shape: GetShapeResult = serde_json::from_str("null").unwrap();
+    assert!(shape.is_none());
+    let shape: GetShapeResult = serde_json::from_str(r#"{"radius":1.5}"#).unwrap();
+    assert!(matches!(shape, Some(Shape::Circle(c)) if c.radius == 1.5));
+    let shape: GetShapeResult = serde_json::from_str(r#""dot""#).unwrap();
+    assert!(matches!(shape, Some(Shape::Name(n)) if n == "dot"));
+}
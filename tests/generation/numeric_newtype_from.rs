@@ -0,0 +1,42 @@
+//! `generation.numeric-newtype-from` converts numeric newtypes to and from their inner type.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/numeric_newtype_from/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("numeric_newtype_from");
+}
+
+#[test]
+fn structs_convert_from_their_field() {
+    let height = BlockHeight::from(42u64);
+    assert_eq!(height.height, 42);
+    assert_eq!(u64::from(height), 42);
+    let nonce: Nonce = 7u64.into();
+    assert_eq!(u64::from(nonce), 7);
+}
+
+#[test]
+fn tuples_convert_from_their_item() {
+    let gas = Gas::from(21_000u64);
+    assert_eq!(gas.0, 21_000);
+    assert_eq!(u64::from(gas), 21_000);
+}
+
+#[test]
+fn collapsed_enums_convert_from_their_variant() {
+    let balance = Balance::from(1.5);
+    assert_eq!(f64::from(balance), 1.5);
+}
+
+#[test]
+fn other_structs_get_no_conversion() {
+    let generated = crate::generate("numeric_newtype_from").code;
+    assert!(!generated.contains("for Name {"));
+    assert!(!generated.contains("for Timeout {"));
+}
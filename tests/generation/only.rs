@@ -0,0 +1,86 @@
+//! `--only` restricts the generated code to the methods and types matching glob patterns, along
+//! with the types and errors they reference.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/only/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("only");
+}
+
+#[test]
+fn generates_everything_by_default() {
+    assert_eq!(Method::from_name("net_peers"), Some(Method::NetPeers));
+    assert_eq!(Error::NotListening.code(), 2);
+    let _ = Receipt {
+        block: Block {
+            header: Header { hash: "0x1".into() },
+        },
+    };
+}
+
+#[test]
+fn keeps_matching_methods_and_what_they_reference() {
+    let generated = crate::generate_with("only", "config.toml", &["--only", "eth_get*"]).unwrap();
+    assert!(generated
+        .code
+        .contains("    EthGetBlock,\n    /// `eth_getBalance`\n    EthGetBalance,\n}"));
+    assert!(!generated.code.contains("NetPeers"));
+    assert!(generated.code.contains("pub struct Block {"));
+    assert!(generated.code.contains("pub struct Header {"));
+    assert!(!generated.code.contains("pub struct Peer {"));
+    assert!(!generated.code.contains("pub struct Receipt {"));
+    assert!(generated.code.contains("BlockNotFound"));
+    assert!(!generated.code.contains("NotListening"));
+    assert!(generated.stderr.is_empty());
+}
+
+#[test]
+fn keeps_matching_types_without_methods() {
+    let generated = crate::generate_with(
+        "only",
+        "config.toml",
+        &["--only", "#/components/schemas/Rec*"],
+    )
+    .unwrap();
+    assert!(generated.code.contains("pub struct Receipt {"));
+    assert!(generated.code.contains("pub struct Block {"));
+    assert!(generated.code.contains("pub struct Header {"));
+    assert!(!generated.code.contains("pub struct Peer {"));
+    assert!(!generated.code.contains("pub enum Method"));
+    assert!(!generated.code.contains("pub enum Error"));
+}
+
+#[test]
+fn patterns_may_be_repeated() {
+    let generated = crate::generate_with(
+        "only",
+        "config.toml",
+        &["--only", "net_peers", "--only", "eth_getB?lance"],
+    )
+    .unwrap();
+    assert!(generated.code.contains("NetPeers"));
+    assert!(generated.code.contains("EthGetBalance"));
+    assert!(!generated.code.contains("EthGetBlock"));
+    assert!(generated.code.contains("pub struct Peer {"));
+    assert!(!generated.code.contains("pub struct Block {"));
+}
+
+#[test]
+fn warns_about_patterns_matching_nothing() {
+    let generated = crate::generate_with(
+        "only",
+        "config.toml",
+        &["--only", "eth_getBlock", "--only", "debug_*"],
+    )
+    .unwrap();
+    assert!(generated
+        .stderr
+        .contains("pattern passed to `--only` matches no method or type\n- pattern = debug_*\n"));
+    assert!(generated.code.contains("EthGetBlock"));
+}
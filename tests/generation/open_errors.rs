@@ -0,0 +1,53 @@
+//! With `open-errors`, errors with undeclared codes deserialize into `Error::Unknown`.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/open_errors/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("open_errors");
+    assert!(generated
+        .code
+        .contains("#[non_exhaustive]\npub enum Error {"));
+}
+
+#[test]
+fn undeclared_code_is_unknown() {
+    let json = r#"{"code":-32000,"message":"Server busy","data":[1]}"#;
+    let error: Error = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        error,
+        Error::Unknown {
+            code: -32000,
+            message: "Server busy".into(),
+            data: Some(serde_json::json!([1])),
+        }
+    );
+    assert_eq!(error.code(), -32000);
+    assert_eq!(
+        serde_json::to_value(&error).unwrap(),
+        serde_json::from_str::<serde_json::Value>(json).unwrap()
+    );
+}
+
+#[test]
+fn declared_code_is_its_variant() {
+    let error: Error = serde_json::from_str(r#"{"code":404,"message":"Not found"}"#).unwrap();
+    assert_eq!(error, Error::NotFound);
+}
+
+#[test]
+fn rejects_errors_named_unknown() {
+    let err = crate::generate_document_with("open_errors", "unknown_error.json", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't generate the `Unknown` variant: an error of the document has the same name:\n\
+         - path = #/components/errors/Unknown\n\
+         - option = generation.open-errors\n"
+    ));
+}
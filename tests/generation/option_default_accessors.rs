@@ -0,0 +1,50 @@
+//! `generation.option-default-accessors` generates `*_or_default` accessors for the optional
+//! fields of structs.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/option_default_accessors/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("option_default_accessors");
+}
+
+fn filter(json: &str) -> Filter {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn absent_fields_return_the_schema_default() {
+    let filter = filter(r#"{"address":"0x1"}"#);
+    assert_eq!(filter.limit, None);
+    assert_eq!(filter.limit_or_default(), 10);
+    assert_eq!(filter.order_or_default(), "asc");
+}
+
+#[test]
+fn absent_fields_without_schema_default_return_the_type_default() {
+    let filter = filter(r#"{"address":"0x1"}"#);
+    assert!(!filter.verbose_or_default());
+    assert!(filter.topics_or_default().is_empty());
+}
+
+#[test]
+fn present_fields_are_returned() {
+    let filter =
+        filter(r#"{"address":"0x1","limit":3,"order":"desc","verbose":true,"topics":["a"]}"#);
+    assert_eq!(filter.limit_or_default(), 3);
+    assert_eq!(filter.order_or_default(), "desc");
+    assert!(filter.verbose_or_default());
+    assert_eq!(filter.topics_or_default(), ["a"]);
+}
+
+#[test]
+fn fields_without_known_default_are_skipped() {
+    let generated = crate::generate("option_default_accessors").code;
+    assert!(!generated.contains("fn range_or_default"));
+    assert!(!generated.contains("fn address_or_default"));
+}
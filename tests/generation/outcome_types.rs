@@ -0,0 +1,79 @@
+//! `*Outcome` enums route JSON-RPC responses to the result or to the errors of their method.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/outcome_types/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("outcome_types");
+}
+
+#[test]
+fn success_response_is_ok() {
+    let outcome: GetBlockOutcome =
+        serde_json::from_str(r#"{"jsonrpc":"2.0","id":1,"result":{"number":7}}"#).unwrap();
+    assert!(matches!(outcome, GetBlockOutcome::Ok(ref block) if block.number == 7));
+    assert_eq!(outcome.into_result().unwrap().number, 7);
+}
+
+#[test]
+fn declared_error_is_its_variant() {
+    let outcome: GetBlockOutcome = serde_json::from_str(
+        r#"{"jsonrpc":"2.0","id":1,"error":{"code":404,"message":"Not found"}}"#,
+    )
+    .unwrap();
+    assert!(matches!(outcome, GetBlockOutcome::NotFound));
+    assert_eq!(outcome.into_result().unwrap_err(), Error::NotFound);
+}
+
+#[test]
+fn other_errors_are_other() {
+    // Declared by the document, but not for this method.
+    let outcome: GetBlockOutcome =
+        serde_json::from_str(r#"{"jsonrpc":"2.0","id":1,"error":{"code":503,"message":"Busy"}}"#)
+            .unwrap();
+    assert!(matches!(outcome, GetBlockOutcome::Other(Error::Busy)));
+
+    // Not declared at all.
+    let outcome: GetBlockOutcome = serde_json::from_str(
+        r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32000,"message":"Oops"}}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        outcome,
+        GetBlockOutcome::Other(Error::Unknown { code: -32000, .. })
+    ));
+}
+
+#[test]
+fn closed_errors_are_rejected() {
+    let err = crate::generate_with("outcome_types", "closed.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains("`generation.outcome-types` requires"));
+}
+
+#[test]
+fn emitted_without_declared_errors() {
+    let generated = crate::generate_with("union_results", "outcome.toml", &[]).unwrap();
+    assert!(generated.code.contains("pub enum Error {"));
+    assert!(generated.code.contains("pub enum GetTransactionOutcome {"));
+}
+
+#[test]
+fn rejects_errors_named_like_the_generated_variants() {
+    let err = crate::generate_document_with("outcome_types", "variant_names.json", &[])
+        .err()
+        .unwrap();
+    for name in ["Ok", "Other"] {
+        assert!(err.contains(&format!(
+            "can't generate the `{name}` variant: an error of the document has the same name:\n\
+             - path = #/components/errors/{name}\n\
+             - option = generation.outcome-types\n"
+        )));
+    }
+}
@@ -0,0 +1,78 @@
+//! Paginated methods get a stream fetching their pages one after the other.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/pagination/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("pagination");
+}
+
+/// A client answering `listBlocks` with two pages, recording the cursors it receives.
+#[derive(Default)]
+struct MockClient {
+    cursors: RefCell<Vec<serde_json::Value>>,
+}
+
+impl Client for MockClient {
+    type Error = serde_json::Error;
+
+    async fn call<P, R>(&self, method: &str, params: P) -> Result<R, Self::Error>
+    where
+        P: serde::Serialize,
+        R: serde::de::DeserializeOwned,
+    {
+        assert_eq!(method, "listBlocks");
+        let params = serde_json::to_value(params)?;
+        let cursor = params["cursor"].clone();
+        self.cursors.borrow_mut().push(cursor.clone());
+        let page = match cursor.as_str() {
+            None => serde_json::json!({ "items": [1, 2], "next_cursor": "page2" }),
+            Some("page2") => serde_json::json!({ "items": [3] }),
+            Some(other) => panic!("unexpected cursor `{other}`"),
+        };
+        serde_json::from_value(page)
+    }
+}
+
+/// Runs the provided future, which must not wait on anything, to completion.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("the future is not ready"),
+    }
+}
+
+#[test]
+fn stream_fetches_every_page() {
+    let client = MockClient::default();
+    let mut stream = ListBlocksParams {
+        limit: 2,
+        cursor: None,
+    }
+    .into_stream(&client);
+
+    let mut items = Vec::new();
+    while let Some(page) = block_on(stream.next_page()) {
+        items.extend(page.unwrap().items);
+    }
+
+    assert_eq!(items, [1, 2, 3]);
+    assert_eq!(
+        *client.cursors.borrow(),
+        [serde_json::Value::Null, serde_json::json!("page2")]
+    );
+}
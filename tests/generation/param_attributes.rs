@@ -0,0 +1,79 @@
+//! The serde attributes of method parameters apply to both their serialization and their
+//! deserialization.
+
+#[allow(dead_code)]
+mod generated {
+    /// Represents integers as hexadecimal strings.
+    mod hex {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&format!("{value:#x}"))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+            let s = String::deserialize(deserializer)?;
+            let digits = s.strip_prefix("0x").unwrap_or(&s);
+            i64::from_str_radix(digits, 16).map_err(serde::de::Error::custom)
+        }
+
+        pub mod option {
+            use serde::{Deserialize, Deserializer, Serializer};
+
+            pub fn serialize<S: Serializer>(
+                value: &Option<i64>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match value {
+                    Some(value) => super::serialize(value, serializer),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<i64>, D::Error> {
+                #[derive(Deserialize)]
+                struct Hex(#[serde(with = "super")] i64);
+
+                Ok(Option::<Hex>::deserialize(deserializer)?.map(|hex| hex.0))
+            }
+        }
+    }
+
+    include!("../fixtures/param_attributes/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("param_attributes");
+}
+
+#[test]
+fn named_params_round_trip() {
+    let value = json!({ "amount": "0xff", "fee": "0x1", "memo": "rent" });
+    let params: TransferByNameParams = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!((params.amount, params.fee), (255, Some(1)));
+    assert_eq!(serde_json::to_value(&params).unwrap(), value);
+
+    let value = json!({ "amount": "0x10", "fee": null, "memo": null });
+    let params: TransferByNameParams = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(params.fee, None);
+    assert_eq!(serde_json::to_value(&params).unwrap(), value);
+}
+
+#[test]
+fn positional_params_round_trip() {
+    let value = json!(["0xff", "0x1", "rent"]);
+    let params: TransferByPositionParams = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!((params.amount, params.fee), (255, Some(1)));
+    assert_eq!(serde_json::to_value(&params).unwrap(), value);
+
+    let value = json!(["0x10", null, null]);
+    let params: TransferByPositionParams = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(params.fee, None);
+    assert_eq!(serde_json::to_value(&params).unwrap(), value);
+}
@@ -0,0 +1,15 @@
+//! Parameter examples are rendered in the documentation of the parameter fields.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/param_examples/generated.rs");
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("param_examples");
+    assert!(generated.code.contains(
+        "    /// The account.\n    ///\n    /// # Example\n    ///\n    /// ```json\n    \
+        /// \"0x1234\"\n    /// ```\n    pub address: String,\n"
+    ));
+}
@@ -0,0 +1,35 @@
+//! `generation.param-macros` generates a macro per method building its parameters from
+//! `key: value` pairs.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/param_macros/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("param_macros");
+}
+
+#[test]
+fn omitted_optional_params_are_defaulted() {
+    let params = get_block_params!(number: 1);
+    assert_eq!(params.number, 1);
+    assert_eq!(params.fulltransactions, None);
+    assert_eq!(params.tag, None);
+}
+
+#[test]
+fn optional_params_take_their_inner_value() {
+    let params = get_block_params!(tag: String::from("latest"), number: 2, fulltransactions: true,);
+    assert_eq!(params.number, 2);
+    assert_eq!(params.fulltransactions, Some(true));
+    assert_eq!(params.tag.as_deref(), Some("latest"));
+}
+
+#[test]
+fn methods_without_params_get_a_macro() {
+    let _: PingParams = ping_params!();
+}
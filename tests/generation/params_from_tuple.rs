@@ -0,0 +1,32 @@
+//! `generation.params-from-tuple` builds the parameters of methods accepting positional
+//! parameters from tuples.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/params_from_tuple/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("params_from_tuple");
+    // Methods only accepting named parameters are left out.
+    assert!(!generated
+        .code
+        .contains("impl From<(String,)> for GetAccountParams"));
+}
+
+#[test]
+fn builds_from_a_tuple() {
+    let params = GetBalanceParams::from((String::from("0x1"), Some(4)));
+    assert_eq!((&*params.address, params.block), ("0x1", Some(4)));
+    assert_eq!(serde_json::to_value(&params).unwrap(), json!(["0x1", 4]),);
+}
+
+#[test]
+fn builds_from_a_single_element_tuple() {
+    let params: GetBlockParams = (7,).into();
+    assert_eq!(params.number, 7);
+}
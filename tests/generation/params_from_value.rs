@@ -0,0 +1,40 @@
+//! `generation.params-from-value` reads parameter types from the `params` of a request.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/params_from_value/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("params_from_value");
+}
+
+#[test]
+fn reads_named_params_by_their_json_name() {
+    let params =
+        GetBlockParams::from_rpc_params(json!({ "blockNumber": 4, "includeTxs": true })).unwrap();
+    assert_eq!(params.blocknumber, 4);
+    assert_eq!(params.includetxs, Some(true));
+
+    let params = GetBlockParams::from_rpc_params(json!({ "blockNumber": 4 })).unwrap();
+    assert_eq!(params.includetxs, None);
+
+    // The Rust names of the parameters are not accepted in their place.
+    assert!(GetBlockParams::from_rpc_params(json!({ "blocknumber": 4 })).is_err());
+}
+
+#[test]
+fn reads_positional_params() {
+    let params = GetBlockParams::from_rpc_params(json!([4, false])).unwrap();
+    assert_eq!(params.blocknumber, 4);
+    assert_eq!(params.includetxs, Some(false));
+}
+
+#[test]
+fn rejects_other_values() {
+    assert!(GetBlockParams::from_rpc_params(json!(4)).is_err());
+}
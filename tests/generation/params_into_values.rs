@@ -0,0 +1,34 @@
+//! Positional parameters can be turned into the list of their JSON values.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/params_into_values/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("params_into_values");
+}
+
+#[test]
+fn into_values() {
+    let params = TransferParams {
+        to: "alice".into(),
+        amount: 3,
+        memo: Some(Memo {
+            text: "rent".into(),
+        }),
+    };
+    let values = params.into_values();
+    assert_eq!(values.len(), 3);
+    assert!(values[0].is_string());
+    assert!(values[1].is_i64());
+    assert!(values[2].is_object());
+    assert_eq!(
+        values,
+        [json!("alice"), json!(3), json!({ "text": "rent" })]
+    );
+}
@@ -0,0 +1,25 @@
+//! `prefixItems` arrays become tuple structs with the type of each item.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/prefix_items/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("prefix_items");
+    assert!(generated
+        .code
+        .contains("pub struct ValueWithProof(pub i64, pub Vec<String>, pub bool);"));
+}
+
+#[test]
+fn round_trips_as_an_array() {
+    let json = r#"[3,["0xa","0xb"],true]"#;
+    let ValueWithProof(value, proof, flag) = serde_json::from_str(json).unwrap();
+    assert_eq!((value, proof.len(), flag), (3, 2, true));
+    let tuple = ValueWithProof(value, proof, flag);
+    assert_eq!(serde_json::to_string(&tuple).unwrap(), json);
+}
@@ -0,0 +1,29 @@
+//! Method parameters use a copy of their struct without its `readOnly` fields.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/read_only_params/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("read_only_params");
+}
+
+#[test]
+fn params_exclude_read_only_fields() {
+    let params = CreateUserParams {
+        user: UserRequest {
+            name: "alice".into(),
+        },
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({ "user": { "name": "alice" } }),
+    );
+
+    let result: User = serde_json::from_str(r#"{"id":1,"name":"alice"}"#).unwrap();
+    assert_eq!(result.id, 1);
+}
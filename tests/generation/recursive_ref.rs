@@ -0,0 +1,26 @@
+//! `$recursiveRef`s are approximated by plain references to the nearest anchor, with a warning.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/recursive_ref/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("recursive_ref");
+    assert!(generated.code.contains("    pub children: Vec<Tree>,\n"));
+    assert!(generated.stderr.contains(
+        "`$recursiveRef` approximated by a plain reference to the nearest anchor\n\
+        - pointer = #/components/schemas/Tree/properties/children/items\n\
+        - target = #/components/schemas/Tree\n"
+    ));
+}
+
+#[test]
+fn deserializes_nested_trees() {
+    let tree: Tree =
+        serde_json::from_str(r#"{"value":1,"children":[{"value":2,"children":[]}]}"#).unwrap();
+    assert_eq!(tree.children[0].value, 2);
+}
@@ -0,0 +1,41 @@
+//! `generation.remote-types` (de)serializes external types through generated remote shims.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/remote_types/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+/// An external type, which implements neither `Serialize` nor `Deserialize`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("remote_types");
+}
+
+#[test]
+fn required_fields_round_trip() {
+    let json = json!({ "origin": { "x": 1, "y": 2 }, "anchor": null });
+    let shape: Shape = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(shape.origin, Point { x: 1, y: 2 });
+    assert_eq!(shape.anchor, None);
+    assert_eq!(serde_json::to_value(&shape).unwrap(), json);
+}
+
+#[test]
+fn optional_fields_round_trip() {
+    let json = json!({ "origin": { "x": 1, "y": 2 }, "anchor": { "x": 3, "y": 4 } });
+    let shape: Shape = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(shape.anchor, Some(Point { x: 3, y: 4 }));
+    assert_eq!(serde_json::to_value(&shape).unwrap(), json);
+
+    let shape: Shape = serde_json::from_value(json!({ "origin": { "x": 1, "y": 2 } })).unwrap();
+    assert_eq!(shape.anchor, None);
+}
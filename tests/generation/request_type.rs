@@ -0,0 +1,55 @@
+//! `generation.request-type` generates a `Request` type, serializing notifications without an
+//! `id` member.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/request_type/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("request_type");
+}
+
+#[test]
+fn requests_have_an_id() {
+    let request = Request::new(Id::Unsigned(1), "eth_blockNumber", json!([]));
+    assert!(!request.is_notification());
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({ "jsonrpc": "2.0", "method": "eth_blockNumber", "params": [], "id": 1 })
+    );
+}
+
+#[test]
+fn notifications_have_no_id() {
+    let notification = Request::notification("eth_subscribe", json!(["newHeads"]));
+    assert!(notification.is_notification());
+    let value = serde_json::to_value(&notification).unwrap();
+    assert!(value.get("id").is_none());
+    assert_eq!(
+        value,
+        json!({ "jsonrpc": "2.0", "method": "eth_subscribe", "params": ["newHeads"] })
+    );
+}
+
+#[test]
+fn null_ids_are_not_notifications() {
+    let request = Request::new(Id::Null, "eth_blockNumber", json!([]));
+    assert!(!request.is_notification());
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({ "jsonrpc": "2.0", "method": "eth_blockNumber", "params": [], "id": null })
+    );
+}
+
+#[test]
+fn requires_id_type() {
+    let err = crate::generate_with("request_type", "no_id_type.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains("`generation.request-type` requires `generation.id-type`"));
+}
@@ -0,0 +1,25 @@
+//! With `rustfmt-skip`, the items are wrapped in a `#[rustfmt::skip]` module and re-exported.
+
+// The generated code holds its own `generated` module.
+#[allow(dead_code, clippy::module_inception)]
+mod generated {
+    include!("../fixtures/rustfmt_skip/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("rustfmt_skip");
+    let code = generated
+        .code
+        .lines()
+        .find(|line| !line.starts_with("//") && !line.is_empty());
+    assert_eq!(code, Some("#[rustfmt::skip]"));
+}
+
+#[test]
+fn items_are_re_exported() {
+    let point: Point = serde_json::from_str(r#"{"x":1,"y":2}"#).unwrap();
+    assert_eq!((point.x, point.y), (1, 2));
+}
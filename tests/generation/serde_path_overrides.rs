@@ -0,0 +1,56 @@
+//! `generation.serde-path-overrides` derives `Serialize` and `Deserialize` through another path
+//! to the `serde` crate for the listed types.
+
+/// A re-export of `serde`, standing for a vendored copy of it.
+mod vendored {
+    pub use serde;
+}
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/serde_path_overrides/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("serde_path_overrides");
+}
+
+#[test]
+fn listed_types_use_the_override() {
+    let generated = crate::generate("serde_path_overrides");
+    let derive = "#[derive(Debug, Clone, crate::serde_path_overrides::vendored::serde::Serialize, \
+                  crate::serde_path_overrides::vendored::serde::Deserialize)]\n\
+                  #[serde(crate = \"crate::serde_path_overrides::vendored::serde\")]\n\
+                  pub struct Block {";
+    assert!(generated.code.contains(derive));
+}
+
+#[test]
+fn other_types_use_the_imported_crate() {
+    let generated = crate::generate("serde_path_overrides");
+    assert!(generated
+        .code
+        .contains("#[derive(Debug, Clone, Serialize, Deserialize)]\npub struct Header {"));
+}
+
+#[test]
+fn overridden_types_round_trip() {
+    let json = r#"{"header":{"hash":"0x1"},"status":"final"}"#;
+    let block: Block = serde_json::from_str(json).unwrap();
+    assert_eq!(block.status, Status::Final);
+    assert_eq!(serde_json::to_string(&block).unwrap(), json);
+}
+
+#[test]
+fn rejects_invalid_paths() {
+    let err = crate::generate_with("serde_path_overrides", "invalid.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "invalid crate `vendored serde` for `#/components/schemas/Block` in \
+         `generation.serde-path-overrides`"
+    ));
+}
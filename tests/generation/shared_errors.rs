@@ -0,0 +1,40 @@
+//! Errors shared between methods appear once in the unified `Error` enum.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/shared_errors/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("shared_errors");
+    assert_eq!(generated.code.matches("    NotFound,").count(), 1);
+}
+
+#[test]
+fn shared_error_has_one_variant() {
+    let error: Error = serde_json::from_str(r#"{"code":404,"message":"Not found"}"#).unwrap();
+    assert_eq!(error, Error::NotFound);
+    assert_eq!(error.code(), 404);
+    assert_eq!(Error::InternalError.code(), 500);
+}
+
+#[test]
+fn message_is_the_declared_one() {
+    assert_eq!(Error::NotFound.message(), "Not found");
+    assert_eq!(Error::InternalError.message(), "Internal error");
+}
+
+#[test]
+fn error_name_collision_is_reported() {
+    let err = crate::generate_with("shared_errors", "collision.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't generate `Error`: a type of the document has the same name:\n\
+        - path = #/components/schemas/Error\n\
+        - option = generation.error-types\n"
+    ));
+}
@@ -0,0 +1,134 @@
+//! Shared string types are (de)serialized without the `rc` feature of `serde`, which this crate
+//! does not enable.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/shared_str/generated.rs");
+}
+
+use std::sync::Arc;
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("shared_str");
+}
+
+#[test]
+fn struct_fields() {
+    let value = json!({
+        "kind": "user",
+        "name": "alice",
+        "groups": [["admin"], []],
+        "handle": ["@alice"],
+        "badges": ["early"],
+    });
+    let user: User = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(user.name, Arc::from("alice"));
+    assert_eq!(user.nickname, None);
+    assert_eq!(user.groups, [vec![Arc::from("admin")], vec![]]);
+    assert_eq!(user.handle.0, Arc::from("@alice"));
+    assert_eq!(**user.badges.as_ref().unwrap(), [Arc::from("early")]);
+    assert_eq!(
+        serde_json::to_value(&user).unwrap(),
+        json!({
+            "kind": "user",
+            "name": "alice",
+            "nickname": null,
+            "groups": [["admin"], []],
+            "handle": ["@alice"],
+            "badges": ["early"],
+        }),
+    );
+}
+
+#[test]
+fn bounded_arrays() {
+    let with_badges = |badges| {
+        let user = json!({
+            "kind": "user",
+            "name": "dave",
+            "groups": [],
+            "handle": ["@dave"],
+            "badges": badges,
+        });
+        serde_json::from_value::<User>(user)
+    };
+    assert!(with_badges(json!(["a", "b"])).is_ok());
+    assert!(with_badges(json!(["a", "b", "c"])).is_err());
+}
+
+#[test]
+fn jsonrpc_version() {
+    let notice: Notice = serde_json::from_value(json!({ "jsonrpc": "2.0", "text": "hi" })).unwrap();
+    assert_eq!(&*notice.text, "hi");
+    assert_eq!(
+        serde_json::to_value(&notice).unwrap(),
+        json!({ "jsonrpc": "2.0", "text": "hi" }),
+    );
+    assert!(serde_json::from_value::<Notice>(json!({ "jsonrpc": "1.0", "text": "hi" })).is_err());
+}
+
+#[test]
+fn collection_caps() {
+    let params = |tags| {
+        serde_json::from_value::<TagUserParams>(json!([
+            { "kind": "user", "name": "erin", "groups": [], "handle": ["@erin"] },
+            tags,
+            null,
+        ]))
+    };
+    assert!(params(json!(vec!["a"; 8])).is_ok());
+    assert!(params(json!(vec!["a"; 9])).is_err());
+    assert!(params(json!(["abcdefgh"])).is_ok());
+    assert!(params(json!(["abcdefghi"])).is_err());
+}
+
+#[test]
+fn enum_variants() {
+    let identity: Identity = serde_json::from_str(r#"["a","b"]"#).unwrap();
+    assert!(matches!(&identity, Identity::Names(names) if names.len() == 2));
+    let identity: Identity = serde_json::from_str(r#""a""#).unwrap();
+    assert!(matches!(&identity, Identity::Name(name) if &**name == "a"));
+    assert_eq!(serde_json::to_value(&identity).unwrap(), json!("a"));
+}
+
+#[test]
+fn positional_params() {
+    let user = json!({ "kind": "user", "name": "bob", "groups": [], "handle": ["@bob"] });
+    let params: TagUserParams = serde_json::from_value(json!([user, ["x", "y"], "hello"])).unwrap();
+    assert_eq!(params.tags, [Arc::from("x"), Arc::from("y")]);
+    assert_eq!(params.note.as_deref(), Some("hello"));
+    let value = serde_json::to_value(&params).unwrap();
+    assert_eq!(value[1], json!(["x", "y"]));
+    assert_eq!(value[2], json!("hello"));
+    assert_eq!(params.into_values()[1], json!(["x", "y"]));
+}
+
+#[test]
+fn named_params() {
+    let params: RenameUserParams =
+        serde_json::from_value(json!({ "name": "carol", "aliases": ["c"] })).unwrap();
+    assert_eq!(params.name, Arc::from("carol"));
+    assert_eq!(params.aliases, Some(vec![Arc::from("c")]));
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({ "name": "carol", "aliases": ["c"] }),
+    );
+}
+
+#[test]
+fn responses() {
+    let response = Response::deserialize_for(Method::RenameUser, json!(["a"])).unwrap();
+    assert!(matches!(&response, Response::RenameUser(names) if names.len() == 1));
+    assert_eq!(serde_json::to_value(&response).unwrap(), json!(["a"]));
+}
+
+#[test]
+fn outcomes() {
+    let outcome: RenameUserOutcome =
+        serde_json::from_value(json!({ "jsonrpc": "2.0", "id": 1, "result": ["a", "b"] })).unwrap();
+    assert!(matches!(&outcome, RenameUserOutcome::Ok(names) if names.len() == 2));
+}
@@ -0,0 +1,35 @@
+//! Fields listed in `generation.skip-if` are omitted when their predicate holds.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/skip_if/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("skip_if");
+}
+
+#[test]
+fn empty_vec_is_omitted() {
+    let block = Block {
+        number: 1,
+        transactions: Vec::new(),
+    };
+    assert_eq!(
+        serde_json::to_value(&block).unwrap(),
+        json!({ "number": 1 })
+    );
+
+    let block = Block {
+        number: 1,
+        transactions: vec!["0x1".into()],
+    };
+    assert_eq!(
+        serde_json::to_value(&block).unwrap(),
+        json!({ "number": 1, "transactions": ["0x1"] }),
+    );
+}
@@ -0,0 +1,35 @@
+//! `generation.source-links` ends the documentation of each type with the pointer of the schema
+//! it was generated from.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/source_links/generated.rs");
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("source_links");
+}
+
+#[test]
+fn links_follow_the_documentation() {
+    let generated = crate::generate("source_links");
+    assert!(generated.code.contains(
+        "/// A block of the chain.\n\
+         ///\n\
+         /// Generated from #/components/schemas/Block\n\
+         #[derive("
+    ));
+}
+
+#[test]
+fn inline_schemas_link_to_their_own_pointer() {
+    let generated = crate::generate("source_links");
+    assert!(generated.code.contains(
+        "/// Generated from #/components/schemas/Block/properties/header\n\
+         #[derive("
+    ));
+    assert!(generated
+        .code
+        .contains("/// Generated from #/components/schemas/Hash\npub type Hash = String;"));
+}
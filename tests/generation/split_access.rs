@@ -0,0 +1,37 @@
+//! Structs mixing `readOnly` and `writeOnly` fields are split into request and response types.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/split_access/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("split_access");
+    // The originals are only referenced through their copies.
+    assert!(!generated.code.contains("pub struct Account {"));
+    assert!(!generated.code.contains("pub struct Envelope {"));
+    assert!(!generated.code.contains("pub struct EnvelopeResponse {"));
+    // Only `readOnly` fields: nothing to split.
+    assert!(generated.code.contains("pub struct Profile {"));
+    assert!(!generated.code.contains("ProfileRequest"));
+}
+
+#[test]
+fn copies_hold_the_right_fields() {
+    let request = EnvelopeRequest {
+        account: AccountRequest {
+            name: "alice".into(),
+            password: "hunter2".into(),
+        },
+    };
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({ "account": { "name": "alice", "password": "hunter2" } }),
+    );
+
+    let response: AccountResponse = serde_json::from_str(r#"{"id":1,"name":"alice"}"#).unwrap();
+    assert_eq!((response.id, &*response.name), (1, "alice"));
+}
@@ -0,0 +1,22 @@
+//! With `str-partial-eq`, string enums and newtypes compare with their JSON representation.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/str_partial_eq/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("str_partial_eq");
+}
+
+#[test]
+fn compares_with_str() {
+    let receipt: Receipt = serde_json::from_str(r#"{"status":"accepted","hash":"0x1"}"#).unwrap();
+    assert!(receipt.status == "accepted");
+    assert!(receipt.status != "rejected");
+    assert!(receipt.hash == "0x1");
+    assert!(receipt.hash == *"0x1");
+}
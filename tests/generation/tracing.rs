@@ -0,0 +1,24 @@
+//! `generation.tracing` instruments the methods of the `Client` trait with the `instrument`
+//! attribute of the crate named by `generation.tracing-crate`.
+//!
+//! The generated code is not compiled, as it requires the `tracing` crate.
+
+const ATTRIBUTE: &str =
+    "instrument(name = \"eth_getBalance\", skip_all)]\n    async fn eth_get_balance(";
+
+#[test]
+fn uses_the_configured_crate() {
+    let generated = crate::generate("tracing");
+    assert!(generated
+        .code
+        .contains(&format!("    #[::telemetry::tracing::{ATTRIBUTE}")));
+    assert!(!generated.code.contains("#[tracing::"));
+}
+
+#[test]
+fn defaults_to_tracing() {
+    let generated = crate::generate_with("tracing", "default_crate.toml", &[]).unwrap();
+    assert!(generated
+        .code
+        .contains(&format!("    #[tracing::{ATTRIBUTE}")));
+}
@@ -0,0 +1,98 @@
+//! `generation.trim-strings` trims the leading and trailing whitespace of the listed string
+//! fields when they are deserialized.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/trim_strings/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("trim_strings");
+}
+
+#[test]
+fn listed_fields_are_trimmed() {
+    let json = r#"{"name":"  hi  ","nickname":"\tbob\n","address":" 0x1 ","balance":3}"#;
+    let account: Account = serde_json::from_str(json).unwrap();
+    assert_eq!(account.name, "hi");
+    assert_eq!(account.nickname.as_deref(), Some("bob"));
+    assert_eq!(account.address, " 0x1 ");
+}
+
+#[test]
+fn missing_optional_fields_are_still_accepted() {
+    let json = r#"{"name":"hi","address":"0x1","balance":3}"#;
+    let account: Account = serde_json::from_str(json).unwrap();
+    assert_eq!(account.nickname, None);
+}
+
+#[test]
+fn serialization_is_unaffected() {
+    let account = Account {
+        address: "0x1".into(),
+        balance: 3,
+        name: " hi ".into(),
+        nickname: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&account).unwrap()["name"],
+        serde_json::json!(" hi ")
+    );
+}
+
+#[test]
+fn true_trims_every_string_field() {
+    let generated = crate::generate_with("trim_strings", "all.toml", &[]).unwrap();
+    assert!(generated.code.contains(
+        "    #[serde(deserialize_with = \"trimmed_string::deserialize\")]\n    pub address: String,\n"
+    ));
+    assert!(generated.code.contains("    pub balance: i64,\n"));
+    assert!(!generated.code.contains(
+        "    #[serde(deserialize_with = \"trimmed_string::deserialize\")]\n    pub balance: i64,\n"
+    ));
+}
+
+#[test]
+fn rejects_fields_that_are_not_strings() {
+    let err = crate::generate_with("trim_strings", "not_a_string.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't trim: string field not found:\n- path = #/components/schemas/Account/balance\n"
+    ));
+    assert!(err.contains(
+        "can't trim: string field not found:\n- path = #/components/schemas/Account/missing\n"
+    ));
+}
+
+#[test]
+fn rejects_listed_fields_with_another_deserializer() {
+    let err = crate::generate_with("trim_strings", "capped.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't trim: field already has a custom deserializer:\n\
+         - path = #/components/schemas/Account/name\n\
+         - attribute = #[serde(deserialize_with = \"capped::string\")]\n"
+    ));
+
+    let err = crate::generate_with("trim_strings", "shared.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't trim: field already has a custom deserializer:\n\
+         - path = #/components/schemas/Account/name\n\
+         - attribute = #[serde(with = \"shared_str\")]\n"
+    ));
+}
+
+#[test]
+fn true_skips_fields_with_another_deserializer() {
+    let generated = crate::generate_with("trim_strings", "shared_all.toml", &[]).unwrap();
+    assert!(!generated
+        .code
+        .contains("deserialize_with = \"trimmed_string::"));
+}
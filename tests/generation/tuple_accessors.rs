@@ -0,0 +1,37 @@
+//! `generation.tuple-accessors` generates named accessors for the items of tuple structs.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/tuple_accessors/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("tuple_accessors");
+}
+
+fn proof() -> Proof {
+    serde_json::from_str(r#"[1, ["0xa", "0xb"], "0xc", true, "x"]"#).unwrap()
+}
+
+#[test]
+fn accessors_are_named_after_titles() {
+    let proof = proof();
+    assert_eq!(*proof.value(), 1);
+    assert_eq!(proof.merkle_proof(), &["0xa", "0xb"]);
+}
+
+#[test]
+fn accessors_are_named_after_referenced_types() {
+    assert_eq!(proof().hash(), "0xc");
+}
+
+#[test]
+fn accessors_fall_back_to_the_index() {
+    // The fourth item has no title, and the title of the fifth one is already taken.
+    let proof = proof();
+    assert!(*proof.item3());
+    assert_eq!(proof.item4(), "x");
+}
@@ -0,0 +1,69 @@
+//! Fields of a type listed in `type-serde-with` are (de)serialized through the given module.
+
+#[allow(dead_code)]
+mod generated {
+    /// Represents integers as hexadecimal strings.
+    mod hex {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&format!("{value:#x}"))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+            let s = String::deserialize(deserializer)?;
+            let digits = s.strip_prefix("0x").unwrap_or(&s);
+            i64::from_str_radix(digits, 16).map_err(serde::de::Error::custom)
+        }
+
+        pub mod option {
+            use serde::{Deserialize, Deserializer, Serializer};
+
+            pub fn serialize<S: Serializer>(
+                value: &Option<i64>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match value {
+                    Some(value) => super::serialize(value, serializer),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<i64>, D::Error> {
+                #[derive(Deserialize)]
+                struct Hex(#[serde(with = "super")] i64);
+
+                Ok(Option::<Hex>::deserialize(deserializer)?.map(|hex| hex.0))
+            }
+        }
+    }
+
+    include!("../fixtures/type_serde_with/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("type_serde_with");
+    assert!(generated
+        .code
+        .contains("    #[serde(with = \"hex\")]\n    pub amount: Felt,\n"));
+    assert!(generated
+        .code
+        .contains("    #[serde(with = \"hex::option\")]\n    pub fee: Option<Felt>,\n"));
+}
+
+#[test]
+fn fields_go_through_the_module() {
+    let transfer: Transfer = serde_json::from_str(r#"{"amount":"0xff","fee":"0x1"}"#).unwrap();
+    assert_eq!((transfer.amount, transfer.fee), (255, Some(1)));
+    let transfer: Transfer = serde_json::from_str(r#"{"amount":"0x10"}"#).unwrap();
+    assert_eq!(transfer.fee, None);
+    assert_eq!(
+        serde_json::to_string(&transfer).unwrap(),
+        r#"{"amount":"0x10","fee":null}"#
+    );
+}
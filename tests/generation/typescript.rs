@@ -0,0 +1,14 @@
+//! The TypeScript declarations mirror the optionality of the generated fields.
+
+#[test]
+fn optional_fields_are_optional_properties() {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("typescript.d.ts");
+    crate::generate_with(
+        "typescript",
+        "config.toml",
+        &["--typescript", path.to_str().unwrap()],
+    )
+    .unwrap();
+    let declarations = std::fs::read_to_string(path).unwrap();
+    assert!(declarations.contains("export interface User {\n  age?: number;\n  name: string;\n}"));
+}
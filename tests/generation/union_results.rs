@@ -0,0 +1,35 @@
+//! A union of object shapes as a method result becomes an untagged enum.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/union_results/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("union_results");
+    assert!(generated
+        .code
+        .contains("#[serde(untagged)]\npub enum Anonymous {"));
+    assert!(generated
+        .code
+        .contains("pub type GetTransactionResult = Anonymous;"));
+}
+
+#[test]
+fn deserializes_each_shape() {
+    let result: GetTransactionResult = serde_json::from_str(r#"{"hash":"0x1"}"#).unwrap();
+    assert!(matches!(result, Anonymous::Pending(p) if p.hash == "0x1"));
+    let result: GetTransactionResult = serde_json::from_str(r#"{"hash":"0x1","block":2}"#).unwrap();
+    assert!(matches!(result, Anonymous::Confirmed(c) if c.block == 2));
+}
+
+#[test]
+fn titles_are_sanitized() {
+    let generated = crate::generate("union_results").code;
+    assert!(generated.contains("pub type GetReceiptResult = T2NdStageReceiptV2;"));
+    let result: GetReceiptResult = serde_json::from_str(r#"{"hash":"0x1","block":2}"#).unwrap();
+    assert!(matches!(result, T2NdStageReceiptV2::Confirmed(c) if c.block == 2));
+}
@@ -0,0 +1,15 @@
+//! Parsing errors point at the offending value of the document.
+
+#[test]
+fn unresolved_ref_reports_its_pointer() {
+    let err = crate::generate_with("unresolved_ref", "config.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "`#/components/schemas/Block/header/_anon`: reference `#/components/schemas/Header` not \
+        found\n\
+        - pointer = #/components/schemas/Block/properties/header/$ref\n"
+    ));
+    // Errors are not followed by blank lines.
+    assert!(!err.contains("\n\n"), "{err}");
+}
@@ -0,0 +1,79 @@
+//! Structs whose fields declare bounds get a `validate` method when
+//! `generation.validate-method` is set.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/validate_method/generated.rs");
+}
+
+use generated::*;
+
+fn account() -> Account {
+    Account {
+        age: 30,
+        name: "alice".into(),
+        display_name: None,
+        tags: None,
+    }
+}
+
+fn violation(
+    field: &'static str,
+    keyword: &'static str,
+    limit: &'static str,
+) -> ConstraintViolation {
+    ConstraintViolation {
+        field,
+        keyword,
+        limit,
+    }
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("validate_method");
+}
+
+#[test]
+fn valid_account_passes() {
+    assert_eq!(account().validate(), Ok(()));
+
+    let account = Account {
+        display_name: Some("al".into()),
+        tags: Some(vec!["a".into(), "b".into()]),
+        ..account()
+    };
+    assert_eq!(account.validate(), Ok(()));
+}
+
+#[test]
+fn required_fields_are_checked() {
+    let account = Account {
+        age: 150,
+        name: String::new(),
+        ..account()
+    };
+    assert_eq!(
+        account.validate(),
+        Err(vec![
+            violation("age", "exclusiveMaximum", "150"),
+            violation("name", "minLength", "1"),
+        ]),
+    );
+}
+
+#[test]
+fn optional_fields_are_checked_when_present() {
+    let account = Account {
+        display_name: Some("alicia".into()),
+        tags: Some(vec!["a".into(), "b".into(), "c".into()]),
+        ..account()
+    };
+    assert_eq!(
+        account.validate(),
+        Err(vec![
+            violation("display-name", "maxLength", "4"),
+            violation("tags", "maxItems", "2"),
+        ]),
+    );
+}
@@ -0,0 +1,22 @@
+//! With `value-tryfrom`, generated types convert from and into `serde_json::Value`.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/value_tryfrom/generated.rs");
+}
+
+use generated::*;
+use serde_json::{json, Value};
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("value_tryfrom");
+}
+
+#[test]
+fn converts_from_and_into_value() {
+    let point = Point::try_from(json!({ "x": 1, "y": 2 })).unwrap();
+    assert_eq!((point.x, point.y), (1, 2));
+    assert_eq!(Value::try_from(point).unwrap(), json!({ "x": 1, "y": 2 }));
+    assert!(Point::try_from(json!({ "x": 1 })).is_err());
+}
@@ -0,0 +1,57 @@
+//! `generation.variant-constructors` generates a `new_*` function for each variant of tagged
+//! enums, boxing the payload of the variants boxed by `generation.box-large-variants`.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/variant_constructors/generated.rs");
+}
+
+use generated::*;
+use serde_json::json;
+
+fn receipt() -> Receipt {
+    let s = || String::from("0x0");
+    Receipt {
+        a1: s(),
+        b1: s(),
+        c1: s(),
+        d1: s(),
+        e1: s(),
+        f1: s(),
+        g1: s(),
+        h1: s(),
+        i1: s(),
+    }
+}
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("variant_constructors");
+    assert!(generated.code.contains(
+        "    pub fn new_receipt(value: Receipt) -> Self {\n        Self::Receipt(Box::new(value))\n"
+    ));
+    assert!(generated.code.contains(
+        "    pub fn new_transfer(value: Transfer) -> Self {\n        Self::Transfer(value)\n"
+    ));
+}
+
+#[test]
+fn plain_variant() {
+    let event = Event::new_transfer(Transfer { amount: 3 });
+    assert!(matches!(&event, Event::Transfer(t) if t.amount == 3));
+    assert_eq!(
+        serde_json::to_value(&event).unwrap(),
+        json!({ "type": "transfer", "amount": 3 }),
+    );
+}
+
+#[test]
+fn boxed_variant() {
+    let event = Event::new_receipt(receipt());
+    let Event::Receipt(receipt) = event.clone() else {
+        panic!("expected a receipt");
+    };
+    let receipt: Receipt = *receipt;
+    assert_eq!(receipt.i1, "0x0");
+    assert_eq!(serde_json::to_value(&event).unwrap()["type"], "receipt");
+}
@@ -0,0 +1,47 @@
+//! `generation.variant-eq` implements `PartialEq` manually for the listed enums, comparing
+//! only the variants of their values.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/variant_eq/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("variant_eq");
+}
+
+#[test]
+fn same_variants_are_equal_regardless_of_payloads() {
+    assert_eq!(BlockId::Number(1), BlockId::Number(2));
+    assert_eq!(BlockId::Hash("0x1".into()), BlockId::Hash("0x2".into()));
+}
+
+#[test]
+fn different_variants_are_not_equal() {
+    assert_ne!(BlockId::Number(1), BlockId::Hash("0x1".into()));
+}
+
+#[test]
+fn rejects_enums_deriving_partial_eq() {
+    let err = crate::generate_with("variant_eq", "unit_enum.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't implement `PartialEq` by variant: enum already derives `PartialEq`:\n\
+         - path = #/components/schemas/Status\n"
+    ));
+}
+
+#[test]
+fn rejects_unknown_enums() {
+    let err = crate::generate_with("variant_eq", "missing.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains(
+        "can't implement `PartialEq` by variant: enum not found:\n\
+         - path = #/components/schemas/Missing\n"
+    ));
+}
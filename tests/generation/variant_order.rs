@@ -0,0 +1,72 @@
+//! Untagged enum variants are tried from the most specific, unless their order is pinned.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/variant_order/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    let generated = crate::check_fixture("variant_order");
+    // Variants keep the order of the document, even past ten members.
+    assert!(generated.code.contains(
+        "// Variants are tried in order: Name, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11.\n"
+    ));
+    assert!(generated.code.contains(
+        "// Variants are tried in order: U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, Text.\n"
+    ));
+}
+
+#[test]
+fn more_specific_variant_is_tried_first() {
+    // Tried in the order of the document, `Pending` would accept this payload and drop `block`.
+    let transaction: Transaction = serde_json::from_str(r#"{"hash":"0x1","block":2}"#).unwrap();
+    assert!(matches!(transaction, Transaction::Confirmed(c) if c.block == 2));
+    let transaction: Transaction = serde_json::from_str(r#"{"hash":"0x1"}"#).unwrap();
+    assert!(matches!(transaction, Transaction::Pending(_)));
+}
+
+#[test]
+fn members_past_ten_deserialize() {
+    let pinned: Pinned = serde_json::from_str(r#"{"v11":1}"#).unwrap();
+    assert!(matches!(pinned, Pinned::V11(v) if v.v11 == 1));
+    let unpinned: Unpinned = serde_json::from_str(r#""x""#).unwrap();
+    assert!(matches!(unpinned, Unpinned::Text(t) if t == "x"));
+}
+
+/// Returns the variants listed by the try-order comment of the provided enum, along with the
+/// variants in the order they are declared.
+fn try_order(code: &str, name: &str) -> (Vec<String>, Vec<String>) {
+    let start = code.find(&format!("pub enum {name} {{\n")).unwrap();
+    let comment = code[..start]
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix("// Variants are tried in order: "))
+        .unwrap();
+    let listed = comment
+        .trim_end_matches('.')
+        .split(", ")
+        .map(String::from)
+        .collect();
+    let declared = code[start..]
+        .lines()
+        .skip(1)
+        .take_while(|line| *line != "}")
+        .map(|line| line.trim().split('(').next().unwrap().to_string())
+        .collect();
+    (listed, declared)
+}
+
+#[test]
+fn try_order_comment_follows_the_declaration_order() {
+    let generated = crate::generate("variant_order");
+    for name in ["Pinned", "Unpinned", "Transaction"] {
+        let (listed, declared) = try_order(&generated.code, name);
+        assert_eq!(listed, declared, "{name}");
+    }
+    // `Confirmed` is more specific, and is moved before `Pending`.
+    let (listed, _) = try_order(&generated.code, "Transaction");
+    assert_eq!(listed, ["Confirmed", "Pending"]);
+}
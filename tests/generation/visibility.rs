@@ -0,0 +1,51 @@
+//! `generation.visibility` sets the visibility of the generated items, including restricted
+//! `pub(in path)` visibilities.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/visibility/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("visibility");
+}
+
+#[test]
+fn items_are_visible_within_the_restriction() {
+    let block = Block {
+        hash: "0x1".into(),
+        status: Status::Final,
+    };
+    let params = GetBlockParams {
+        hash: block.hash.clone(),
+    };
+    assert_eq!(params.hash, "0x1");
+    assert_eq!(Error::BlockNotFound.code(), 1);
+}
+
+#[test]
+fn every_item_uses_the_restriction() {
+    let generated = crate::generate("visibility");
+    for item in [
+        "struct Block {",
+        "type Hash = String;",
+        "enum Status {",
+        "enum Error {",
+        "struct GetBlockParams {",
+    ] {
+        assert!(generated
+            .code
+            .contains(&format!("\npub(in crate::visibility) {item}")));
+    }
+}
+
+#[test]
+fn rejects_paths_not_rooted_at_an_ancestor() {
+    let err = crate::generate_with("visibility", "invalid.toml", &[])
+        .err()
+        .unwrap();
+    assert!(err.contains("invalid `generation.visibility` `pub(in generated)`"));
+}
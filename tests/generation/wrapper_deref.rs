@@ -0,0 +1,38 @@
+//! `generation.wrapper-deref` implements `Deref` and `DerefMut` for structs with a single field.
+
+#[allow(dead_code)]
+mod generated {
+    include!("../fixtures/wrapper_deref/generated.rs");
+}
+
+use generated::*;
+
+#[test]
+fn generated_is_up_to_date() {
+    crate::check_fixture("wrapper_deref");
+}
+
+#[test]
+fn inner_methods_are_reachable_through_the_wrapper() {
+    let mut address = Address {
+        value: "0x12".into(),
+    };
+    assert_eq!(address.len(), 4);
+    assert!(address.starts_with("0x"));
+    address.push_str("34");
+    assert_eq!(address.value, "0x1234");
+}
+
+#[test]
+fn optional_fields_deref_to_the_option() {
+    let mut tags = Tags { items: None };
+    assert!(tags.is_none());
+    *tags = Some(vec!["a".into()]);
+    assert_eq!(tags.as_deref(), Some(&["a".to_string()][..]));
+}
+
+#[test]
+fn structs_with_several_fields_are_left_alone() {
+    let generated = crate::generate("wrapper_deref");
+    assert!(!generated.code.contains("for Range {"));
+}